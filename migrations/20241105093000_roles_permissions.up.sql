@@ -0,0 +1,74 @@
+-- Add up migration script here
+CREATE TABLE roles (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(50) NOT NULL UNIQUE,
+    description VARCHAR(1000) DEFAULT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+CREATE TABLE permissions (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL UNIQUE,
+    description VARCHAR(1000) DEFAULT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+CREATE TABLE roles_permissions (
+    id SERIAL PRIMARY KEY,
+    role_id INTEGER NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    permission_id INTEGER NOT NULL REFERENCES permissions(id) ON DELETE CASCADE,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (role_id, permission_id)
+);
+CREATE TABLE users_roles (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role_id INTEGER NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (user_id, role_id)
+);
+INSERT INTO roles (name, description)
+VALUES ('admin', 'Full access to every resource'),
+    ('editor', 'Manages and publishes all content'),
+    ('author', 'Writes posts and submits them for review');
+INSERT INTO permissions (name)
+VALUES ('posts:read'),
+    ('posts:write'),
+    ('posts:delete'),
+    ('posts:publish'),
+    ('categories:read'),
+    ('categories:write'),
+    ('categories:delete'),
+    ('tags:read'),
+    ('tags:write'),
+    ('tags:delete'),
+    ('users:read'),
+    ('users:write'),
+    ('users:delete'),
+    ('roles:read'),
+    ('roles:write'),
+    ('roles:delete');
+INSERT INTO roles_permissions (role_id, permission_id)
+SELECT r.id,
+    p.id
+FROM roles r
+    CROSS JOIN permissions p
+WHERE r.name = 'admin'
+    OR (
+        r.name = 'editor'
+        AND split_part(p.name, ':', 1) IN ('posts', 'categories', 'tags')
+    )
+    OR (
+        r.name = 'author'
+        AND p.name IN (
+            'posts:read',
+            'posts:write',
+            'categories:read',
+            'tags:read'
+        )
+    );
+-- Existing accounts had unrestricted access before roles were introduced
+INSERT INTO users_roles (user_id, role_id)
+SELECT u.id,
+    r.id
+FROM users u
+    CROSS JOIN roles r
+WHERE r.name = 'admin';
@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE posts DROP COLUMN IF EXISTS date_updated,
+    DROP COLUMN IF EXISTS updated_by;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE posts
+ADD COLUMN updated_by INTEGER DEFAULT NULL REFERENCES users(id) ON DELETE SET NULL,
+    ADD COLUMN date_updated TIMESTAMP DEFAULT NULL;
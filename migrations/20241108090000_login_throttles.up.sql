@@ -0,0 +1,11 @@
+-- Add up migration script here
+CREATE TABLE login_throttles (
+    id SERIAL PRIMARY KEY,
+    scope VARCHAR(10) NOT NULL,
+    identifier VARCHAR(200) NOT NULL,
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    last_failed_at TIMESTAMP DEFAULT NULL,
+    locked_until TIMESTAMP DEFAULT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (scope, identifier)
+);
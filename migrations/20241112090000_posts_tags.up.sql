@@ -0,0 +1,9 @@
+-- Add up migration script here
+CREATE TABLE posts_tags (
+    id SERIAL PRIMARY KEY,
+    post_id INTEGER NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (post_id, tag_id)
+);
+CREATE INDEX posts_tags_tag_id_idx ON posts_tags (tag_id);
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS posts_scheduled_date_published_idx;
+DROP TABLE IF EXISTS post_status_transitions;
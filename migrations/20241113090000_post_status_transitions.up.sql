@@ -0,0 +1,12 @@
+-- Add up migration script here
+CREATE TABLE post_status_transitions (
+    id SERIAL PRIMARY KEY,
+    post_id INTEGER NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    from_status posts_status NOT NULL,
+    to_status posts_status NOT NULL,
+    changed_by INTEGER DEFAULT NULL REFERENCES users(id) ON DELETE SET NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+CREATE INDEX post_status_transitions_post_id_idx ON post_status_transitions (post_id);
+CREATE INDEX posts_scheduled_date_published_idx ON posts (date_published)
+WHERE status = 'Scheduled';
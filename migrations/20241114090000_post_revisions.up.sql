@@ -0,0 +1,33 @@
+-- Add up migration script here
+CREATE TABLE post_revisions (
+    id SERIAL PRIMARY KEY,
+    post_id INTEGER NOT NULL REFERENCES posts(id) ON DELETE CASCADE,
+    revision_number INTEGER NOT NULL,
+    title VARCHAR(200) NOT NULL,
+    content TEXT NOT NULL,
+    slug VARCHAR(200) NOT NULL,
+    status posts_status NOT NULL,
+    editor_id INTEGER DEFAULT NULL REFERENCES users(id) ON DELETE SET NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (post_id, revision_number)
+);
+-- Existing posts start their history with their current state
+INSERT INTO post_revisions (
+        post_id,
+        revision_number,
+        title,
+        content,
+        slug,
+        status,
+        editor_id,
+        date_created
+    )
+SELECT id,
+    1,
+    title,
+    content,
+    slug,
+    status,
+    COALESCE(updated_by, author_id),
+    COALESCE(date_updated, date_created)
+FROM posts;
@@ -0,0 +1,10 @@
+-- Add up migration script here
+CREATE TABLE post_drafts (
+    post_id INTEGER PRIMARY KEY REFERENCES posts(id) ON DELETE CASCADE,
+    title VARCHAR(200) NOT NULL,
+    content TEXT NOT NULL,
+    slug VARCHAR(200) NOT NULL,
+    editor_id INTEGER DEFAULT NULL REFERENCES users(id) ON DELETE SET NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    date_updated TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
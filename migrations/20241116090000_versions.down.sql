@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE users DROP COLUMN IF EXISTS version;
+ALTER TABLE tags DROP COLUMN IF EXISTS version;
+ALTER TABLE categories DROP COLUMN IF EXISTS version;
+ALTER TABLE posts DROP COLUMN IF EXISTS version;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE posts DROP COLUMN IF EXISTS content_html,
+    DROP COLUMN IF EXISTS content_format;
+DROP TYPE IF EXISTS posts_content_format;
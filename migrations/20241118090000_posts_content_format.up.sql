@@ -0,0 +1,16 @@
+-- Add up migration script here
+DO $$ BEGIN IF NOT EXISTS (
+    SELECT 1
+    FROM pg_type
+    WHERE typname = 'posts_content_format'
+) THEN CREATE TYPE posts_content_format AS ENUM (
+    'markdown',
+    'html',
+    'plain'
+);
+END IF;
+END $$;
+-- Existing content was served as is, it is rendered on startup
+ALTER TABLE posts
+ADD COLUMN content_format posts_content_format NOT NULL DEFAULT 'html',
+    ADD COLUMN content_html TEXT DEFAULT NULL;
@@ -0,0 +1,14 @@
+-- Add down migration script here
+-- Enum values cannot be dropped: posts made of blocks keep their rendering
+UPDATE posts
+SET content_format = 'html',
+    content = COALESCE(content_html, '')
+WHERE content_format = 'blocks';
+ALTER TYPE posts_content_format RENAME TO posts_content_format_old;
+CREATE TYPE posts_content_format AS ENUM ('markdown', 'html', 'plain');
+ALTER TABLE posts
+ALTER COLUMN content_format DROP DEFAULT,
+    ALTER COLUMN content_format TYPE posts_content_format USING content_format::text::posts_content_format,
+    ALTER COLUMN content_format
+SET DEFAULT 'html';
+DROP TYPE posts_content_format_old;
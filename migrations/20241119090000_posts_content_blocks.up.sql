@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TYPE posts_content_format ADD VALUE IF NOT EXISTS 'blocks';
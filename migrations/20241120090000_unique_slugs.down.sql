@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE tags DROP CONSTRAINT IF EXISTS tags_slug_key;
+ALTER TABLE categories DROP CONSTRAINT IF EXISTS categories_slug_key;
+ALTER TABLE posts DROP CONSTRAINT IF EXISTS posts_slug_key;
@@ -0,0 +1,44 @@
+-- Add up migration script here
+-- Duplicates keep the slug on their oldest row, the others get their ID
+UPDATE posts
+SET slug = LEFT(posts.slug, 190) || '-' || posts.id
+FROM (
+        SELECT id,
+            ROW_NUMBER() OVER (
+                PARTITION BY slug
+                ORDER BY id
+            ) AS position
+        FROM posts
+    ) AS duplicates
+WHERE duplicates.id = posts.id
+    AND duplicates.position > 1;
+UPDATE categories
+SET slug = LEFT(categories.slug, 190) || '-' || categories.id
+FROM (
+        SELECT id,
+            ROW_NUMBER() OVER (
+                PARTITION BY slug
+                ORDER BY id
+            ) AS position
+        FROM categories
+    ) AS duplicates
+WHERE duplicates.id = categories.id
+    AND duplicates.position > 1;
+UPDATE tags
+SET slug = LEFT(tags.slug, 190) || '-' || tags.id
+FROM (
+        SELECT id,
+            ROW_NUMBER() OVER (
+                PARTITION BY slug
+                ORDER BY id
+            ) AS position
+        FROM tags
+    ) AS duplicates
+WHERE duplicates.id = tags.id
+    AND duplicates.position > 1;
+ALTER TABLE posts
+ADD CONSTRAINT posts_slug_key UNIQUE (slug);
+ALTER TABLE categories
+ADD CONSTRAINT categories_slug_key UNIQUE (slug);
+ALTER TABLE tags
+ADD CONSTRAINT tags_slug_key UNIQUE (slug);
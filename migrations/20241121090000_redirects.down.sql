@@ -0,0 +1,6 @@
+-- Add down migration script here
+DELETE FROM permissions
+WHERE split_part(name, ':', 1) = 'redirects';
+DROP TABLE IF EXISTS redirects;
+DROP TABLE IF EXISTS slug_redirects;
+DROP TYPE IF EXISTS slug_type;
@@ -0,0 +1,37 @@
+-- Add up migration script here
+DO $$ BEGIN IF NOT EXISTS (
+    SELECT 1
+    FROM pg_type
+    WHERE typname = 'slug_type'
+) THEN CREATE TYPE slug_type AS ENUM ('post', 'category', 'tag');
+END IF;
+END $$;
+-- Previous slugs of posts, categories and tags, resolved to their content
+CREATE TABLE slug_redirects (
+    id SERIAL PRIMARY KEY,
+    slug_type slug_type NOT NULL,
+    slug VARCHAR(200) NOT NULL,
+    target_id INTEGER NOT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (slug_type, slug)
+);
+CREATE TABLE redirects (
+    id SERIAL PRIMARY KEY,
+    source_path VARCHAR(2000) NOT NULL UNIQUE,
+    target VARCHAR(2000) NOT NULL,
+    status_code INTEGER NOT NULL DEFAULT 301 CHECK (status_code IN (301, 302)),
+    hits BIGINT NOT NULL DEFAULT 0,
+    last_hit_at TIMESTAMP DEFAULT NULL,
+    date_created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+INSERT INTO permissions (name)
+VALUES ('redirects:read'),
+    ('redirects:write'),
+    ('redirects:delete') ON CONFLICT (name) DO NOTHING;
+INSERT INTO roles_permissions (role_id, permission_id)
+SELECT r.id,
+    p.id
+FROM roles r
+    CROSS JOIN permissions p
+WHERE r.name IN ('admin', 'editor')
+    AND split_part(p.name, ':', 1) = 'redirects' ON CONFLICT (role_id, permission_id) DO NOTHING;
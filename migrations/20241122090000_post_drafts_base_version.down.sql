@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE post_drafts DROP COLUMN IF EXISTS base_version;
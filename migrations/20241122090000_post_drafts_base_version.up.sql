@@ -0,0 +1,6 @@
+-- Add up migration script here
+ALTER TABLE post_drafts ADD COLUMN base_version INTEGER;
+UPDATE post_drafts d SET base_version = p.version
+FROM posts p
+WHERE p.id = d.post_id;
+ALTER TABLE post_drafts ALTER COLUMN base_version SET NOT NULL;
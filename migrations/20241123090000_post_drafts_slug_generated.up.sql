@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE post_drafts
+ADD COLUMN slug_generated BOOLEAN NOT NULL DEFAULT FALSE;
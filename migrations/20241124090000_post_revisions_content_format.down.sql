@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE post_revisions DROP COLUMN IF EXISTS content_format;
@@ -0,0 +1,8 @@
+-- Add up migration script here
+ALTER TABLE post_revisions
+ADD COLUMN content_format posts_content_format;
+-- The format of older snapshots was not recorded, the post's is the best guess
+UPDATE post_revisions r SET content_format = p.content_format
+FROM posts p
+WHERE p.id = r.post_id;
+ALTER TABLE post_revisions ALTER COLUMN content_format SET NOT NULL;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE post_drafts ALTER COLUMN title TYPE VARCHAR(200);
+ALTER TABLE post_revisions ALTER COLUMN title TYPE VARCHAR(200);
+ALTER TABLE posts ALTER COLUMN title TYPE VARCHAR(200);
@@ -0,0 +1,5 @@
+-- Add up migration script here
+-- Titles are validated up to 500 characters
+ALTER TABLE posts ALTER COLUMN title TYPE VARCHAR(500);
+ALTER TABLE post_revisions ALTER COLUMN title TYPE VARCHAR(500);
+ALTER TABLE post_drafts ALTER COLUMN title TYPE VARCHAR(500);
@@ -34,3 +34,104 @@ pub fn get_backtrace() -> u16 {
         Err(_) => panic!("RUST_BACKTRACE must be a valid u16"),
     }
 }
+
+pub fn get_access_token_ttl() -> i64 {
+    let ttl_str =
+        env::var("ACCESS_TOKEN_TTL").unwrap_or_else(|_| String::from("900"));
+
+    match ttl_str.parse::<i64>() {
+        Ok(ttl) => ttl,
+        Err(_) => panic!("ACCESS_TOKEN_TTL must be a valid i64"),
+    }
+}
+
+pub fn get_refresh_token_ttl() -> i64 {
+    let ttl_str = env::var("REFRESH_TOKEN_TTL")
+        .unwrap_or_else(|_| String::from("2592000"));
+
+    match ttl_str.parse::<i64>() {
+        Ok(ttl) => ttl,
+        Err(_) => panic!("REFRESH_TOKEN_TTL must be a valid i64"),
+    }
+}
+
+pub fn get_jwt_keys_dir() -> Option<String> {
+    env::var("JWT_KEYS_DIR").ok()
+}
+
+pub fn get_jwt_signing_key_id() -> String {
+    env::var("JWT_SIGNING_KEY_ID")
+        .expect("JWT_SIGNING_KEY_ID must be set when JWT_KEYS_DIR is set")
+}
+
+/// Base URL of the front end, used to build the links sent by email.
+/// Defaults to `CORS_ALLOWED_URL`.
+pub fn get_app_url() -> String {
+    env::var("APP_URL")
+        .or_else(|_| env::var("CORS_ALLOWED_URL"))
+        .unwrap_or_else(|_| String::from("http://127.0.0.1:3000"))
+}
+
+pub fn get_smtp_host() -> Option<String> {
+    env::var("SMTP_HOST").ok()
+}
+
+pub fn get_smtp_port() -> u16 {
+    let port_str =
+        env::var("SMTP_PORT").unwrap_or_else(|_| String::from("587"));
+
+    match port_str.parse::<u16>() {
+        Ok(port) => port,
+        Err(_) => panic!("SMTP_PORT must be a valid u16"),
+    }
+}
+
+/// Either `starttls` (default), `tls` or `none`.
+pub fn get_smtp_tls() -> String {
+    env::var("SMTP_TLS").unwrap_or_else(|_| String::from("starttls"))
+}
+
+pub fn get_smtp_credentials() -> Option<(String, String)> {
+    match (env::var("SMTP_USERNAME"), env::var("SMTP_PASSWORD")) {
+        (Ok(username), Ok(password)) => Some((username, password)),
+        _ => None,
+    }
+}
+
+pub fn get_mail_from() -> String {
+    env::var("MAIL_FROM").unwrap_or_else(|_| String::from("no-reply@localhost"))
+}
+
+/// Directory emails are written to when no SMTP server is configured.
+pub fn get_mail_dir() -> String {
+    env::var("MAIL_DIR").unwrap_or_else(|_| String::from("mails"))
+}
+
+/// Name shown by authenticator apps next to the account.
+pub fn get_totp_issuer() -> String {
+    env::var("TOTP_ISSUER").unwrap_or_else(|_| String::from("RustCMS"))
+}
+
+/// Seconds between two runs of the scheduled publishing worker, `0` disables
+/// it. Defaults to a minute.
+pub fn get_publish_scheduler_interval() -> u16 {
+    let interval_str = env::var("PUBLISH_SCHEDULER_INTERVAL")
+        .unwrap_or_else(|_| String::from("60"));
+
+    match interval_str.parse::<u16>() {
+        Ok(interval) => interval,
+        Err(_) => panic!("PUBLISH_SCHEDULER_INTERVAL must be a valid u16"),
+    }
+}
+
+/// Days a post stays in the trash before it is purged, `0` keeps trashed
+/// posts until they are purged by hand. Defaults to 30 days.
+pub fn get_trash_retention_days() -> u16 {
+    let days_str =
+        env::var("TRASH_RETENTION_DAYS").unwrap_or_else(|_| String::from("30"));
+
+    match days_str.parse::<u16>() {
+        Ok(days) => days,
+        Err(_) => panic!("TRASH_RETENTION_DAYS must be a valid u16"),
+    }
+}
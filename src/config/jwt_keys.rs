@@ -0,0 +1,300 @@
+use std::{collections::BTreeMap, fs, path::Path, sync::OnceLock};
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use jsonwebtoken::{
+    decode, decode_header, encode,
+    errors::{Error as JwtError, ErrorKind},
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet,
+        KeyAlgorithm, OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse,
+        RSAKeyParameters, RSAKeyType,
+    },
+    Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use pkcs1::{der::Decode, RsaPublicKey};
+use serde::{de::DeserializeOwned, Serialize};
+use spki::{der::DecodePem, ObjectIdentifier, SubjectPublicKeyInfoOwned};
+
+use super::config::{get_jwt_keys_dir, get_jwt_signing_key_id, get_secret_key};
+
+const RSA_ENCRYPTION_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const ED25519_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.101.112");
+
+static JWT_KEYS: OnceLock<JwtKeys> = OnceLock::new();
+
+/// Returns the keys used to sign and verify tokens, loading them on first use.
+///
+/// When `JWT_KEYS_DIR` is set, tokens are signed with the asymmetric key
+/// named by `JWT_SIGNING_KEY_ID`; otherwise HS256 with `JWT_SECRET` is used.
+pub fn jwt_keys() -> &'static JwtKeys {
+    JWT_KEYS.get_or_init(|| match get_jwt_keys_dir() {
+        Some(dir) => {
+            JwtKeys::from_dir(Path::new(&dir), &get_jwt_signing_key_id())
+                .unwrap_or_else(|e| panic!("Failed to load JWT keys: {}", e))
+        }
+        None => JwtKeys::from_secret(&get_secret_key()),
+    })
+}
+
+/// A public key accepted when verifying tokens.
+struct VerificationKey {
+    algorithm: Algorithm,
+    key: DecodingKey,
+}
+
+pub struct JwtKeys {
+    signing_kid: Option<String>,
+    signing_algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    secret: Option<DecodingKey>,
+    verification_keys: BTreeMap<String, VerificationKey>,
+    jwks: JwkSet,
+}
+
+impl JwtKeys {
+    /// Signs and verifies tokens with a shared HS256 secret.
+    pub fn from_secret(secret: &str) -> Self {
+        JwtKeys {
+            signing_kid: None,
+            signing_algorithm: Algorithm::HS256,
+            encoding_key: EncodingKey::from_secret(secret.as_ref()),
+            secret: Some(DecodingKey::from_secret(secret.as_ref())),
+            verification_keys: BTreeMap::new(),
+            jwks: JwkSet { keys: vec![] },
+        }
+    }
+
+    /// Loads the RSA and Ed25519 keys of a directory.
+    ///
+    /// Every `<kid>.pub.pem` file is a verification key, published in the
+    /// JWKS. Keeping the public key of a retired signing key in the directory
+    /// lets its tokens be verified until they expire. The signing key is read
+    /// from `<signing_kid>.pem` and must have its public key alongside it.
+    pub fn from_dir(dir: &Path, signing_kid: &str) -> Result<Self> {
+        let mut verification_keys = BTreeMap::new();
+        let mut jwks = JwkSet { keys: vec![] };
+
+        let mut entries = fs::read_dir(dir)
+            .map_err(|e| anyhow!("Cannot read {}: {}", dir.display(), e))?
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+
+        for entry in entries {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let kid = match file_name.strip_suffix(".pub.pem") {
+                Some(kid) => kid.to_string(),
+                None => continue,
+            };
+
+            let pem = fs::read_to_string(entry.path())?;
+            let jwk = public_key_to_jwk(&kid, &pem)
+                .map_err(|e| anyhow!("Invalid public key {}: {}", kid, e))?;
+            let algorithm = match jwk.common.key_algorithm {
+                Some(KeyAlgorithm::EdDSA) => Algorithm::EdDSA,
+                _ => Algorithm::RS256,
+            };
+            let key = DecodingKey::from_jwk(&jwk)?;
+
+            verification_keys.insert(kid, VerificationKey { algorithm, key });
+            jwks.keys.push(jwk);
+        }
+
+        let signing_algorithm = verification_keys
+            .get(signing_kid)
+            .map(|key| key.algorithm)
+            .ok_or_else(|| {
+                anyhow!("Public key {}.pub.pem not found", signing_kid)
+            })?;
+
+        let private_pem = fs::read(dir.join(format!("{}.pem", signing_kid)))
+            .map_err(|e| anyhow!("Cannot read {}.pem: {}", signing_kid, e))?;
+        let encoding_key = match signing_algorithm {
+            Algorithm::EdDSA => EncodingKey::from_ed_pem(&private_pem)?,
+            _ => EncodingKey::from_rsa_pem(&private_pem)?,
+        };
+
+        Ok(JwtKeys {
+            signing_kid: Some(signing_kid.to_string()),
+            signing_algorithm,
+            encoding_key,
+            secret: None,
+            verification_keys,
+            jwks,
+        })
+    }
+
+    /// Signs the claims with the current signing key, setting its `kid`.
+    pub fn encode<T: Serialize>(
+        &self,
+        claims: &T,
+    ) -> jsonwebtoken::errors::Result<String> {
+        let mut header = Header::new(self.signing_algorithm);
+        header.kid = self.signing_kid.clone();
+
+        encode(&header, claims, &self.encoding_key)
+    }
+
+    /// Verifies a token against the key named by its `kid` header.
+    pub fn decode<T: DeserializeOwned>(
+        &self,
+        token: &str,
+    ) -> jsonwebtoken::errors::Result<T> {
+        let (algorithm, key) = match &self.secret {
+            Some(secret) => (Algorithm::HS256, secret),
+            None => {
+                let kid = decode_header(token)?
+                    .kid
+                    .ok_or_else(|| JwtError::from(ErrorKind::InvalidToken))?;
+                let key = self
+                    .verification_keys
+                    .get(&kid)
+                    .ok_or_else(|| JwtError::from(ErrorKind::InvalidToken))?;
+                (key.algorithm, &key.key)
+            }
+        };
+
+        decode::<T>(token, key, &Validation::new(algorithm))
+            .map(|data| data.claims)
+    }
+
+    /// The public verification keys, as served on `/.well-known/jwks.json`.
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
+    }
+}
+
+/// Converts a PEM encoded RSA or Ed25519 public key into a JWK.
+fn public_key_to_jwk(kid: &str, pem: &str) -> Result<Jwk> {
+    let spki = SubjectPublicKeyInfoOwned::from_pem(pem)?;
+    let public_key = spki.subject_public_key.raw_bytes();
+
+    let (key_algorithm, algorithm) = match spki.algorithm.oid {
+        RSA_ENCRYPTION_OID => {
+            let rsa = RsaPublicKey::from_der(public_key)?;
+            (
+                KeyAlgorithm::RS256,
+                AlgorithmParameters::RSA(RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: URL_SAFE_NO_PAD.encode(rsa.modulus.as_bytes()),
+                    e: URL_SAFE_NO_PAD.encode(rsa.public_exponent.as_bytes()),
+                }),
+            )
+        }
+        ED25519_OID => (
+            KeyAlgorithm::EdDSA,
+            AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                key_type: OctetKeyPairType::OctetKeyPair,
+                curve: EllipticCurve::Ed25519,
+                x: URL_SAFE_NO_PAD.encode(public_key),
+            }),
+        ),
+        oid => return Err(anyhow!("Unsupported key algorithm {}", oid)),
+    };
+
+    Ok(Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(key_algorithm),
+            key_id: Some(kid.to_string()),
+            ..Default::default()
+        },
+        algorithm,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Claims {
+        sub: String,
+        exp: usize,
+    }
+
+    fn claims() -> Claims {
+        Claims {
+            sub: String::from("1"),
+            exp: chrono::Utc::now()
+                .checked_add_signed(chrono::Duration::minutes(5))
+                .expect("Failed to compute token expiration")
+                .timestamp() as usize,
+        }
+    }
+
+    fn keys(signing_kid: &str) -> JwtKeys {
+        JwtKeys::from_dir(Path::new("src/tests/keys"), signing_kid)
+            .expect("Failed to load test keys")
+    }
+
+    #[test]
+    fn test_rs256_round_trip() {
+        // Arrange
+        let keys = keys("rsa-test");
+
+        // Act
+        let token = keys.encode(&claims()).unwrap();
+
+        // Assert
+        let header = decode_header(&token).unwrap();
+        assert_eq!(header.alg, Algorithm::RS256);
+        assert_eq!(header.kid.as_deref(), Some("rsa-test"));
+        assert_eq!(keys.decode::<Claims>(&token).unwrap().sub, "1");
+    }
+
+    #[test]
+    fn test_rotated_key_still_verifies() {
+        // Arrange
+        let previous = keys("ed-test");
+        let current = keys("rsa-test");
+
+        // Act
+        let token = previous.encode(&claims()).unwrap();
+
+        // Assert
+        assert_eq!(decode_header(&token).unwrap().alg, Algorithm::EdDSA);
+        assert!(current.decode::<Claims>(&token).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_unknown_kid_and_secret_tokens() {
+        // Arrange
+        let keys = keys("rsa-test");
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some(String::from("unknown"));
+        let unknown_kid = encode(
+            &header,
+            &claims(),
+            &EncodingKey::from_secret("secret".as_ref()),
+        )
+        .unwrap();
+        let without_kid = JwtKeys::from_secret("secret").encode(&claims());
+
+        // Act & Assert
+        assert!(keys.decode::<Claims>(&unknown_kid).is_err());
+        assert!(keys.decode::<Claims>(&without_kid.unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_jwks_lists_public_keys() {
+        // Arrange
+        let keys = keys("rsa-test");
+
+        // Act
+        let jwks = keys.jwks();
+
+        // Assert
+        let kids: Vec<&str> = jwks
+            .keys
+            .iter()
+            .filter_map(|jwk| jwk.common.key_id.as_deref())
+            .collect();
+        assert_eq!(kids, vec!["ed-test", "rsa-retired", "rsa-test"]);
+        assert!(jwks.find("rsa-retired").is_some());
+    }
+}
@@ -0,0 +1,149 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::api_key_dtos::CreateApiKeyDTO,
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::api_keys_service::create_api_key_service,
+};
+
+use super::api_key_management_forbidden;
+
+#[utoipa::path(
+    post,
+    path = "/me/api-keys",
+    tag = "API keys",
+    request_body = CreateApiKeyDTO,
+    responses(
+        (status = 201, description = "API key created, its secret is only shown once", body = CreatedApiKeyDTO),
+        (status = 400, description = "Invalid name, scopes or expiration date", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Requests authenticated with an API key cannot manage API keys", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/me/api-keys")]
+pub async fn create_api_key_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUser,
+    request: Json<CreateApiKeyDTO>,
+) -> Result<HttpResponse, web::Error> {
+    if current_user.api_key_id.is_some() {
+        return Ok(api_key_management_forbidden());
+    }
+
+    match create_api_key_service(
+        pool.get_ref(),
+        current_user.id,
+        request.into_inner(),
+    )
+    .await
+    {
+        Ok(api_key) => Ok(HttpResponse::Created().json(&api_key)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::api_keys::{
+        get_api_keys_controller::get_api_keys_controller,
+        revoke_api_key_controller::revoke_api_key_controller,
+    };
+    use crate::dtos::api_key_dtos::{ApiKeyDTO, CreatedApiKeyDTO};
+    use crate::middlewares::auth_middleware::JwtAuth;
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "api-keys@test.com";
+
+    #[ntex::test]
+    async fn test_api_key_lifecycle() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let jwt = bearer_token_for(user_id, &[]);
+        let app = test::init_service(
+            web::App::new().state(pool.clone()).service(
+                web::scope("/")
+                    .wrap(JwtAuth)
+                    .service(create_api_key_controller)
+                    .service(get_api_keys_controller)
+                    .service(revoke_api_key_controller),
+            ),
+        )
+        .await;
+        let create = |scopes: Vec<String>| {
+            test::TestRequest::post()
+                .uri("/me/api-keys")
+                .header(http::header::AUTHORIZATION, jwt.clone())
+                .set_json(&CreateApiKeyDTO {
+                    name: String::from("Static site generator"),
+                    scopes,
+                    expires_at: None,
+                })
+                .to_request()
+        };
+        let list = |authorization: String| {
+            test::TestRequest::get()
+                .uri("/me/api-keys")
+                .header(http::header::AUTHORIZATION, authorization)
+                .to_request()
+        };
+
+        // Act
+        let not_granted =
+            test::call_service(&app, create(vec![String::from("users:read")]))
+                .await;
+        let resp = test::call_service(&app, create(vec![])).await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+        let created: CreatedApiKeyDTO =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse JSON");
+        let api_key = format!("Bearer {}", created.key);
+
+        let listed_with_key =
+            test::call_service(&app, list(api_key.clone())).await;
+        let listed = test::call_service(&app, list(jwt.clone())).await;
+        let revoke = test::TestRequest::delete()
+            .uri(&format!("/me/api-keys/{}", created.api_key.id))
+            .header(http::header::AUTHORIZATION, jwt.clone())
+            .to_request();
+        let revoked = test::call_service(&app, revoke).await;
+        let listed_with_revoked_key =
+            test::call_service(&app, list(api_key.clone())).await;
+
+        // Assert
+        assert_eq!(not_granted.status(), http::StatusCode::BAD_REQUEST);
+        assert!(created
+            .key
+            .starts_with(&format!("rcms_{}_", created.api_key.prefix)));
+        assert_eq!(listed_with_key.status(), http::StatusCode::FORBIDDEN);
+        assert_eq!(listed.status(), http::StatusCode::OK);
+        let api_keys: Vec<ApiKeyDTO> =
+            serde_json::from_slice(&test::read_body(listed).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(api_keys.len(), 1);
+        assert!(api_keys[0].last_used_at.is_some());
+        assert_eq!(revoked.status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(
+            listed_with_revoked_key.status(),
+            http::StatusCode::UNAUTHORIZED
+        );
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
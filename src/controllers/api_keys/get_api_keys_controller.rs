@@ -0,0 +1,36 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::api_keys_service::get_api_keys_service,
+};
+
+use super::api_key_management_forbidden;
+
+#[utoipa::path(
+    get,
+    path = "/me/api-keys",
+    tag = "API keys",
+    responses(
+        (status = 200, description = "API keys of the current user", body = [ApiKeyDTO]),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Requests authenticated with an API key cannot manage API keys", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/me/api-keys")]
+pub async fn get_api_keys_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUser,
+) -> Result<HttpResponse, web::Error> {
+    if current_user.api_key_id.is_some() {
+        return Ok(api_key_management_forbidden());
+    }
+
+    match get_api_keys_service(pool.get_ref(), current_user.id).await {
+        Ok(api_keys) => Ok(HttpResponse::Ok().json(&api_keys)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,13 @@
+use ntex::web::HttpResponse;
+
+use crate::middlewares::auth_middleware::forbidden_response;
+
+pub mod create_api_key_controller;
+pub mod get_api_keys_controller;
+pub mod revoke_api_key_controller;
+
+/// API keys are managed with a JWT only, so that a leaked key cannot be used
+/// to mint new keys before it is revoked.
+fn api_key_management_forbidden() -> HttpResponse {
+    forbidden_response("API keys cannot be managed with an API key")
+}
@@ -0,0 +1,56 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::error_middleware::Error,
+    services::api_keys_service::revoke_api_key_service,
+};
+
+use super::api_key_management_forbidden;
+
+#[utoipa::path(
+    delete,
+    path = "/me/api-keys/{id}",
+    tag = "API keys",
+    params(
+        ("id" = i32, description = "ID of the API key")
+    ),
+    responses(
+        (status = 204, description = "API key revoked"),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Requests authenticated with an API key cannot manage API keys", body = Error),
+        (status = 404, description = "API key not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/me/api-keys/{id}")]
+pub async fn revoke_api_key_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUser,
+    api_key_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    if current_user.api_key_id.is_some() {
+        return Ok(api_key_management_forbidden());
+    }
+
+    match revoke_api_key_service(
+        pool.get_ref(),
+        current_user.id,
+        api_key_id.into_inner(),
+    )
+    .await
+    {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::NotFound().json(&Error {
+            message: String::from("API key not found"),
+            backtrace: None,
+        })),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::ForgotPasswordRequestDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    mailers::Mailer, services::auth_service::forgot_password_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/forgot",
+    tag = "Auth",
+    request_body = ForgotPasswordRequestDTO,
+    responses(
+        (status = 202, description = "A reset link is emailed if the account exists"),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/password/forgot")]
+pub async fn forgot_password_controller(
+    pool: State<PgPool>,
+    mailer: State<Arc<dyn Mailer>>,
+    request: Json<ForgotPasswordRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match forgot_password_service(
+        pool.get_ref(),
+        mailer.get_ref().as_ref(),
+        request.into_inner(),
+    )
+    .await
+    {
+        Ok(()) => Ok(HttpResponse::Accepted().finish()),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
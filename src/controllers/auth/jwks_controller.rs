@@ -0,0 +1,37 @@
+use ntex::web::{self, HttpResponse};
+
+use crate::config::jwt_keys::jwt_keys;
+
+/// Publishes the public keys verifying the tokens issued by the CMS, so that
+/// other services can check them without sharing a secret.
+#[web::get("/.well-known/jwks.json")]
+pub async fn jwks_controller() -> HttpResponse {
+    HttpResponse::Ok().json(jwt_keys().jwks())
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{test, App};
+
+    use super::*;
+
+    #[ntex::test]
+    async fn test_jwks_controller_success() {
+        // Arrange
+        let app = test::init_service(App::new().service(jwks_controller)).await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/.well-known/jwks.json")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let body = test::read_body(resp).await;
+        let jwks: serde_json::Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert!(jwks["keys"].is_array());
+    }
+}
@@ -1,14 +1,16 @@
+use ntex::http::header;
 use ntex::web::{
     self,
     types::{Json, State},
-    HttpResponse,
+    HttpRequest, HttpResponse,
 };
 use sqlx::PgPool;
 
 use crate::{
-    dtos::auth_dtos::LoginRequestDTO,
+    dtos::auth_dtos::{LoginRequestDTO, TwoFactorChallengeDTO},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
-    services::auth_service::login_service,
+    middlewares::error_middleware::Error,
+    services::auth_service::{login_service, LoginResult},
 };
 
 #[utoipa::path(
@@ -18,18 +20,193 @@ use crate::{
     request_body = LoginRequestDTO,
     responses(
         (status = 200, description = "Login successful", body = TokenDTO),
+        (status = 202, description = "Password accepted, complete the login on /auth/login/2fa", body = TwoFactorChallengeDTO),
         (status = 401, description = "Invalid credentials", body = Error),
+        (status = 429, description = "Too many failed attempts, see the Retry-After header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     ),
 )]
 #[web::post("/login")]
 pub async fn login_controller(
+    req: HttpRequest,
     pool: State<PgPool>,
     login: Json<LoginRequestDTO>,
 ) -> Result<HttpResponse, web::Error> {
-    match login_service(pool.get_ref(), &login.email, &login.password).await {
-        Ok(Some(token)) => Ok(HttpResponse::Ok().json(&token)),
-        Ok(None) => Ok(HttpResponse::Unauthorized().finish()),
+    match login_service(
+        pool.get_ref(),
+        &login.email,
+        &login.password,
+        &client_ip(&req),
+    )
+    .await
+    {
+        Ok(result) => Ok(login_result_response(result)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
 }
+
+/// The peer address, not a forwarded header a client could forge.
+pub fn client_ip(req: &HttpRequest) -> String {
+    req.peer_addr()
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// Maps the outcome of a login step to its HTTP response.
+pub fn login_result_response(result: LoginResult) -> HttpResponse {
+    match result {
+        LoginResult::Success(token) => HttpResponse::Ok().json(&token),
+        LoginResult::TwoFactorRequired {
+            challenge_token,
+            expires_in,
+        } => HttpResponse::Accepted().json(&TwoFactorChallengeDTO {
+            challenge_token,
+            expires_in: expires_in as usize,
+        }),
+        LoginResult::InvalidCredentials => {
+            HttpResponse::Unauthorized().json(&Error {
+                message: String::from("Invalid email or password"),
+                backtrace: None,
+            })
+        }
+        LoginResult::Locked { retry_after } => HttpResponse::TooManyRequests()
+            .header(header::RETRY_AFTER, retry_after.to_string())
+            .json(&Error {
+                message: String::from(
+                    "Too many failed login attempts, try again later",
+                ),
+                backtrace: None,
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::users::unlock_user_controller::unlock_user_controller;
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    fn login_request(email: &str, password: &str) -> ntex::http::Request {
+        test::TestRequest::post()
+            .uri("/login")
+            .set_json(&LoginRequestDTO {
+                email: String::from(email),
+                password: String::from(password),
+            })
+            .to_request()
+    }
+
+    /// Forgets the failed attempts left over by a previous run. Test requests
+    /// carry no peer address, so they are all throttled as `unknown`.
+    async fn clean_login_throttles(pool: &sqlx::PgPool, emails: &[&str]) {
+        for identifier in emails.iter().chain(&["unknown"]) {
+            clean_data_test(pool, "login_throttles", "identifier", identifier)
+                .await
+                .expect("Failed to clean up test data");
+        }
+    }
+
+    #[ntex::test]
+    async fn test_login_unknown_email_matches_wrong_password() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let emails = ["nobody@test.com", "login-uniform@test.com"];
+        clean_login_throttles(&pool, &emails).await;
+        insert_test_user(&pool, "login-uniform@test.com", "password").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(login_controller),
+        )
+        .await;
+
+        // Act
+        let unknown = test::call_service(
+            &app,
+            login_request("nobody@test.com", "password"),
+        )
+        .await;
+        let wrong = test::call_service(
+            &app,
+            login_request("login-uniform@test.com", "wrong"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(unknown.status(), http::StatusCode::UNAUTHORIZED);
+        assert_eq!(wrong.status(), http::StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            test::read_body(unknown).await,
+            test::read_body(wrong).await
+        );
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "login-uniform@test.com")
+            .await
+            .expect("Failed to clean up test data");
+        clean_login_throttles(&pool, &emails).await;
+    }
+
+    #[ntex::test]
+    async fn test_login_lockout_and_unlock() {
+        // Arrange
+        let pool = setup_test_db().await;
+        clean_login_throttles(&pool, &["login-lockout@test.com"]).await;
+        let user_id =
+            insert_test_user(&pool, "login-lockout@test.com", "password").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(login_controller)
+                .service(unlock_user_controller),
+        )
+        .await;
+
+        // Failures count for the account whatever the case of the email
+        for email in ["login-lockout@test.com", "Login-Lockout@Test.com"]
+            .into_iter()
+            .cycle()
+            .take(5)
+        {
+            let resp =
+                test::call_service(&app, login_request(email, "wrong")).await;
+            assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+        }
+
+        // Act
+        let locked = test::call_service(
+            &app,
+            login_request("login-lockout@test.com", "password"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(locked.status(), http::StatusCode::TOO_MANY_REQUESTS);
+        assert!(locked.headers().contains_key(header::RETRY_AFTER));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/users/{}/unlock", user_id))
+            .header(http::header::AUTHORIZATION, bearer_token(&["users:write"]))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+        let resp = test::call_service(
+            &app,
+            login_request("LOGIN-LOCKOUT@test.com", "password"),
+        )
+        .await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "login-lockout@test.com")
+            .await
+            .expect("Failed to clean up test data");
+        clean_login_throttles(&pool, &["login-lockout@test.com"]).await;
+    }
+}
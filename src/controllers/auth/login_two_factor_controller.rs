@@ -0,0 +1,175 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpRequest, HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::TwoFactorLoginRequestDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::auth_service::login_two_factor_service,
+};
+
+use super::login_controller::{client_ip, login_result_response};
+
+#[utoipa::path(
+    post,
+    path = "/auth/login/2fa",
+    tag = "Auth",
+    request_body = TwoFactorLoginRequestDTO,
+    responses(
+        (status = 200, description = "Login successful", body = TokenDTO),
+        (status = 401, description = "Invalid code or expired challenge", body = Error),
+        (status = 429, description = "Too many failed attempts, see the Retry-After header", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/login/2fa")]
+pub async fn login_two_factor_controller(
+    req: HttpRequest,
+    pool: State<PgPool>,
+    request: Json<TwoFactorLoginRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match login_two_factor_service(
+        pool.get_ref(),
+        request.into_inner(),
+        &client_ip(&req),
+    )
+    .await
+    {
+        Ok(result) => Ok(login_result_response(result)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::auth::login_controller::login_controller;
+    use crate::dtos::auth_dtos::{
+        LoginRequestDTO, TokenDTO, TwoFactorChallengeDTO,
+    };
+    use crate::dtos::two_factor_dtos::TotpCodeDTO;
+    use crate::services::two_factor_service::{
+        confirm_totp_service, enroll_totp_service,
+    };
+    use crate::tests::helpers::setup::{
+        clean_data_test, insert_test_user, setup_test_db, totp_code,
+    };
+
+    const EMAIL: &str = "login-2fa@test.com";
+
+    fn second_step_request(challenge_token: &str, code: &str) -> http::Request {
+        test::TestRequest::post()
+            .uri("/auth/login/2fa")
+            .set_json(&TwoFactorLoginRequestDTO {
+                challenge_token: String::from(challenge_token),
+                code: String::from(code),
+            })
+            .to_request()
+    }
+
+    #[ntex::test]
+    async fn test_login_two_factor_flow() {
+        // Arrange
+        let pool = setup_test_db().await;
+        for identifier in [EMAIL, "unknown"] {
+            clean_data_test(&pool, "login_throttles", "identifier", identifier)
+                .await
+                .expect("Failed to clean up test data");
+        }
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let enrollment = enroll_totp_service(&pool, user_id)
+            .await
+            .unwrap()
+            .expect("Two-factor authentication already enabled");
+        let recovery_codes = confirm_totp_service(
+            &pool,
+            user_id,
+            TotpCodeDTO {
+                code: totp_code(&enrollment.secret, 0),
+            },
+        )
+        .await
+        .unwrap()
+        .expect("Failed to confirm enrolment")
+        .recovery_codes;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(login_controller)
+                .service(login_two_factor_controller),
+        )
+        .await;
+        let start_login = || async {
+            let req = test::TestRequest::post()
+                .uri("/login")
+                .set_json(&LoginRequestDTO {
+                    email: String::from(EMAIL),
+                    password: String::from("password"),
+                })
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), http::StatusCode::ACCEPTED);
+            let challenge: TwoFactorChallengeDTO =
+                serde_json::from_slice(&test::read_body(resp).await)
+                    .expect("Failed to parse JSON");
+            challenge.challenge_token
+        };
+
+        // Act
+        let challenge = start_login().await;
+        let wrong = test::call_service(
+            &app,
+            second_step_request(&challenge, "not-a-code"),
+        )
+        .await;
+        // The next step's code, the current one was used to confirm
+        let code = totp_code(&enrollment.secret, 30);
+        let success =
+            test::call_service(&app, second_step_request(&challenge, &code))
+                .await;
+
+        let challenge = start_login().await;
+        let replayed =
+            test::call_service(&app, second_step_request(&challenge, &code))
+                .await;
+        let recovered = test::call_service(
+            &app,
+            second_step_request(&challenge, &recovery_codes[0]),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(wrong.status(), http::StatusCode::UNAUTHORIZED);
+        assert_eq!(success.status(), http::StatusCode::OK);
+        let token: TokenDTO =
+            serde_json::from_slice(&test::read_body(success).await)
+                .expect("Failed to parse JSON");
+        assert!(!token.token.is_empty());
+        assert_eq!(replayed.status(), http::StatusCode::UNAUTHORIZED);
+        assert_eq!(recovered.status(), http::StatusCode::OK);
+
+        let challenge = start_login().await;
+        let reused = test::call_service(
+            &app,
+            second_step_request(&challenge, &recovery_codes[0]),
+        )
+        .await;
+        assert_eq!(reused.status(), http::StatusCode::UNAUTHORIZED);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+        for identifier in [EMAIL, "unknown"] {
+            clean_data_test(&pool, "login_throttles", "identifier", identifier)
+                .await
+                .expect("Failed to clean up test data");
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::RefreshTokenRequestDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::auth_service::logout_all_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/logout/all",
+    tag = "Auth",
+    request_body = RefreshTokenRequestDTO,
+    responses(
+        (status = 204, description = "All sessions revoked"),
+        (status = 401, description = "Invalid or expired refresh token", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/logout/all")]
+pub async fn logout_all_controller(
+    pool: State<PgPool>,
+    refresh: Json<RefreshTokenRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match logout_all_service(pool.get_ref(), &refresh.refresh_token).await {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::Unauthorized().finish()),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::auth::logout_controller::logout_controller;
+    use crate::services::auth_service::refresh_service;
+    use crate::tests::helpers::setup::{
+        clean_data_test, insert_test_user, login_test_user, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_logout_revokes_sessions() {
+        // Arrange
+        let pool = setup_test_db().await;
+        insert_test_user(&pool, "logout-all@test.com", "password").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(logout_controller)
+                .service(logout_all_controller),
+        )
+        .await;
+
+        let mut sessions = Vec::new();
+        for _ in 0..3 {
+            let token =
+                login_test_user(&pool, "logout-all@test.com", "password").await;
+            sessions.push(token.refresh_token);
+        }
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/auth/logout")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: sessions[0].clone(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+        assert!(refresh_service(&pool, &sessions[0])
+            .await
+            .unwrap()
+            .is_none());
+
+        // A revoked session cannot log out the others
+        let req = test::TestRequest::post()
+            .uri("/auth/logout/all")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: sessions[0].clone(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::post()
+            .uri("/auth/logout/all")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: sessions[1].clone(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+        assert!(refresh_service(&pool, &sessions[2])
+            .await
+            .unwrap()
+            .is_none());
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "logout-all@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
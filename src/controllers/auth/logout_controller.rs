@@ -0,0 +1,33 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::RefreshTokenRequestDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::auth_service::logout_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/logout",
+    tag = "Auth",
+    request_body = RefreshTokenRequestDTO,
+    responses(
+        (status = 204, description = "Session revoked"),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/logout")]
+pub async fn logout_controller(
+    pool: State<PgPool>,
+    refresh: Json<RefreshTokenRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match logout_service(pool.get_ref(), &refresh.refresh_token).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
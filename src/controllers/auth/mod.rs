@@ -1 +1,9 @@
+pub mod forgot_password_controller;
+pub mod jwks_controller;
 pub mod login_controller;
+pub mod login_two_factor_controller;
+pub mod logout_all_controller;
+pub mod logout_controller;
+pub mod refresh_controller;
+pub mod reset_password_controller;
+pub mod verify_email_controller;
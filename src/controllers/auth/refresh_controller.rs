@@ -0,0 +1,127 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::RefreshTokenRequestDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::auth_service::refresh_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/refresh",
+    tag = "Auth",
+    request_body = RefreshTokenRequestDTO,
+    responses(
+        (status = 200, description = "Tokens refreshed", body = TokenDTO),
+        (status = 401, description = "Invalid, expired or reused refresh token", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/refresh")]
+pub async fn refresh_controller(
+    pool: State<PgPool>,
+    refresh: Json<RefreshTokenRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match refresh_service(pool.get_ref(), &refresh.refresh_token).await {
+        Ok(Some(token)) => Ok(HttpResponse::Ok().json(&token)),
+        Ok(None) => Ok(HttpResponse::Unauthorized().finish()),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::auth_dtos::TokenDTO;
+    use crate::tests::helpers::setup::{
+        clean_data_test, insert_test_user, login_test_user, setup_test_db,
+    };
+
+    #[ntex::test]
+    async fn test_refresh_rotates_and_detects_reuse() {
+        // Arrange
+        let pool = setup_test_db().await;
+        insert_test_user(&pool, "refresh-rotate@test.com", "password").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(refresh_controller),
+        )
+        .await;
+
+        let login =
+            login_test_user(&pool, "refresh-rotate@test.com", "password").await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/auth/refresh")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: login.refresh_token.clone(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let body = test::read_body(resp).await;
+        let rotated: TokenDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_ne!(rotated.refresh_token, login.refresh_token);
+
+        // Replaying the rotated token revokes the whole family
+        let req = test::TestRequest::post()
+            .uri("/auth/refresh")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: login.refresh_token,
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::post()
+            .uri("/auth/refresh")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: rotated.refresh_token,
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "refresh-rotate@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_refresh_unknown_token() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(refresh_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::post()
+            .uri("/auth/refresh")
+            .set_json(&RefreshTokenRequestDTO {
+                refresh_token: String::from("unknown"),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+}
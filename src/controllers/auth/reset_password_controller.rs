@@ -0,0 +1,146 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::ResetPasswordRequestDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::error_middleware::Error,
+    services::auth_service::reset_password_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/reset",
+    tag = "Auth",
+    request_body = ResetPasswordRequestDTO,
+    responses(
+        (status = 204, description = "Password changed, all sessions revoked"),
+        (status = 400, description = "Validation Error, or invalid, used or expired token", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/password/reset")]
+pub async fn reset_password_controller(
+    pool: State<PgPool>,
+    request: Json<ResetPasswordRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match reset_password_service(pool.get_ref(), request.into_inner()).await {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::BadRequest().json(&Error {
+            message: String::from("Invalid or expired reset token"),
+            backtrace: None,
+        })),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::auth::forgot_password_controller::forgot_password_controller;
+    use crate::dtos::auth_dtos::ForgotPasswordRequestDTO;
+    use crate::mailers::{memory_mailer::MemoryMailer, Mailer};
+    use crate::services::auth_service::refresh_service;
+    use crate::tests::helpers::setup::{
+        clean_data_test, insert_test_user, login_test_user, setup_test_db,
+        token_from_email,
+    };
+
+    fn forgot_request(email: &str) -> ntex::http::Request {
+        test::TestRequest::post()
+            .uri("/auth/password/forgot")
+            .set_json(&ForgotPasswordRequestDTO {
+                email: String::from(email),
+            })
+            .to_request()
+    }
+
+    fn reset_request(token: &str, password: &str) -> ntex::http::Request {
+        test::TestRequest::post()
+            .uri("/auth/password/reset")
+            .set_json(&ResetPasswordRequestDTO {
+                token: String::from(token),
+                password: String::from(password),
+            })
+            .to_request()
+    }
+
+    #[ntex::test]
+    async fn test_reset_password_flow() {
+        // Arrange
+        let pool = setup_test_db().await;
+        insert_test_user(&pool, "reset-password@test.com", "password").await;
+        let session =
+            login_test_user(&pool, "reset-password@test.com", "password").await;
+        let mailer = Arc::new(MemoryMailer::default());
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .state(mailer.clone() as Arc<dyn Mailer>)
+                .service(forgot_password_controller)
+                .service(reset_password_controller),
+        )
+        .await;
+
+        let resp =
+            test::call_service(&app, forgot_request("reset-password@test.com"))
+                .await;
+        assert_eq!(resp.status(), http::StatusCode::ACCEPTED);
+        let token =
+            token_from_email(&mailer.sent_to("reset-password@test.com")[0]);
+
+        // Act
+        let reset =
+            test::call_service(&app, reset_request(&token, "new-password"))
+                .await;
+        let replayed =
+            test::call_service(&app, reset_request(&token, "other-password"))
+                .await;
+
+        // Assert
+        assert_eq!(reset.status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(replayed.status(), http::StatusCode::BAD_REQUEST);
+        login_test_user(&pool, "reset-password@test.com", "new-password").await;
+        assert!(refresh_service(&pool, &session.refresh_token)
+            .await
+            .unwrap()
+            .is_none());
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "reset-password@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_forgot_password_unknown_email() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let mailer = Arc::new(MemoryMailer::default());
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .state(mailer.clone() as Arc<dyn Mailer>)
+                .service(forgot_password_controller),
+        )
+        .await;
+
+        // Act
+        let resp =
+            test::call_service(&app, forgot_request("nobody-reset@test.com"))
+                .await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::ACCEPTED);
+        assert!(mailer.sent_to("nobody-reset@test.com").is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::VerifyEmailRequestDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::error_middleware::Error,
+    services::auth_service::verify_email_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/auth/verify-email",
+    tag = "Auth",
+    request_body = VerifyEmailRequestDTO,
+    responses(
+        (status = 204, description = "Email address verified"),
+        (status = 400, description = "Invalid, used or expired token", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/auth/verify-email")]
+pub async fn verify_email_controller(
+    pool: State<PgPool>,
+    request: Json<VerifyEmailRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match verify_email_service(pool.get_ref(), request.into_inner()).await {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::BadRequest().json(&Error {
+            message: String::from("Invalid or expired verification token"),
+            backtrace: None,
+        })),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::mailers::memory_mailer::MemoryMailer;
+    use crate::repositories::users_repository::select_user_by_id;
+    use crate::services::auth_service::send_verification_email_service;
+    use crate::tests::helpers::setup::{
+        clean_data_test, insert_test_user, setup_test_db, token_from_email,
+    };
+
+    #[ntex::test]
+    async fn test_verify_email_is_single_use() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id =
+            insert_test_user(&pool, "verify-email@test.com", "password").await;
+        let mailer = MemoryMailer::default();
+        send_verification_email_service(
+            &pool,
+            &mailer,
+            user_id,
+            "verify-email@test.com",
+        )
+        .await
+        .expect("Failed to send verification email");
+        let token =
+            token_from_email(&mailer.sent_to("verify-email@test.com")[0]);
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(verify_email_controller),
+        )
+        .await;
+        let verify = || {
+            test::TestRequest::post()
+                .uri("/auth/verify-email")
+                .set_json(&VerifyEmailRequestDTO {
+                    token: token.clone(),
+                })
+                .to_request()
+        };
+
+        // Act
+        let first = test::call_service(&app, verify()).await;
+        let second = test::call_service(&app, verify()).await;
+
+        // Assert
+        assert_eq!(first.status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(second.status(), http::StatusCode::BAD_REQUEST);
+        let user = select_user_by_id(&pool, user_id).await.unwrap();
+        assert!(user.email_verified_at.is_some());
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "verify-email@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
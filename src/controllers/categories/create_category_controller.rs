@@ -3,6 +3,7 @@ use ntex::web::{self, HttpResponse};
 use sqlx::PgPool;
 
 use crate::dtos::category_dto::CreateCategoryDTO;
+use crate::extractors::permission_extractor::{Authorized, CategoriesWrite};
 use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
 use crate::services::categories_service::create_category_service;
 
@@ -14,12 +15,15 @@ use crate::services::categories_service::create_category_service;
     responses(
         (status = 201, description = "Category created successfully", body = CategoryDTO),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     ),
 )]
 #[web::post("/categories")]
 pub async fn create_category_controller(
     pool: State<PgPool>,
+    _auth: Authorized<CategoriesWrite>,
     category_dto: Json<CreateCategoryDTO>,
 ) -> Result<HttpResponse, web::Error> {
     match create_category_service(pool.get_ref(), category_dto.into_inner())
@@ -39,7 +43,7 @@ mod tests {
 
     use super::*;
     use crate::dtos::category_dto::CreateCategoryDTO;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::tests::helpers::setup::{bearer_token, setup_test_db};
 
     #[ntex::test]
     async fn test_create_category_success() {
@@ -55,12 +59,16 @@ mod tests {
         let category = CreateCategoryDTO {
             parent_id: Some(10),
             name: String::from("New Category"),
-            slug: String::from("new-category"),
+            slug: Some(String::from("new-category")),
             description: Some(String::from("New category description")),
         };
 
         // Act
         let req = test::TestRequest::post()
+            .header(
+                http::header::AUTHORIZATION,
+                bearer_token(&["categories:write"]),
+            )
             .uri("/categories")
             .set_json(&category)
             .to_request();
@@ -95,12 +103,16 @@ mod tests {
         let category = CreateCategoryDTO {
             parent_id: Some(10),
             name: String::new(), // Empty name, should trigger validation failure
-            slug: String::from("new-category"),
+            slug: Some(String::from("new-category")),
             description: Some(String::from("New category description")),
         };
 
         // Act
         let req = test::TestRequest::post()
+            .header(
+                http::header::AUTHORIZATION,
+                bearer_token(&["categories:write"]),
+            )
             .uri("/categories")
             .set_json(&category)
             .to_request();
@@ -124,12 +136,16 @@ mod tests {
         let category = CreateCategoryDTO {
             parent_id: Some(10),
             name: String::from("New Category"),
-            slug: String::from("new-category"),
+            slug: Some(String::from("new-category")),
             description: Some(String::from("New category description")),
         };
 
         // Act
         let req = test::TestRequest::post()
+            .header(
+                http::header::AUTHORIZATION,
+                bearer_token(&["categories:write"]),
+            )
             .uri("/categories")
             .set_json(&category)
             .to_request();
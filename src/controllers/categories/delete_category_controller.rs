@@ -8,6 +8,10 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::category_dto::DeleteCategoryIdsDTO,
+    extractors::{
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, CategoriesDelete},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::categories_service::delete_category_service,
 };
@@ -17,19 +21,34 @@ use crate::{
     path = "/categories",
     tag = "Categories",
     request_body = DeleteCategoryIdsDTO,
+    params(
+        ("If-Match" = String, Header, description = "ETag of the single category to delete, or * to delete any number of categories at any version")
+    ),
     responses(
         (status = 200, description = "Categories deleted", body = i32),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Category not found", body = Error),
+        (status = 412, description = "The category has changed since it was read", body = Error),
+        (status = 428, description = "Missing If-Match header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::delete("/categories")]
 pub async fn delete_category_controller(
     pool: State<PgPool>,
+    _auth: Authorized<CategoriesDelete>,
+    if_match: IfMatch,
     delete_category_ids_dto: Json<DeleteCategoryIdsDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    if_match
+        .require()
+        .map_err(|e| convert_anyhow_to_ntex(e.into()))?;
+
     match delete_category_service(
         pool.get_ref(),
+        &if_match,
         delete_category_ids_dto.into_inner(),
     )
     .await
@@ -6,7 +6,10 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::pagination_dto::PaginationParamsDTO,
+    dtos::{
+        category_dto::CategoryFiltersDTO, pagination_dto::PaginationParamsDTO,
+    },
+    extractors::permission_extractor::{Authorized, CategoriesRead},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::categories_service::get_all_categories_service,
 };
@@ -18,33 +21,36 @@ use crate::{
   params(
     ("page" = Option<i32>, Query, description = "The page number for pagination"),
     ("limit" = Option<i32>, Query, description = "The number of items per page"),
-    ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'name')"),
-    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ("sort" = Option<Vec<CategorySortField>>, Query, style = Form, explode = false, description = "Comma-separated fields to sort by, descending if prefixed with '-' (default '-id')"),
+    CategoryFiltersDTO
   ),
   security(
     ("api_key" = [])
 ),
   responses(
     (status = 200, description = "Get all categories", body = CategoryDTO),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
     (status = 500, description = "Internal Server Error", body = Error)
   ),
 )]
 #[web::get("/categories")]
 pub async fn get_all_categories_controller(
     pool: State<PgPool>,
+    _auth: Authorized<CategoriesRead>,
     params: Query<PaginationParamsDTO>,
+    filters: Query<CategoryFiltersDTO>,
 ) -> Result<HttpResponse, web::Error> {
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(25);
-    let sort_column = params.sort_column.as_deref().unwrap_or("id");
-    let sort_order = params.sort_order.as_deref().unwrap_or("desc");
+    let sort = params.sort.as_deref().unwrap_or("-id");
 
     match get_all_categories_service(
         pool.get_ref(),
+        filters.into_inner(),
         page,
         limit,
-        sort_column,
-        sort_order,
+        sort,
     )
     .await
     {
@@ -57,7 +63,10 @@ pub async fn get_all_categories_controller(
 mod tests {
     use crate::controllers::categories::get_all_categories_controller::get_all_categories_controller;
     use crate::dtos::category_dto::CategoryDTO;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::dtos::pagination_dto::PaginationDTO;
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -71,6 +80,10 @@ mod tests {
                 .service(get_all_categories_controller),
         )
         .await;
+        // Remove any leftover from a previous failed run
+        clean_data_test(&pool, "categories", "slug", "test-get-all-categories")
+            .await
+            .expect("Failed to clean up test data");
 
         sqlx::query!(
             r#"
@@ -79,7 +92,7 @@ mod tests {
             "#,
             None::<i32>,
             "Test Category",
-            "test-category",
+            "test-get-all-categories",
             "Description for test category"
         )
         .execute(&pool)
@@ -87,18 +100,25 @@ mod tests {
         .expect("Failed to insert test data");
 
         // Act
-        let req = test::TestRequest::get().uri("/categories").to_request();
+        let req = test::TestRequest::get()
+            .header(
+                http::header::AUTHORIZATION,
+                bearer_token(&["categories:read"]),
+            )
+            .uri("/categories")
+            .to_request();
         let resp = test::call_service(&app, req).await;
 
         // Assert
         assert_eq!(resp.status(), http::StatusCode::OK);
 
         let body = test::read_body(resp).await;
-        let response_body: Vec<CategoryDTO> =
+        let response_body: PaginationDTO<CategoryDTO> =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
 
-        let category = response_body.iter().find(|&cat| {
-            cat.name == "Test Category" && cat.slug == "test-category"
+        let category = response_body.data.iter().find(|&cat| {
+            cat.name == "Test Category"
+                && cat.slug.as_deref() == Some("test-get-all-categories")
         });
 
         assert!(category.is_some(), "Category not found in the response");
@@ -108,14 +128,8 @@ mod tests {
         );
 
         // Clean Data
-        sqlx::query!(
-            r#"
-            DELETE FROM categories WHERE name = $1
-            "#,
-            "Test Category"
-        )
-        .execute(&pool)
-        .await
-        .expect("Failed to clean up test data");
+        clean_data_test(&pool, "categories", "slug", "test-get-all-categories")
+            .await
+            .expect("Failed to clean up test data");
     }
 }
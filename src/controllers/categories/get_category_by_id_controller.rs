@@ -7,7 +7,11 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    extractors::permission_extractor::{Authorized, CategoriesRead},
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
     services::categories_service::get_category_by_id_service,
 };
 
@@ -19,7 +23,11 @@ use crate::{
         ("id" = i32, description = "ID of the category")
     ),
     responses(
-        (status = 200, description = "Category retrieved", body = CategoryDTO),
+        (status = 200, description = "Category retrieved", body = CategoryDTO, headers(
+            ("ETag" = String, description = "Version of the category")
+        )),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 404, description = "Category not found", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
@@ -27,12 +35,13 @@ use crate::{
 #[web::get("/categories/{id}")]
 pub async fn get_category_by_id_controller(
     pool: State<PgPool>,
+    _auth: Authorized<CategoriesRead>,
     category_id: Path<i32>,
 ) -> Result<HttpResponse, web::Error> {
     match get_category_by_id_service(pool.get_ref(), category_id.into_inner())
         .await
     {
-        Ok(category) => Ok(HttpResponse::Ok().json(&category)),
+        Ok(category) => Ok(ok_with_etag(&category, category.version)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
 }
@@ -7,8 +7,15 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::category_dto::{CategoryDTO, CreateCategoryDTO},
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    dtos::category_dto::CreateCategoryDTO,
+    extractors::{
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, CategoriesWrite},
+    },
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
     services::categories_service::update_category_service,
 };
 
@@ -18,30 +25,44 @@ use crate::{
     tag = "Categories",
     request_body = CreateCategoryDTO,
     params(
-        ("id" = i32, description = "ID of the category")
+        ("id" = i32, description = "ID of the category"),
+        ("If-Match" = String, Header, description = "ETag the category was read with, or * to overwrite any version")
     ),
     responses(
-        (status = 200, description = "Category updated", body = CategoryDTO),
+        (status = 200, description = "Category updated", body = CategoryDTO, headers(
+            ("ETag" = String, description = "New version of the category")
+        )),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 404, description = "Category not found", body = Error),
+        (status = 412, description = "The category has changed since it was read", body = Error),
+        (status = 428, description = "Missing If-Match header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::put("/categories/{id}")]
 pub async fn update_category_controller(
     pool: State<PgPool>,
+    _auth: Authorized<CategoriesWrite>,
     category_id: Path<i32>,
+    if_match: IfMatch,
     category_dto: Json<CreateCategoryDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    if_match
+        .require()
+        .map_err(|e| convert_anyhow_to_ntex(e.into()))?;
+
     match update_category_service(
         pool.get_ref(),
         category_id.into_inner(),
+        &if_match,
         category_dto.into_inner(),
     )
     .await
     {
         Ok(updated_category) => {
-            Ok(HttpResponse::Ok().json(&CategoryDTO::from(updated_category)))
+            Ok(ok_with_etag(&updated_category, updated_category.version))
         }
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
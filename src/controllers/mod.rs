@@ -1,6 +1,20 @@
+pub mod api_keys;
 pub mod auth;
 pub mod categories;
+pub mod permissions;
+pub mod post_drafts;
+pub mod post_revisions;
+pub mod post_trash;
 pub mod posts;
 pub mod posts_categories;
+pub mod profile;
+pub mod public_posts;
+pub mod redirects;
+pub mod roles;
+pub mod roles_permissions;
+pub mod scheduler;
+pub mod slugs;
 pub mod tags;
+pub mod two_factor;
 pub mod users;
+pub mod users_roles;
@@ -0,0 +1,42 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::permission_dto::CreatePermissionDTO,
+    extractors::permission_extractor::{Authorized, RolesWrite},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::permissions_service::create_permission_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/permissions",
+    tag = "Permissions",
+    request_body = CreatePermissionDTO,
+    responses(
+        (status = 201, description = "Create permission", body = PermissionDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/permissions")]
+pub async fn create_permission_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesWrite>,
+    permission_dto: Json<CreatePermissionDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match create_permission_service(pool.get_ref(), permission_dto.into_inner())
+        .await
+    {
+        Ok(created_permission) => {
+            Ok(HttpResponse::Created().json(&created_permission))
+        }
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,43 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::permission_dto::DeletePermissionIdsDTO,
+    extractors::permission_extractor::{Authorized, RolesDelete},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::permissions_service::delete_permission_by_id_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/permissions",
+    tag = "Permissions",
+    request_body = DeletePermissionIdsDTO,
+    responses(
+        (status = 200, description = "Permissions deleted", body = i32),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/permissions")]
+pub async fn delete_permission_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesDelete>,
+    delete_permission_ids_dto: Json<DeletePermissionIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match delete_permission_by_id_service(
+        pool.get_ref(),
+        delete_permission_ids_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,45 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::pagination_dto::PaginationParamsDTO,
+    extractors::permission_extractor::{Authorized, RolesRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::permissions_service::get_all_permissions_service,
+};
+
+#[utoipa::path(
+  get,
+  path = "/permissions",
+  tag = "Permissions",
+  params(
+    ("page" = Option<i32>, Query, description = "The page number for pagination"),
+    ("limit" = Option<i32>, Query, description = "The number of items per page"),
+    ("sort" = Option<Vec<PermissionSortField>>, Query, style = Form, explode = false, description = "Comma-separated fields to sort by, descending if prefixed with '-' (default '-id')")
+  ),
+  responses(
+    (status = 200, description = "Get all permissions", body = [PermissionDTO]),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
+    (status = 500, description = "Internal Server Error", body = Error)
+  ),
+)]
+#[web::get("/permissions")]
+pub async fn get_all_permissions_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesRead>,
+    params: Query<PaginationParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort = params.sort.as_deref().unwrap_or("-id");
+
+    match get_all_permissions_service(pool.get_ref(), page, limit, sort).await {
+        Ok(permissions) => Ok(HttpResponse::Ok().json(&permissions)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,44 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, RolesRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::permissions_service::get_permission_by_id_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/permissions/{id}",
+    tag = "Permissions",
+    params(
+        ("id" = i32, description = "ID of the permission")
+    ),
+    responses(
+        (status = 200, description = "Permission retrieved", body = PermissionDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Permission not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/permissions/{id}")]
+pub async fn get_permission_by_id_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesRead>,
+    permission_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match get_permission_by_id_service(
+        pool.get_ref(),
+        permission_id.into_inner(),
+    )
+    .await
+    {
+        Ok(permission) => Ok(HttpResponse::Ok().json(&permission)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
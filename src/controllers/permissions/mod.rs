@@ -0,0 +1,5 @@
+pub mod create_permission_controller;
+pub mod delete_permission_controller;
+pub mod get_all_permissions_controller;
+pub mod get_permission_by_id_controller;
+pub mod update_permission_controller;
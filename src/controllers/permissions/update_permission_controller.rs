@@ -0,0 +1,51 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::permission_dto::CreatePermissionDTO,
+    extractors::permission_extractor::{Authorized, RolesWrite},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::permissions_service::update_permission_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/permissions/{id}",
+    tag = "Permissions",
+    request_body = CreatePermissionDTO,
+    params(
+        ("id" = i32, description = "ID of the permission")
+    ),
+    responses(
+        (status = 200, description = "Permission updated", body = PermissionDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Permission not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/permissions/{id}")]
+pub async fn update_permission_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesWrite>,
+    permission_id: Path<i32>,
+    permission_dto: Json<CreatePermissionDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match update_permission_service(
+        pool.get_ref(),
+        permission_id.into_inner(),
+        permission_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(updated_permission) => {
+            Ok(HttpResponse::Ok().json(&updated_permission))
+        }
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,45 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, PostsWrite},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::error_middleware::Error,
+    services::post_drafts_service::delete_post_draft_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/posts/{id}/draft",
+    tag = "Post drafts",
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 204, description = "Draft discarded"),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "The post has no draft", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/posts/{id}/draft")]
+pub async fn delete_post_draft_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsWrite>,
+    post_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match delete_post_draft_service(pool.get_ref(), post_id.into_inner()).await
+    {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::NotFound().json(&Error {
+            message: String::from("The post has no draft"),
+            backtrace: None,
+        })),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
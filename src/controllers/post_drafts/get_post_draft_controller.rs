@@ -0,0 +1,39 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, PostsRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_drafts_service::get_post_draft_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/draft",
+    tag = "Post drafts",
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Working draft of the post", body = PostDraftDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "The post has no draft", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/{id}/draft")]
+pub async fn get_post_draft_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsRead>,
+    post_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match get_post_draft_service(pool.get_ref(), post_id.into_inner()).await {
+        Ok(draft) => Ok(HttpResponse::Ok().json(&draft)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
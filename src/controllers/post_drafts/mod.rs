@@ -0,0 +1,4 @@
+pub mod delete_post_draft_controller;
+pub mod get_post_draft_controller;
+pub mod publish_post_draft_controller;
+pub mod save_post_draft_controller;
@@ -0,0 +1,297 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    controllers::posts::require_publish_if_live,
+    extractors::{
+        current_user_extractor::CurrentUser,
+        permission_extractor::{Authorized, PostsWrite},
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_drafts_service::publish_post_draft_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/draft/publish",
+    tag = "Post drafts",
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Draft applied to the post", body = PostDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "The post has no draft", body = Error),
+        (status = 412, description = "The post changed since the draft was started", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/posts/{id}/draft/publish")]
+pub async fn publish_post_draft_controller(
+    pool: State<PgPool>,
+    auth: Authorized<PostsWrite>,
+    current_user: CurrentUser,
+    post_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    let post_id = post_id.into_inner();
+    require_publish_if_live(pool.get_ref(), &auth, post_id).await?;
+
+    match publish_post_draft_service(pool.get_ref(), &current_user, post_id)
+        .await
+    {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::post_drafts::{
+        get_post_draft_controller::get_post_draft_controller,
+        save_post_draft_controller::save_post_draft_controller,
+    };
+    use crate::dtos::post_draft_dtos::{PostDraftDTO, SavePostDraftDTO};
+    use crate::dtos::post_dto::PostDTO;
+    use crate::services::posts_services::get_post_by_id_service;
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "post-drafts@test.com";
+
+    #[ntex::test]
+    async fn test_publish_post_draft() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let post_id: i32 = sqlx::query_scalar(
+            r#"
+            INSERT INTO posts (
+                title, content, slug, author_id, status, date_published
+            )
+            VALUES ('Live post', 'Live content', 'live-post', $1,
+                'Published', CURRENT_TIMESTAMP)
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_post_draft_controller)
+                .service(save_post_draft_controller)
+                .service(publish_post_draft_controller),
+        )
+        .await;
+        let writer = bearer_token_for(user_id, &["posts:read", "posts:write"]);
+        let publisher = bearer_token_for(
+            user_id,
+            &["posts:read", "posts:write", "posts:publish"],
+        );
+        let publish = |token: &str| {
+            test::TestRequest::post()
+                .uri(&format!("/posts/{}/draft/publish", post_id))
+                .header(http::header::AUTHORIZATION, token)
+                .to_request()
+        };
+        let get_draft = || {
+            test::TestRequest::get()
+                .uri(&format!("/posts/{}/draft", post_id))
+                .header(http::header::AUTHORIZATION, writer.clone())
+                .to_request()
+        };
+
+        // Act
+        let saved = test::call_service(
+            &app,
+            test::TestRequest::put()
+                .uri(&format!("/posts/{}/draft", post_id))
+                .header(http::header::AUTHORIZATION, writer.clone())
+                .set_json(&SavePostDraftDTO {
+                    title: String::from("Live post"),
+                    content: String::from("Reworked content"),
+                    slug: None,
+                })
+                .to_request(),
+        )
+        .await;
+        let live_while_editing = get_post_by_id_service(&pool, post_id)
+            .await
+            .expect("Failed to get test post");
+        let draft = test::call_service(&app, get_draft()).await;
+        let forbidden = test::call_service(&app, publish(&writer)).await;
+        let published = test::call_service(&app, publish(&publisher)).await;
+        let draft_after = test::call_service(&app, get_draft()).await;
+        let published_again =
+            test::call_service(&app, publish(&publisher)).await;
+
+        // Assert
+        assert_eq!(saved.status(), http::StatusCode::OK);
+        assert_eq!(live_while_editing.content, "Live content");
+        assert_eq!(draft.status(), http::StatusCode::OK);
+        let draft: PostDraftDTO =
+            serde_json::from_slice(&test::read_body(draft).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(draft.content, "Reworked content");
+        assert_eq!(draft.slug, "live-post");
+        assert_eq!(draft.editor_id, Some(user_id));
+
+        assert_eq!(forbidden.status(), http::StatusCode::FORBIDDEN);
+        assert_eq!(published.status(), http::StatusCode::OK);
+        let post: PostDTO =
+            serde_json::from_slice(&test::read_body(published).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(post.content, "Reworked content");
+        assert_eq!(draft_after.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(published_again.status(), http::StatusCode::NOT_FOUND);
+
+        let revisions: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM post_revisions WHERE post_id = $1",
+        )
+        .bind(post_id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count revisions");
+        assert_eq!(revisions, 1);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_publish_stale_post_draft() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let email = "post-drafts-stale@test.com";
+        let user_id = insert_test_user(&pool, email, "password").await;
+        let post_id: i32 = sqlx::query_scalar(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id)
+            VALUES ('Stale post', 'Live content', 'stale-post', $1)
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+        sqlx::query(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id)
+            VALUES ('Reworked post', '', 'reworked-post', $1)
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test post");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(save_post_draft_controller)
+                .service(publish_post_draft_controller),
+        )
+        .await;
+        let token = bearer_token_for(user_id, &["posts:write"]);
+        let save = |content: &str, if_match: Option<&str>| {
+            let request = test::TestRequest::put()
+                .uri(&format!("/posts/{}/draft", post_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .set_json(&SavePostDraftDTO {
+                    title: String::from("Reworked post"),
+                    content: String::from(content),
+                    slug: None,
+                });
+            match if_match {
+                Some(etag) => request.header(http::header::IF_MATCH, etag),
+                None => request,
+            }
+            .to_request()
+        };
+        let publish = || {
+            test::TestRequest::post()
+                .uri(&format!("/posts/{}/draft/publish", post_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request()
+        };
+
+        // Act
+        let saved =
+            test::call_service(&app, save("Reworked content", None)).await;
+        // Someone else edits the post while the draft is open
+        sqlx::query(
+            "UPDATE posts SET content = 'Concurrent content', \
+             version = version + 1 WHERE id = $1",
+        )
+        .bind(post_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to update test post");
+        let stale = test::call_service(&app, publish()).await;
+        let autosaved =
+            test::call_service(&app, save("Reworked content", None)).await;
+        let rebased_on_stale =
+            test::call_service(&app, save("Merged content", Some("\"1\"")))
+                .await;
+        let rebased =
+            test::call_service(&app, save("Merged content", Some("\"2\"")))
+                .await;
+        let published = test::call_service(&app, publish()).await;
+
+        // Assert
+        assert_eq!(saved.status(), http::StatusCode::OK);
+        let draft: PostDraftDTO =
+            serde_json::from_slice(&test::read_body(saved).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(draft.base_version, 1);
+        assert_eq!(draft.slug, "reworked-post-2");
+        assert_eq!(stale.status(), http::StatusCode::PRECONDITION_FAILED);
+        let draft: PostDraftDTO =
+            serde_json::from_slice(&test::read_body(autosaved).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(draft.base_version, 1);
+        assert_eq!(
+            rebased_on_stale.status(),
+            http::StatusCode::PRECONDITION_FAILED
+        );
+        assert_eq!(rebased.status(), http::StatusCode::OK);
+        let draft: PostDraftDTO =
+            serde_json::from_slice(&test::read_body(rebased).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(draft.base_version, 2);
+        assert_eq!(published.status(), http::StatusCode::OK);
+        let post: PostDTO =
+            serde_json::from_slice(&test::read_body(published).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(post.content, "Merged content");
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", email)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,59 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::post_draft_dtos::SavePostDraftDTO,
+    extractors::{
+        current_user_extractor::CurrentUser,
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, PostsWrite},
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_drafts_service::save_post_draft_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/posts/{id}/draft",
+    tag = "Post drafts",
+    request_body = SavePostDraftDTO,
+    params(
+        ("id" = i32, description = "ID of the post"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the post the draft is rebased onto, once its changes are merged")
+    ),
+    responses(
+        (status = 200, description = "Draft saved, the post is unchanged", body = PostDraftDTO),
+        (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 412, description = "The post is not at the version to rebase onto", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/posts/{id}/draft")]
+pub async fn save_post_draft_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsWrite>,
+    current_user: CurrentUser,
+    post_id: Path<i32>,
+    if_match: IfMatch,
+    draft_dto: Json<SavePostDraftDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match save_post_draft_service(
+        pool.get_ref(),
+        &current_user,
+        post_id.into_inner(),
+        &if_match,
+        draft_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(draft) => Ok(HttpResponse::Ok().json(&draft)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,44 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, PostsRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_revisions_service::get_post_revision_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/revisions/{number}",
+    tag = "Post revisions",
+    params(
+        ("id" = i32, description = "ID of the post"),
+        ("number" = i32, description = "Number of the revision")
+    ),
+    responses(
+        (status = 200, description = "Revision retrieved", body = PostRevisionDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Revision not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/{id}/revisions/{number}")]
+pub async fn get_post_revision_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsRead>,
+    path: Path<(i32, i32)>,
+) -> Result<HttpResponse, web::Error> {
+    let (post_id, revision_number) = path.into_inner();
+
+    match get_post_revision_service(pool.get_ref(), post_id, revision_number)
+        .await
+    {
+        Ok(revision) => Ok(HttpResponse::Ok().json(&revision)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
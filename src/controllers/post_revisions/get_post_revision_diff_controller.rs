@@ -0,0 +1,50 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, PostsRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_revisions_service::get_post_revision_diff_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/revisions/{from}/diff/{to}",
+    tag = "Post revisions",
+    params(
+        ("id" = i32, description = "ID of the post"),
+        ("from" = i32, description = "Number of the revision to compare from"),
+        ("to" = i32, description = "Number of the revision to compare to")
+    ),
+    responses(
+        (status = 200, description = "Line diffs of the title, slug and content", body = PostRevisionDiffDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Revision not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/{id}/revisions/{from}/diff/{to}")]
+pub async fn get_post_revision_diff_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsRead>,
+    path: Path<(i32, i32, i32)>,
+) -> Result<HttpResponse, web::Error> {
+    let (post_id, from_revision, to_revision) = path.into_inner();
+
+    match get_post_revision_diff_service(
+        pool.get_ref(),
+        post_id,
+        from_revision,
+        to_revision,
+    )
+    .await
+    {
+        Ok(diff) => Ok(HttpResponse::Ok().json(&diff)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,40 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, PostsRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_revisions_service::get_post_revisions_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/posts/{id}/revisions",
+    tag = "Post revisions",
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Revisions of the post, the latest first", body = [PostRevisionSummaryDTO]),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/{id}/revisions")]
+pub async fn get_post_revisions_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsRead>,
+    post_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match get_post_revisions_service(pool.get_ref(), post_id.into_inner()).await
+    {
+        Ok(revisions) => Ok(HttpResponse::Ok().json(&revisions)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
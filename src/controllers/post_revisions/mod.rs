@@ -0,0 +1,4 @@
+pub mod get_post_revision_controller;
+pub mod get_post_revision_diff_controller;
+pub mod get_post_revisions_controller;
+pub mod restore_post_revision_controller;
@@ -0,0 +1,264 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    controllers::posts::require_publish_if_live,
+    extractors::{
+        current_user_extractor::CurrentUser,
+        permission_extractor::{Authorized, PostsWrite},
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_revisions_service::restore_post_revision_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/revisions/{number}/restore",
+    tag = "Post revisions",
+    params(
+        ("id" = i32, description = "ID of the post"),
+        ("number" = i32, description = "Number of the revision to restore")
+    ),
+    responses(
+        (status = 200, description = "Revision restored as a new revision", body = PostDTO),
+        (status = 400, description = "The revision holds invalid blocks", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Revision not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/posts/{id}/revisions/{number}/restore")]
+pub async fn restore_post_revision_controller(
+    pool: State<PgPool>,
+    auth: Authorized<PostsWrite>,
+    current_user: CurrentUser,
+    path: Path<(i32, i32)>,
+) -> Result<HttpResponse, web::Error> {
+    let (post_id, revision_number) = path.into_inner();
+
+    require_publish_if_live(pool.get_ref(), &auth, post_id).await?;
+
+    match restore_post_revision_service(
+        pool.get_ref(),
+        &current_user,
+        post_id,
+        revision_number,
+    )
+    .await
+    {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::post_revisions::{
+        get_post_revision_diff_controller::get_post_revision_diff_controller,
+        get_post_revisions_controller::get_post_revisions_controller,
+    };
+    use crate::dtos::post_dto::{CreatePostDTO, PostDTO};
+    use crate::dtos::post_revision_dtos::{
+        PostRevisionDiffDTO, PostRevisionSummaryDTO,
+    };
+    use crate::extractors::if_match_extractor::IfMatch;
+    use crate::handlers::line_diff_handler::DiffOperation;
+    use crate::models::posts_model::{ContentFormat, PostsStatus};
+    use crate::services::posts_services::{
+        create_post_service, update_post_service,
+    };
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "post-revisions@test.com";
+
+    fn post_dto(content: &str) -> CreatePostDTO {
+        CreatePostDTO {
+            title: String::from("Revised post"),
+            content: String::from(content),
+            content_format: ContentFormat::default(),
+            content_blocks: None,
+            slug: Some(String::from("revised-post")),
+            status: PostsStatus::Draft,
+            date_published: None,
+            categories_ids: vec![],
+            tag_ids: vec![],
+            tags: vec![],
+        }
+    }
+
+    #[ntex::test]
+    async fn test_post_revisions() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let current_user = CurrentUser {
+            id: user_id,
+            api_key_id: None,
+        };
+        let post = create_post_service(
+            &pool,
+            &current_user,
+            post_dto("first line\nsecond line"),
+        )
+        .await
+        .expect("Failed to create test post");
+        let post_id = post.id.unwrap();
+        update_post_service(
+            &pool,
+            &current_user,
+            post_id,
+            &IfMatch::default(),
+            CreatePostDTO {
+                content_format: ContentFormat::Markdown,
+                ..post_dto("first line\nchanged line")
+            },
+        )
+        .await
+        .expect("Failed to update test post");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_post_revisions_controller)
+                .service(get_post_revision_diff_controller)
+                .service(restore_post_revision_controller),
+        )
+        .await;
+        let token = bearer_token_for(user_id, &["posts:read", "posts:write"]);
+
+        // Act
+        let diff = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&format!("/posts/{}/revisions/1/diff/2", post_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request(),
+        )
+        .await;
+        let restored = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri(&format!("/posts/{}/revisions/1/restore", post_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request(),
+        )
+        .await;
+        let revisions = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&format!("/posts/{}/revisions", post_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request(),
+        )
+        .await;
+        let unknown = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri(&format!("/posts/{}/revisions/99/restore", post_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request(),
+        )
+        .await;
+        sqlx::query(
+            r#"
+            INSERT INTO post_revisions (
+                post_id, revision_number, title, content, content_format,
+                slug, status, editor_id
+            )
+            VALUES ($1, 50, 'Revised post', '{"version": 1, "blocks": [{}]}',
+                'blocks', 'revised-post', 'Draft', $2)
+            "#,
+        )
+        .bind(post_id)
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to insert test revision");
+        let invalid_blocks = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri(&format!("/posts/{}/revisions/50/restore", post_id))
+                .header(http::header::AUTHORIZATION, token)
+                .to_request(),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(diff.status(), http::StatusCode::OK);
+        let diff: PostRevisionDiffDTO =
+            serde_json::from_slice(&test::read_body(diff).await)
+                .expect("Failed to parse JSON");
+        let content: Vec<(DiffOperation, &str)> = diff
+            .content
+            .iter()
+            .map(|line| (line.operation, line.line.as_str()))
+            .collect();
+        assert_eq!(
+            content,
+            vec![
+                (DiffOperation::Equal, "first line"),
+                (DiffOperation::Delete, "second line"),
+                (DiffOperation::Insert, "changed line"),
+            ]
+        );
+        assert!(diff
+            .title
+            .iter()
+            .all(|line| line.operation == DiffOperation::Equal));
+
+        assert_eq!(restored.status(), http::StatusCode::OK);
+        let post: PostDTO =
+            serde_json::from_slice(&test::read_body(restored).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(post.content, "first line\nsecond line");
+        assert_eq!(post.content_format, ContentFormat::Html);
+
+        assert_eq!(revisions.status(), http::StatusCode::OK);
+        let revisions: Vec<PostRevisionSummaryDTO> =
+            serde_json::from_slice(&test::read_body(revisions).await)
+                .expect("Failed to parse JSON");
+        let numbers: Vec<i32> = revisions
+            .iter()
+            .map(|revision| revision.revision_number)
+            .collect();
+        assert_eq!(numbers, vec![3, 2, 1]);
+        assert!(revisions
+            .iter()
+            .all(|revision| revision.editor_id == Some(user_id)));
+        assert_eq!(unknown.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(invalid_blocks.status(), http::StatusCode::BAD_REQUEST);
+
+        let formats: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT content_format::TEXT FROM post_revisions
+            WHERE post_id = $1 AND revision_number <= 2
+            ORDER BY revision_number
+            "#,
+        )
+        .bind(post_id)
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select revisions");
+        assert_eq!(formats, vec!["html", "markdown"]);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
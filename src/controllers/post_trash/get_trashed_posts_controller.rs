@@ -0,0 +1,43 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::pagination_dto::PaginationParamsDTO,
+    extractors::permission_extractor::{Authorized, PostsDelete},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_trash_service::get_trashed_posts_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/posts/trash",
+    tag = "Post trash",
+    params(
+        ("page" = Option<i32>, Query, description = "The page number for pagination"),
+        ("limit" = Option<i32>, Query, description = "The number of items per page")
+    ),
+    responses(
+        (status = 200, description = "Trashed posts, the last trashed first", body = PostDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/trash")]
+pub async fn get_trashed_posts_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsDelete>,
+    params: Query<PaginationParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+
+    match get_trashed_posts_service(pool.get_ref(), page, limit).await {
+        Ok(posts) => Ok(HttpResponse::Ok().json(&posts)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,3 @@
+pub mod get_trashed_posts_controller;
+pub mod purge_trashed_posts_controller;
+pub mod restore_trashed_posts_controller;
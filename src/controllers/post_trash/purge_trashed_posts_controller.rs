@@ -0,0 +1,188 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::post_dto::TrashedPostIdsDTO,
+    extractors::permission_extractor::{Authorized, PostsDelete},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_trash_service::purge_trashed_posts_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/posts/trash",
+    tag = "Post trash",
+    request_body = TrashedPostIdsDTO,
+    responses(
+        (status = 200, description = "IDs of the permanently deleted posts", body = i32),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/posts/trash")]
+pub async fn purge_trashed_posts_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsDelete>,
+    trashed_post_ids_dto: Json<TrashedPostIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match purge_trashed_posts_service(
+        pool.get_ref(),
+        trashed_post_ids_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(purged_ids) => Ok(HttpResponse::Ok().json(&purged_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::post_trash::{
+        get_trashed_posts_controller::get_trashed_posts_controller,
+        restore_trashed_posts_controller::restore_trashed_posts_controller,
+    };
+    use crate::controllers::posts::{
+        delete_post_controller::delete_post_controller,
+        get_post_by_id_controller::get_post_by_id_controller,
+    };
+    use crate::dtos::pagination_dto::PaginationDTO;
+    use crate::dtos::post_dto::{DeletePostIdsDTO, PostDTO};
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "post-trash@test.com";
+
+    #[ntex::test]
+    async fn test_post_trash() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let post_id: i32 = sqlx::query_scalar(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id, status)
+            VALUES ('Trashed post', 'Content', 'trashed-post', $1, 'Draft')
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_trashed_posts_controller)
+                .service(restore_trashed_posts_controller)
+                .service(purge_trashed_posts_controller)
+                .service(get_post_by_id_controller)
+                .service(delete_post_controller),
+        )
+        .await;
+        let token = bearer_token_for(user_id, &["posts:read", "posts:delete"]);
+        let get_post = || {
+            test::TestRequest::get()
+                .uri(&format!("/posts/{}", post_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request()
+        };
+        let ids_request = |request: test::TestRequest| {
+            request
+                .header(http::header::AUTHORIZATION, token.clone())
+                .set_json(&DeletePostIdsDTO { ids: vec![post_id] })
+                .to_request()
+        };
+        let ids = |body: ntex::util::Bytes| -> Vec<i32> {
+            serde_json::from_slice(&body).expect("Failed to parse JSON")
+        };
+
+        // Act
+        let trashed = test::call_service(
+            &app,
+            ids_request(
+                test::TestRequest::delete()
+                    .uri("/posts")
+                    .header(http::header::IF_MATCH, "*"),
+            ),
+        )
+        .await;
+        let hidden = test::call_service(&app, get_post()).await;
+        let trash = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/posts/trash")
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request(),
+        )
+        .await;
+        let restored = test::call_service(
+            &app,
+            ids_request(test::TestRequest::post().uri("/posts/trash/restore")),
+        )
+        .await;
+        let visible = test::call_service(&app, get_post()).await;
+        let purge_live = test::call_service(
+            &app,
+            ids_request(test::TestRequest::delete().uri("/posts/trash")),
+        )
+        .await;
+        test::call_service(
+            &app,
+            ids_request(
+                test::TestRequest::delete()
+                    .uri("/posts")
+                    .header(http::header::IF_MATCH, "*"),
+            ),
+        )
+        .await;
+        let purged = test::call_service(
+            &app,
+            ids_request(test::TestRequest::delete().uri("/posts/trash")),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(ids(test::read_body(trashed).await), vec![post_id]);
+        assert_eq!(hidden.status(), http::StatusCode::NOT_FOUND);
+        let trash: PaginationDTO<PostDTO> =
+            serde_json::from_slice(&test::read_body(trash).await)
+                .expect("Failed to parse JSON");
+        let trashed_post = trash
+            .data
+            .iter()
+            .find(|post| post.id == Some(post_id))
+            .expect("The trashed post should be listed");
+        assert!(trashed_post.deleted_at.is_some());
+        assert_eq!(ids(test::read_body(restored).await), vec![post_id]);
+        assert_eq!(visible.status(), http::StatusCode::OK);
+        assert!(ids(test::read_body(purge_live).await).is_empty());
+        assert_eq!(ids(test::read_body(purged).await), vec![post_id]);
+        let remaining: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM posts WHERE id = $1")
+                .bind(post_id)
+                .fetch_one(&pool)
+                .await
+                .expect("Failed to count posts");
+        assert_eq!(remaining, 0);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,42 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::post_dto::TrashedPostIdsDTO,
+    extractors::permission_extractor::{Authorized, PostsDelete},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::post_trash_service::restore_trashed_posts_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/posts/trash/restore",
+    tag = "Post trash",
+    request_body = TrashedPostIdsDTO,
+    responses(
+        (status = 200, description = "IDs of the restored posts", body = i32),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/posts/trash/restore")]
+pub async fn restore_trashed_posts_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsDelete>,
+    trashed_post_ids_dto: Json<TrashedPostIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match restore_trashed_posts_service(
+        pool.get_ref(),
+        trashed_post_ids_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(restored_ids) => Ok(HttpResponse::Ok().json(&restored_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -4,6 +4,10 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::post_dto::CreatePostDTO,
+    extractors::{
+        current_user_extractor::CurrentUser,
+        permission_extractor::{Authorized, PostsWrite},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::posts_services::create_post_service,
 };
@@ -16,18 +20,242 @@ use crate::{
     responses(
         (status = 201, description = "Post created successfully", body = PostDTO),
         (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 409, description = "A post is created as a draft or pending review", body = Error),
         (status = 500, description = "Internal server error", body = Error)
     )
 )]
 #[web::post("/posts")]
 pub async fn create_post_controller(
     pool: State<PgPool>,
+    _auth: Authorized<PostsWrite>,
+    current_user: CurrentUser,
     post_dto: Json<CreatePostDTO>,
 ) -> Result<HttpResponse, web::Error> {
-    match create_post_service(pool.get_ref(), post_dto.into_inner()).await {
+    match create_post_service(
+        pool.get_ref(),
+        &current_user,
+        post_dto.into_inner(),
+    )
+    .await
+    {
         Ok(post_with_categories) => {
             Ok(HttpResponse::Created().json(&post_with_categories))
         }
         Err(err) => Err(convert_anyhow_to_ntex(err)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::post_dto::PostDTO;
+    use crate::models::{
+        post_blocks_model::{PostBlock, PostBlocks},
+        posts_model::{ContentFormat, PostsStatus},
+    };
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "create-post@test.com";
+    const BLOCKS_EMAIL: &str = "create-post-blocks@test.com";
+
+    fn post_dto(slug: &str, categories_ids: Vec<i32>) -> CreatePostDTO {
+        CreatePostDTO {
+            title: String::from("Created post"),
+            content: String::from("Created *post* content"),
+            content_format: ContentFormat::Markdown,
+            content_blocks: None,
+            slug: Some(String::from(slug)),
+            status: PostsStatus::Pending,
+            date_published: None,
+            categories_ids,
+            tag_ids: vec![],
+            tags: vec![String::from("Created Post Tag")],
+        }
+    }
+
+    #[ntex::test]
+    async fn test_create_post_with_terms() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let category_id: i32 = sqlx::query_scalar(
+            "INSERT INTO categories (name, slug) VALUES ($1, $2) RETURNING id",
+        )
+        .bind("Created Post Category")
+        .bind("created-post-category")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test category");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(create_post_controller),
+        )
+        .await;
+        let create = |dto: CreatePostDTO| {
+            test::TestRequest::post()
+                .uri("/posts")
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token_for(user_id, &["posts:write"]),
+                )
+                .set_json(&dto)
+                .to_request()
+        };
+
+        // Act
+        let resp = test::call_service(
+            &app,
+            create(post_dto("created-post", vec![category_id])),
+        )
+        .await;
+        let rejected = test::call_service(
+            &app,
+            create(post_dto("rejected-post", vec![category_id, i32::MAX])),
+        )
+        .await;
+        let live = test::call_service(
+            &app,
+            create(CreatePostDTO {
+                status: PostsStatus::Published,
+                ..post_dto("live-post", vec![])
+            }),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+        let post: PostDTO =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(post.author_id, user_id);
+        assert_eq!(post.status, PostsStatus::Pending);
+        assert_eq!(post.content_format, ContentFormat::Markdown);
+        assert_eq!(
+            post.content_html.as_deref(),
+            Some("<p>Created <em>post</em> content</p>\n")
+        );
+        let categories = post.categories.expect("Missing categories");
+        assert_eq!(categories[0]["id"], category_id);
+        let tags = post.tags.expect("Missing tags");
+        assert_eq!(tags[0]["slug"], "created-post-tag");
+
+        // Nothing of a rejected post is saved
+        assert_eq!(rejected.status(), http::StatusCode::BAD_REQUEST);
+        let rejected_posts: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM posts WHERE slug = 'rejected-post'",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count posts");
+        assert_eq!(rejected_posts, 0);
+
+        // Posts only go live through the workflow, whatever the permissions
+        assert_eq!(live.status(), http::StatusCode::CONFLICT);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "categories", "slug", "created-post-category")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "tags", "slug", "created-post-tag")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_create_post_with_blocks() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, BLOCKS_EMAIL, "password").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(create_post_controller),
+        )
+        .await;
+        let create = |slug: &str, blocks: PostBlocks| {
+            let mut dto = post_dto(slug, vec![]);
+            dto.content = String::new();
+            dto.content_blocks = Some(blocks);
+            dto.status = PostsStatus::Draft;
+            dto.tags = vec![];
+            test::TestRequest::post()
+                .uri("/posts")
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token_for(user_id, &["posts:write"]),
+                )
+                .set_json(&dto)
+                .to_request()
+        };
+        let blocks = PostBlocks {
+            version: 1,
+            blocks: vec![
+                PostBlock::Heading {
+                    level: 2,
+                    text: String::from("Blocks"),
+                },
+                PostBlock::Paragraph {
+                    text: String::from("<b>Escaped</b>"),
+                },
+            ],
+        };
+        let mut invalid_blocks = blocks.clone();
+        invalid_blocks.blocks.push(PostBlock::Heading {
+            level: 9,
+            text: String::from("Too deep"),
+        });
+
+        // Act
+        let resp =
+            test::call_service(&app, create("blocks-post", blocks.clone()))
+                .await;
+        let rejected = test::call_service(
+            &app,
+            create("rejected-blocks-post", invalid_blocks),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+        let post: PostDTO =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(post.content_format, ContentFormat::Blocks);
+        assert_eq!(post.content_blocks, Some(blocks));
+        assert_eq!(
+            post.content_html.as_deref(),
+            Some("<h2>Blocks</h2>\n<p>&lt;b&gt;Escaped&lt;/b&gt;</p>")
+        );
+        assert_eq!(
+            post.content_text.as_deref(),
+            Some("Blocks\n\n<b>Escaped</b>")
+        );
+        assert_eq!(rejected.status(), http::StatusCode::BAD_REQUEST);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", BLOCKS_EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
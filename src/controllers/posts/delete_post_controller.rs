@@ -7,6 +7,10 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::post_dto::DeletePostIdsDTO,
+    extractors::{
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, PostsDelete},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::posts_services::delete_post_service,
 };
@@ -16,19 +20,37 @@ use crate::{
     path = "/posts",
     tag = "Posts",
     request_body = DeletePostIdsDTO,
+    params(
+        ("If-Match" = String, Header, description = "ETag of the single post to trash, or * to trash any number of posts at any version")
+    ),
     responses(
-        (status = 200, description = "Posts deleted", body = i32),
+        (status = 200, description = "Posts moved to the trash", body = i32),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 412, description = "The post has changed since it was read", body = Error),
+        (status = 428, description = "Missing If-Match header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::delete("/posts")]
 pub async fn delete_post_controller(
     pool: State<PgPool>,
+    _auth: Authorized<PostsDelete>,
+    if_match: IfMatch,
     delete_post_ids_dto: Json<DeletePostIdsDTO>,
 ) -> Result<HttpResponse, web::Error> {
-    match delete_post_service(pool.get_ref(), delete_post_ids_dto.into_inner())
-        .await
+    if_match
+        .require()
+        .map_err(|e| convert_anyhow_to_ntex(e.into()))?;
+
+    match delete_post_service(
+        pool.get_ref(),
+        &if_match,
+        delete_post_ids_dto.into_inner(),
+    )
+    .await
     {
         Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
@@ -43,7 +65,9 @@ mod tests {
 
     use super::*;
     use crate::models::posts_model::PostsStatus;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, insert_test_user, setup_test_db,
+    };
 
     #[ntex::test]
     async fn test_delete_post_success() {
@@ -56,34 +80,8 @@ mod tests {
         )
         .await;
 
-        // Check if a user already exists
-        let existing_user_id: Option<i32> = sqlx::query_scalar!(
-            r#"
-            SELECT id FROM users LIMIT 1
-            "#
-        )
-        .fetch_optional(&pool)
-        .await
-        .expect("Failed to query users");
-
-        // If no user exists, create one
-        let user_id = if let Some(id) = existing_user_id {
-            id
-        } else {
-            sqlx::query!(
-                r#"
-                INSERT INTO users (username, email) 
-                VALUES ($1, $2)
-                RETURNING id
-                "#,
-                "testuser",
-                "testuser@example.com"
-            )
-            .fetch_one(&pool)
-            .await
-            .expect("Failed to insert test user")
-            .id
-        };
+        let user_id =
+            insert_test_user(&pool, "delete-post@test.com", "password").await;
 
         let inserted_post = sqlx::query!(
             r#"
@@ -102,21 +100,45 @@ mod tests {
         .expect("Failed to insert test data");
 
         // Act
-        let req = test::TestRequest::delete()
-            .uri(&format!("/posts"))
-            .set_json(&DeletePostIdsDTO {
-                ids: vec![inserted_post.id],
-            })
+        let delete_request = || {
+            test::TestRequest::delete()
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token(&["posts:delete"]),
+                )
+                .uri("/posts")
+                .set_json(&DeletePostIdsDTO {
+                    ids: vec![inserted_post.id],
+                })
+        };
+        let unconditional =
+            test::call_service(&app, delete_request().to_request()).await;
+        let req = delete_request()
+            .header(http::header::IF_MATCH, "\"1\"")
             .to_request();
         let resp = test::call_service(&app, req).await;
 
         // Assert
+        assert_eq!(
+            unconditional.status(),
+            http::StatusCode::PRECONDITION_REQUIRED
+        );
         assert_eq!(resp.status(), http::StatusCode::OK);
 
         // Check that the response contains the deleted post ID
         let body = test::read_body(resp).await;
-        let deleted_ids: DeletePostIdsDTO =
+        let deleted_ids: Vec<i32> =
             from_slice(&body).expect("Failed to parse response body");
-        assert_eq!(deleted_ids.ids, vec![inserted_post.id]);
+        assert_eq!(deleted_ids, vec![inserted_post.id]);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", "delete-post@test.com")
+            .await
+            .expect("Failed to clean up test data");
     }
 }
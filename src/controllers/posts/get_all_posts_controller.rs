@@ -6,7 +6,8 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::pagination_dto::PaginationParamsDTO,
+    dtos::{pagination_dto::PaginationParamsDTO, post_dto::PostFiltersDTO},
+    extractors::permission_extractor::{Authorized, PostsRead},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::posts_services::get_all_posts_service,
 };
@@ -18,31 +19,34 @@ use crate::{
   params(
     ("page" = Option<i32>, Query, description = "The page number for pagination"),
     ("limit" = Option<i32>, Query, description = "The number of items per page"),
-    ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'name')"),
-    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ("sort" = Option<Vec<PostSortField>>, Query, style = Form, explode = false, description = "Comma-separated fields to sort by, descending if prefixed with '-' (default '-id')"),
+    PostFiltersDTO
   ),
     responses(
         (status = 200, description = "Get all posts", body = PostDTO),
         (status = 400, description = "Bad Request"),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error")
     )
 )]
 #[web::get("/posts")]
 pub async fn get_all_posts_controller(
     pool: State<PgPool>,
+    _auth: Authorized<PostsRead>,
     params: Query<PaginationParamsDTO>,
+    filters: Query<PostFiltersDTO>,
 ) -> Result<HttpResponse, web::Error> {
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(25);
-    let sort_column = params.sort_column.as_deref().unwrap_or("id");
-    let sort_order = params.sort_order.as_deref().unwrap_or("desc");
+    let sort = params.sort.as_deref().unwrap_or("-id");
 
     match get_all_posts_service(
         pool.get_ref(),
+        filters.into_inner(),
         page,
         limit,
-        sort_column,
-        sort_order,
+        sort,
     )
     .await
     {
@@ -54,9 +58,16 @@ pub async fn get_all_posts_controller(
 #[cfg(test)]
 mod tests {
     use crate::controllers::posts::get_all_posts_controller::get_all_posts_controller;
-    use crate::dtos::post_dto::PostDTO;
-    use crate::models::posts_model::PostsStatus;
-    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+    use crate::dtos::pagination_dto::PaginationDTO;
+    use crate::dtos::post_dto::{CreatePostDTO, PostDTO};
+    use crate::extractors::current_user_extractor::CurrentUser;
+    use crate::models::posts_model::{ContentFormat, PostsStatus};
+    use crate::services::posts_services::{
+        create_post_service, transition_post_service,
+    };
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, insert_test_user, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -71,54 +82,28 @@ mod tests {
         )
         .await;
 
-        // Check if a user already exists
-        let existing_user_id: Option<i32> = sqlx::query_scalar!(
-            r#"
-            SELECT id FROM users LIMIT 1
-            "#
-        )
-        .fetch_optional(&pool)
-        .await
-        .expect("Failed to query users");
-
-        // If no user exists, create one
-        let user_id = if let Some(id) = existing_user_id {
-            id
-        } else {
-            sqlx::query!(
-                r#"
-                INSERT INTO users (username, email) 
-                VALUES ($1, $2)
-                RETURNING id
-                "#,
-                "testuser",
-                "testuser@example.com"
-            )
-            .fetch_one(&pool)
-            .await
-            .expect("Failed to insert test user")
-            .id
-        };
+        let user_id =
+            insert_test_user(&pool, "get-all-posts@test.com", "password").await;
 
-        let inserted_post = sqlx::query!(
+        sqlx::query!(
             r#"
             INSERT INTO posts (title, content, slug, author_id, status) 
             VALUES ($1, $2, $3, $4, $5::posts_status)
-            RETURNING id
             "#,
             "Test Post",
             "Test Post Content",
-            "test-post",
+            "test-get-all-posts",
             user_id,
             PostsStatus::Published as _,
         )
-        .fetch_one(&pool)
+        .execute(&pool)
         .await
         .expect("Failed to insert test data");
 
         // Act
         let req = test::TestRequest::get()
             .uri("/posts?limit=100&offset=0")
+            .header(http::header::AUTHORIZATION, bearer_token(&["posts:read"]))
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -126,18 +111,151 @@ mod tests {
         assert_eq!(resp.status(), http::StatusCode::OK);
 
         let body = test::read_body(resp).await;
-        let response_body: Vec<PostDTO> =
+        let response_body: PaginationDTO<PostDTO> =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
 
-        let post = response_body.iter().find(|&post| {
-            post.title == "Test Post" && post.slug == "test-post"
+        let post = response_body.data.iter().find(|&post| {
+            post.title == "Test Post"
+                && post.slug.as_deref() == Some("test-get-all-posts")
         });
 
         assert!(post.is_some(), "Post not found in the response");
         assert_eq!(post.unwrap().status, PostsStatus::Published);
 
         // Clean up test data
-        clean_data_test(&pool, "posts", "name", "Test Post")
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", "get-all-posts@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    fn post_dto(
+        title: &str,
+        status: PostsStatus,
+        tags: Vec<String>,
+    ) -> CreatePostDTO {
+        CreatePostDTO {
+            title: String::from(title),
+            content: String::from("Filtered content, 100% tested"),
+            content_format: ContentFormat::default(),
+            content_blocks: None,
+            slug: None,
+            status,
+            date_published: None,
+            categories_ids: vec![],
+            tag_ids: vec![],
+            tags,
+        }
+    }
+
+    #[ntex::test]
+    async fn test_get_all_posts_controller_filters() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id =
+            insert_test_user(&pool, "post-filters@test.com", "password").await;
+        let current_user = CurrentUser {
+            id: user_id,
+            api_key_id: None,
+        };
+        for dto in [
+            post_dto(
+                "Filtered alpha",
+                PostsStatus::Pending,
+                vec![String::from("Post Filters Tag")],
+            ),
+            post_dto("Filtered beta", PostsStatus::Draft, vec![]),
+        ] {
+            let post = create_post_service(&pool, &current_user, dto)
+                .await
+                .expect("Failed to create test post");
+            if post.status == PostsStatus::Pending {
+                transition_post_service(
+                    &pool,
+                    &current_user,
+                    post.id.unwrap(),
+                    PostsStatus::Published,
+                    None,
+                )
+                .await
+                .expect("Failed to publish test post");
+            }
+        }
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_all_posts_controller),
+        )
+        .await;
+        let titles = |filters: &str| {
+            let req = test::TestRequest::get()
+                .uri(&format!("/posts?author_id={}&{}", user_id, filters))
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token(&["posts:read"]),
+                )
+                .to_request();
+            let app = &app;
+            async move {
+                let resp = test::call_service(app, req).await;
+                assert_eq!(resp.status(), http::StatusCode::OK);
+                let page: PaginationDTO<PostDTO> =
+                    serde_json::from_slice(&test::read_body(resp).await)
+                        .expect("Failed to parse JSON");
+                assert_eq!(page.total_items, page.data.len() as i64);
+                page.data
+                    .into_iter()
+                    .map(|post| post.title)
+                    .collect::<Vec<String>>()
+            }
+        };
+
+        // Act
+        let all = titles("sort=-title").await;
+        let published = titles("status=Published").await;
+        let tagged = titles("tag=post-filters-tag").await;
+        let searched = titles("q=BETA").await;
+        let literal = titles("q=100%25%20tested&sort=title").await;
+        let wildcard = titles("q=100_%25").await;
+        let future = titles("date_published[gte]=2100-01-01T00:00:00").await;
+        let past = titles("date_published[lte]=2100-01-01T00:00:00").await;
+        let req = test::TestRequest::get()
+            .uri("/posts?status=Unknown")
+            .header(http::header::AUTHORIZATION, bearer_token(&["posts:read"]))
+            .to_request();
+        let invalid = test::call_service(&app, req).await;
+        let req = test::TestRequest::get()
+            .uri("/posts?sort=-date_published,password")
+            .header(http::header::AUTHORIZATION, bearer_token(&["posts:read"]))
+            .to_request();
+        let unknown_sort = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(all, vec!["Filtered beta", "Filtered alpha"]);
+        assert_eq!(published, vec!["Filtered alpha"]);
+        assert_eq!(tagged, vec!["Filtered alpha"]);
+        assert_eq!(searched, vec!["Filtered beta"]);
+        assert_eq!(literal, vec!["Filtered alpha", "Filtered beta"]);
+        assert!(wildcard.is_empty());
+        assert!(future.is_empty());
+        assert_eq!(past, vec!["Filtered alpha"]);
+        assert_eq!(invalid.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(unknown_sort.status(), http::StatusCode::BAD_REQUEST);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "tags", "slug", "post-filters-tag")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", "post-filters@test.com")
             .await
             .expect("Failed to clean up test data");
     }
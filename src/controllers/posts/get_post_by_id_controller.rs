@@ -2,7 +2,11 @@ use ntex::web::{self, Error, HttpResponse};
 use sqlx::PgPool;
 
 use crate::{
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    extractors::permission_extractor::{Authorized, PostsRead},
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
     services::posts_services::get_post_by_id_service,
 };
 
@@ -14,7 +18,11 @@ use crate::{
         ("id" = i32, description = "ID of the post")
     ),
     responses(
-        (status = 200, description = "Post retrieved", body = PostDTO),
+        (status = 200, description = "Post retrieved", body = PostDTO, headers(
+            ("ETag" = String, description = "Version of the post")
+        )),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 404, description = "Post not found", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
@@ -22,10 +30,11 @@ use crate::{
 #[web::get("/posts/{id}")]
 pub async fn get_post_by_id_controller(
     pool: web::types::State<PgPool>,
+    _auth: Authorized<PostsRead>,
     post_id: web::types::Path<i32>,
 ) -> Result<HttpResponse, Error> {
     match get_post_by_id_service(pool.get_ref(), post_id.into_inner()).await {
-        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Ok(post) => Ok(ok_with_etag(&post, post.version)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
 }
@@ -35,8 +44,9 @@ mod tests {
     use crate::controllers::posts::get_post_by_id_controller::get_post_by_id_controller;
     use crate::dtos::post_dto::PostDTO;
     use crate::models::posts_model::PostsStatus;
-    use crate::tests::helpers::setup::setup_test_db;
-    use chrono::NaiveDateTime;
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, insert_test_user, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -51,34 +61,8 @@ mod tests {
         )
         .await;
 
-        // Check if a user already exists
-        let existing_user_id: Option<i32> = sqlx::query_scalar!(
-            r#"
-            SELECT id FROM users LIMIT 1
-            "#
-        )
-        .fetch_optional(&pool)
-        .await
-        .expect("Failed to query users");
-
-        // If no user exists, create one
-        let user_id = if let Some(id) = existing_user_id {
-            id
-        } else {
-            sqlx::query!(
-                r#"
-                INSERT INTO users (username, email) 
-                VALUES ($1, $2)
-                RETURNING id
-                "#,
-                "testuser",
-                "testuser@example.com"
-            )
-            .fetch_one(&pool)
-            .await
-            .expect("Failed to insert test user")
-            .id
-        };
+        let user_id =
+            insert_test_user(&pool, "post-by-id@test.com", "password").await;
 
         let inserted_post = sqlx::query!(
             r#"
@@ -88,7 +72,7 @@ mod tests {
             "#,
             "Test Post",
             "Test Post Content",
-            "test-post",
+            "test-post-by-id",
             user_id,
             PostsStatus::Published as _
         )
@@ -99,6 +83,7 @@ mod tests {
         // Act
         let req = test::TestRequest::get()
             .uri(&format!("/posts/{}", inserted_post.id))
+            .header(http::header::AUTHORIZATION, bearer_token(&["posts:read"]))
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -111,7 +96,7 @@ mod tests {
 
         let post = &response_body;
         assert_eq!(post.title, "Test Post");
-        assert_eq!(post.slug, "test-post");
+        assert_eq!(post.slug.as_deref(), Some("test-post-by-id"));
         assert_eq!(post.status, PostsStatus::Published);
 
         // Clean Data
@@ -124,5 +109,8 @@ mod tests {
         .execute(&pool)
         .await
         .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", "post-by-id@test.com")
+            .await
+            .expect("Failed to clean up test data");
     }
 }
@@ -1,5 +1,37 @@
+use ntex::web;
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{
+        Authorized, PostsPublish, RequiredPermission,
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::posts_services::get_post_by_id_service,
+};
+
 pub mod create_post_controller;
 pub mod delete_post_controller;
 pub mod get_all_posts_controller;
 pub mod get_post_by_id_controller;
+pub mod publish_post_controller;
+pub mod schedule_post_controller;
+pub mod submit_post_controller;
+pub mod unpublish_post_controller;
 pub mod update_post_controller;
+
+/// Changing the content readers see requires the right to publish, on top
+/// of the permission of the controller.
+pub async fn require_publish_if_live<P: RequiredPermission>(
+    pool: &PgPool,
+    auth: &Authorized<P>,
+    post_id: i32,
+) -> Result<(), web::Error> {
+    let post = get_post_by_id_service(pool, post_id)
+        .await
+        .map_err(convert_anyhow_to_ntex)?;
+    if post.status.is_published() {
+        auth.require::<PostsPublish>()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::{
+        current_user_extractor::CurrentUser,
+        permission_extractor::{Authorized, PostsPublish},
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::posts_model::PostsStatus,
+    services::posts_services::transition_post_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/publish",
+    tag = "Posts",
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Post published", body = PostDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 409, description = "The post cannot be published from its current status", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/posts/{id}/publish")]
+pub async fn publish_post_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsPublish>,
+    current_user: CurrentUser,
+    post_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match transition_post_service(
+        pool.get_ref(),
+        &current_user,
+        post_id.into_inner(),
+        PostsStatus::Published,
+        None,
+    )
+    .await
+    {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::posts::{
+        schedule_post_controller::schedule_post_controller,
+        submit_post_controller::submit_post_controller,
+        unpublish_post_controller::unpublish_post_controller,
+    };
+    use crate::dtos::post_dto::{PostDTO, SchedulePostDTO};
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "post-workflow@test.com";
+
+    #[ntex::test]
+    async fn test_post_workflow() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let post_id: i32 = sqlx::query_scalar(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id)
+            VALUES ('Workflow post', '', 'workflow-post', $1)
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(submit_post_controller)
+                .service(publish_post_controller)
+                .service(schedule_post_controller)
+                .service(unpublish_post_controller),
+        )
+        .await;
+        let transition = |action: &str| {
+            test::TestRequest::post()
+                .uri(&format!("/posts/{}/{}", post_id, action))
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token_for(
+                        user_id,
+                        &["posts:write", "posts:publish"],
+                    ),
+                )
+        };
+        let schedule = |date_published| {
+            transition("schedule")
+                .set_json(&SchedulePostDTO { date_published })
+                .to_request()
+        };
+
+        // Act
+        let submitted =
+            test::call_service(&app, transition("submit").to_request()).await;
+        let submitted_again =
+            test::call_service(&app, transition("submit").to_request()).await;
+        let in_past = test::call_service(
+            &app,
+            schedule(Utc::now().naive_utc() - Duration::hours(1)),
+        )
+        .await;
+        let published =
+            test::call_service(&app, transition("publish").to_request()).await;
+        let rescheduled = test::call_service(
+            &app,
+            schedule(Utc::now().naive_utc() + Duration::hours(1)),
+        )
+        .await;
+        let unpublished =
+            test::call_service(&app, transition("unpublish").to_request())
+                .await;
+        let republished =
+            test::call_service(&app, transition("publish").to_request()).await;
+
+        // Assert
+        assert_eq!(submitted.status(), http::StatusCode::OK);
+        let post: PostDTO =
+            serde_json::from_slice(&test::read_body(submitted).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(post.status, PostsStatus::Pending);
+        assert_eq!(post.updated_by, Some(user_id));
+        assert_eq!(submitted_again.status(), http::StatusCode::CONFLICT);
+        assert_eq!(in_past.status(), http::StatusCode::BAD_REQUEST);
+
+        assert_eq!(published.status(), http::StatusCode::OK);
+        let post: PostDTO =
+            serde_json::from_slice(&test::read_body(published).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(post.status, PostsStatus::Published);
+        assert!(post.date_published.is_some());
+        assert_eq!(rescheduled.status(), http::StatusCode::CONFLICT);
+        assert_eq!(unpublished.status(), http::StatusCode::OK);
+        assert_eq!(republished.status(), http::StatusCode::CONFLICT);
+
+        let transitions: Vec<(String, String)> = sqlx::query_as(
+            r#"
+            SELECT from_status::TEXT, to_status::TEXT
+            FROM post_status_transitions
+            WHERE post_id = $1 AND changed_by = $2
+            ORDER BY id
+            "#,
+        )
+        .bind(post_id)
+        .bind(user_id)
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select transitions");
+        let expected = [
+            ("Draft", "Pending"),
+            ("Pending", "Published"),
+            ("Published", "Draft"),
+        ];
+        assert_eq!(
+            transitions,
+            expected
+                .iter()
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect::<Vec<_>>()
+        );
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,57 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::post_dto::SchedulePostDTO,
+    extractors::{
+        current_user_extractor::CurrentUser,
+        permission_extractor::{Authorized, PostsPublish},
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::posts_model::PostsStatus,
+    services::posts_services::transition_post_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/schedule",
+    tag = "Posts",
+    request_body = SchedulePostDTO,
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Post scheduled", body = PostDTO),
+        (status = 400, description = "The date is not in the future", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 409, description = "The post cannot be scheduled from its current status", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/posts/{id}/schedule")]
+pub async fn schedule_post_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsPublish>,
+    current_user: CurrentUser,
+    post_id: Path<i32>,
+    schedule: Json<SchedulePostDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match transition_post_service(
+        pool.get_ref(),
+        &current_user,
+        post_id.into_inner(),
+        PostsStatus::Scheduled,
+        Some(schedule.into_inner().date_published),
+    )
+    .await
+    {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
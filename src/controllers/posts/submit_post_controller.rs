@@ -0,0 +1,53 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::{
+        current_user_extractor::CurrentUser,
+        permission_extractor::{Authorized, PostsWrite},
+    },
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    models::posts_model::PostsStatus,
+    services::posts_services::transition_post_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/posts/{id}/submit",
+    tag = "Posts",
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Post submitted for review", body = PostDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Post not found", body = Error),
+        (status = 409, description = "Only drafts can be submitted", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/posts/{id}/submit")]
+pub async fn submit_post_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsWrite>,
+    current_user: CurrentUser,
+    post_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match transition_post_service(
+        pool.get_ref(),
+        &current_user,
+        post_id.into_inner(),
+        PostsStatus::Pending,
+        None,
+    )
+    .await
+    {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
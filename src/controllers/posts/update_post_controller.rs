@@ -2,8 +2,17 @@ use ntex::web::{self, HttpResponse};
 use sqlx::PgPool;
 
 use crate::{
-    dtos::post_dto::{CreatePostDTO, PostDTO},
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    controllers::posts::require_publish_if_live,
+    dtos::post_dto::CreatePostDTO,
+    extractors::{
+        current_user_extractor::CurrentUser,
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, PostsPublish, PostsWrite},
+    },
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
     services::posts_services::update_post_service,
 };
 
@@ -13,31 +22,205 @@ use crate::{
     tag = "Posts",
     request_body = CreatePostDTO,
     params(
-        ("id" = i32, description = "ID of the post")
+        ("id" = i32, description = "ID of the post"),
+        ("If-Match" = String, Header, description = "ETag the post was read with, or * to overwrite any version")
     ),
     responses(
-        (status = 200, description = "Post updated", body = PostDTO),
+        (status = 200, description = "Post updated", body = PostDTO, headers(
+            ("ETag" = String, description = "New version of the post")
+        )),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 404, description = "Post not found", body = Error),
+        (status = 412, description = "The post has changed since it was read", body = Error),
+        (status = 428, description = "Missing If-Match header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::put("/posts/{id}")]
 pub async fn update_post_controller(
     pool: web::types::State<PgPool>,
+    auth: Authorized<PostsWrite>,
+    current_user: CurrentUser,
     post_id: web::types::Path<i32>,
+    if_match: IfMatch,
     post_dto: web::types::Json<CreatePostDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    let post_id = post_id.into_inner();
+    if post_dto.status.is_published() {
+        auth.require::<PostsPublish>()?;
+    }
+    require_publish_if_live(pool.get_ref(), &auth, post_id).await?;
+    if_match
+        .require()
+        .map_err(|e| convert_anyhow_to_ntex(e.into()))?;
+
     match update_post_service(
         pool.get_ref(),
-        post_id.into_inner(),
+        &current_user,
+        post_id,
+        &if_match,
         post_dto.into_inner(),
     )
     .await
     {
         Ok(updated_post) => {
-            Ok(HttpResponse::Ok().json(&PostDTO::from(updated_post)))
+            Ok(ok_with_etag(&updated_post, updated_post.version))
         }
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::post_dto::PostDTO;
+    use crate::models::posts_model::{ContentFormat, PostsStatus};
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "update-post@test.com";
+
+    #[ntex::test]
+    async fn test_update_post_categories() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let mut category_ids = vec![];
+        for slug in [
+            "update-post-kept",
+            "update-post-removed",
+            "update-post-added",
+        ] {
+            let category_id: i32 = sqlx::query_scalar(
+                "INSERT INTO categories (name, slug) VALUES ($1, $1) RETURNING id",
+            )
+            .bind(slug)
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to insert test category");
+            category_ids.push(category_id);
+        }
+        let post_id: i32 = sqlx::query_scalar(
+            r#"
+            INSERT INTO posts (title, content, slug, author_id)
+            VALUES ('Updated post', '', 'updated-post', $1)
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test post");
+        sqlx::query(
+            r#"
+            INSERT INTO posts_categories (post_id, category_id)
+            SELECT $1, UNNEST($2::INTEGER[])
+            "#,
+        )
+        .bind(post_id)
+        .bind(&category_ids[..2])
+        .execute(&pool)
+        .await
+        .expect("Failed to categorize test post");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(update_post_controller),
+        )
+        .await;
+
+        let post_dto = CreatePostDTO {
+            title: String::from("Updated post"),
+            content: String::from("Updated content"),
+            content_format: ContentFormat::default(),
+            content_blocks: None,
+            slug: Some(String::from("updated-post")),
+            status: PostsStatus::Draft,
+            date_published: None,
+            categories_ids: vec![category_ids[0], category_ids[2]],
+            tag_ids: vec![],
+            tags: vec![],
+        };
+        let update_request = || {
+            test::TestRequest::put()
+                .uri(&format!("/posts/{}", post_id))
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token_for(user_id, &["posts:write"]),
+                )
+                .set_json(&post_dto)
+        };
+
+        // Act
+        let unconditional =
+            test::call_service(&app, update_request().to_request()).await;
+        let req = update_request()
+            .header(http::header::IF_MATCH, "\"1\"")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        sqlx::query(
+            "UPDATE posts SET status = 'Published', \
+             date_published = CURRENT_TIMESTAMP WHERE id = $1",
+        )
+        .bind(post_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to publish test post");
+        let taken_offline = test::call_service(
+            &app,
+            update_request()
+                .header(http::header::IF_MATCH, "*")
+                .to_request(),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(
+            unconditional.status(),
+            http::StatusCode::PRECONDITION_REQUIRED
+        );
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let post: PostDTO =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(post.content, "Updated content");
+        assert_eq!(post.updated_by, Some(user_id));
+        let mut assigned: Vec<i32> = post
+            .categories
+            .expect("Missing categories")
+            .as_array()
+            .expect("Categories should be an array")
+            .iter()
+            .map(|category| category["id"].as_i64().unwrap() as i32)
+            .collect();
+        assigned.sort_unstable();
+        assert_eq!(assigned, vec![category_ids[0], category_ids[2]]);
+        // Taking a live post back to a draft requires posts:publish
+        assert_eq!(taken_offline.status(), http::StatusCode::FORBIDDEN);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+        for slug in [
+            "update-post-kept",
+            "update-post-removed",
+            "update-post-added",
+        ] {
+            clean_data_test(&pool, "categories", "slug", slug)
+                .await
+                .expect("Failed to clean up test data");
+        }
+    }
+}
@@ -3,6 +3,7 @@ use ntex::web::{self, HttpResponse};
 use sqlx::PgPool;
 
 use crate::dtos::posts_categories_dto::CreatePostsCategoriesDTO;
+use crate::extractors::permission_extractor::{Authorized, PostsWrite};
 use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
 use crate::services::posts_categories_service::create_post_category_service;
 
@@ -14,12 +15,15 @@ use crate::services::posts_categories_service::create_post_category_service;
     responses(
         (status = 201, description = "Posts Categories created successfully", body = PostsCategoriesDTO),
         (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal server error", body = Error)
     )
 )]
 #[web::post("/posts-categories")]
 pub async fn create_posts_categories_controller(
     pool: State<PgPool>,
+    _auth: Authorized<PostsWrite>,
     posts_categories_dto: Json<CreatePostsCategoriesDTO>,
 ) -> Result<HttpResponse, web::Error> {
     match create_post_category_service(
@@ -0,0 +1,122 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::auth_dtos::ChangePasswordRequestDTO,
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::{
+        auth_middleware::forbidden_response, error_middleware::Error,
+    },
+    services::auth_service::change_password_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/me/password",
+    tag = "Profile",
+    request_body = ChangePasswordRequestDTO,
+    responses(
+        (status = 204, description = "Password changed, every session is signed out"),
+        (status = 400, description = "Wrong current password or invalid new password", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Requests authenticated with an API key cannot change the password", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/me/password")]
+pub async fn change_password_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUser,
+    request: Json<ChangePasswordRequestDTO>,
+) -> Result<HttpResponse, web::Error> {
+    if current_user.api_key_id.is_some() {
+        return Ok(forbidden_response(
+            "The password cannot be changed with an API key",
+        ));
+    }
+
+    match change_password_service(
+        pool.get_ref(),
+        current_user.id,
+        request.into_inner(),
+    )
+    .await
+    {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::BadRequest().json(&Error {
+            message: String::from("Current password is incorrect"),
+            backtrace: None,
+        })),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "change-password@test.com";
+
+    #[ntex::test]
+    async fn test_change_password() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(change_password_controller),
+        )
+        .await;
+        let change = |current_password: &str, new_password: &str| {
+            test::TestRequest::post()
+                .uri("/me/password")
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token_for(user_id, &[]),
+                )
+                .set_json(&ChangePasswordRequestDTO {
+                    current_password: String::from(current_password),
+                    new_password: String::from(new_password),
+                })
+                .to_request()
+        };
+
+        // Act
+        let wrong = test::call_service(
+            &app,
+            change("not-the-password", "new-password"),
+        )
+        .await;
+        let changed =
+            test::call_service(&app, change("password", "new-password")).await;
+        let old_password =
+            test::call_service(&app, change("password", "other-password"))
+                .await;
+        let new_password =
+            test::call_service(&app, change("new-password", "other-password"))
+                .await;
+
+        // Assert
+        assert_eq!(wrong.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(changed.status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(old_password.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(new_password.status(), http::StatusCode::NO_CONTENT);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,34 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::current_user_extractor::CurrentUser,
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
+    services::users_service::get_user_by_id_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/me",
+    tag = "Profile",
+    responses(
+        (status = 200, description = "Profile of the current user", body = UserDTO, headers(
+            ("ETag" = String, description = "Version of the user")
+        )),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/me")]
+pub async fn get_profile_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUser,
+) -> Result<HttpResponse, web::Error> {
+    match get_user_by_id_service(pool.get_ref(), current_user.id).await {
+        Ok(user) => Ok(ok_with_etag(&user, user.version)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,3 @@
+pub mod change_password_controller;
+pub mod get_profile_controller;
+pub mod update_profile_controller;
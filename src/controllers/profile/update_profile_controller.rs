@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::user_dtos::UpdateProfileDTO,
+    extractors::{
+        current_user_extractor::CurrentUser, if_match_extractor::IfMatch,
+    },
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
+    mailers::Mailer,
+    services::users_service::update_user_service,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/me",
+    tag = "Profile",
+    request_body = UpdateProfileDTO,
+    params(
+        ("If-Match" = Option<String>, Header, description = "ETag the profile was read with")
+    ),
+    responses(
+        (status = 200, description = "Profile updated", body = UserDTO, headers(
+            ("ETag" = String, description = "New version of the user")
+        )),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 412, description = "The profile has changed since it was read", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::patch("/me")]
+pub async fn update_profile_controller(
+    pool: State<PgPool>,
+    mailer: State<Arc<dyn Mailer>>,
+    current_user: CurrentUser,
+    if_match: IfMatch,
+    profile_dto: Json<UpdateProfileDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match update_user_service(
+        pool.get_ref(),
+        mailer.get_ref().as_ref(),
+        current_user.id,
+        &if_match,
+        profile_dto.into_inner().into(),
+    )
+    .await
+    {
+        Ok(user) => Ok(ok_with_etag(&user, user.version)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::user_dtos::UserDTO;
+    use crate::mailers::memory_mailer::MemoryMailer;
+    use crate::repositories::users_repository::select_user_by_id;
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "update-profile@test.com";
+
+    #[ntex::test]
+    async fn test_update_profile_partial() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let previous = select_user_by_id(&pool, user_id).await.unwrap();
+        let mailer: Arc<dyn Mailer> = Arc::new(MemoryMailer::default());
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .state(mailer.clone())
+                .service(update_profile_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::patch()
+            .uri("/me")
+            .header(http::header::AUTHORIZATION, bearer_token_for(user_id, &[]))
+            .set_json(&serde_json::json!({ "firstname": "Ada" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let body = test::read_body(resp).await;
+        let json: serde_json::Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert!(json.get("password").is_none());
+        let user: UserDTO =
+            serde_json::from_value(json).expect("Failed to parse JSON");
+        assert_eq!(user.firstname, "Ada");
+        assert_eq!(user.lastname, previous.lastname);
+        assert_eq!(user.email, EMAIL);
+        let updated = select_user_by_id(&pool, user_id).await.unwrap();
+        assert_eq!(updated.password, previous.password);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,37 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_posts_service::get_public_post_by_id_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/posts/{id}",
+    tag = "Public",
+    params(
+        ("id" = i32, description = "ID of the post")
+    ),
+    responses(
+        (status = 200, description = "Published post retrieved", body = PublicPostDTO),
+        (status = 404, description = "Post not found or not published", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/{id}")]
+pub async fn get_public_post_by_id_controller(
+    pool: State<PgPool>,
+    post_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_post_by_id_service(pool.get_ref(), post_id.into_inner())
+        .await
+    {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,35 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_posts_service::get_public_post_by_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/posts/slug/{slug}",
+    tag = "Public",
+    params(
+        ("slug" = String, description = "Slug of the post")
+    ),
+    responses(
+        (status = 200, description = "Published post retrieved", body = PublicPostDTO),
+        (status = 404, description = "Post not found or not published", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts/slug/{slug}")]
+pub async fn get_public_post_by_slug_controller(
+    pool: State<PgPool>,
+    slug: Path<String>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_post_by_slug_service(pool.get_ref(), &slug).await {
+        Ok(post) => Ok(HttpResponse::Ok().json(&post)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,181 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::public_post_dtos::PublicPostsParamsDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::public_posts_service::get_public_posts_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/public/posts",
+    tag = "Public",
+    params(PublicPostsParamsDTO),
+    responses(
+        (status = 200, description = "Published posts, the most recent first", body = [PublicPostDTO]),
+        (status = 400, description = "Invalid pagination", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/posts")]
+pub async fn get_public_posts_controller(
+    pool: State<PgPool>,
+    params: Query<PublicPostsParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match get_public_posts_service(pool.get_ref(), params.into_inner()).await {
+        Ok(posts) => Ok(HttpResponse::Ok().json(&posts)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::public_posts::{
+        get_public_post_by_id_controller::get_public_post_by_id_controller,
+        get_public_post_by_slug_controller::get_public_post_by_slug_controller,
+    };
+    use crate::dtos::pagination_dto::PaginationDTO;
+    use crate::dtos::post_dto::CreatePostDTO;
+    use crate::dtos::public_post_dtos::PublicPostDTO;
+    use crate::extractors::current_user_extractor::CurrentUser;
+    use crate::models::posts_model::{ContentFormat, PostsStatus};
+    use crate::services::posts_services::{
+        create_post_service, transition_post_service,
+    };
+    use crate::tests::helpers::setup::{
+        clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "public-posts@test.com";
+
+    fn post_dto(slug: &str, status: PostsStatus) -> CreatePostDTO {
+        CreatePostDTO {
+            title: String::from("Public Post"),
+            content: String::from("Public *content*"),
+            content_format: ContentFormat::Markdown,
+            content_blocks: None,
+            slug: Some(String::from(slug)),
+            status,
+            date_published: None,
+            categories_ids: vec![],
+            tag_ids: vec![],
+            tags: vec![],
+        }
+    }
+
+    #[ntex::test]
+    async fn test_public_posts() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let current_user = CurrentUser {
+            id: user_id,
+            api_key_id: None,
+        };
+        let pending = create_post_service(
+            &pool,
+            &current_user,
+            post_dto("public-post-published", PostsStatus::Pending),
+        )
+        .await
+        .expect("Failed to create test post");
+        let published = transition_post_service(
+            &pool,
+            &current_user,
+            pending.id.unwrap(),
+            PostsStatus::Published,
+            None,
+        )
+        .await
+        .expect("Failed to publish test post");
+        let draft = create_post_service(
+            &pool,
+            &current_user,
+            post_dto("public-post-draft", PostsStatus::Draft),
+        )
+        .await
+        .expect("Failed to create test post");
+        let app = test::init_service(
+            web::App::new().state(pool.clone()).service(
+                web::scope("/public")
+                    .service(get_public_posts_controller)
+                    .service(get_public_post_by_id_controller)
+                    .service(get_public_post_by_slug_controller),
+            ),
+        )
+        .await;
+        let get = |uri: String| test::TestRequest::get().uri(&uri).to_request();
+
+        // Act
+        let list = test::call_service(
+            &app,
+            get(String::from("/public/posts?limit=50")),
+        )
+        .await;
+        let by_slug = test::call_service(
+            &app,
+            get(String::from("/public/posts/slug/public-post-published")),
+        )
+        .await;
+        let draft_by_slug = test::call_service(
+            &app,
+            get(String::from("/public/posts/slug/public-post-draft")),
+        )
+        .await;
+        let draft_by_id = test::call_service(
+            &app,
+            get(format!("/public/posts/{}", draft.id.unwrap())),
+        )
+        .await;
+        let too_many = test::call_service(
+            &app,
+            get(String::from("/public/posts?limit=51")),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(list.status(), http::StatusCode::OK);
+        let list: PaginationDTO<PublicPostDTO> =
+            serde_json::from_slice(&test::read_body(list).await)
+                .expect("Failed to parse JSON");
+        assert!(list
+            .data
+            .iter()
+            .any(|post| post.id == published.id.unwrap()));
+        assert!(list.data.iter().all(|post| post.id != draft.id.unwrap()));
+        assert_eq!(by_slug.status(), http::StatusCode::OK);
+        let body = test::read_body(by_slug).await;
+        let post: PublicPostDTO =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(post.author_name, "Test User");
+        assert_eq!(
+            post.content_html.as_deref(),
+            Some("<p>Public <em>content</em></p>\n")
+        );
+        let body = String::from_utf8_lossy(&body);
+        assert!(!body.contains(EMAIL));
+        assert!(!body.contains("author_id"));
+        assert_eq!(draft_by_slug.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(draft_by_id.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(too_many.status(), http::StatusCode::BAD_REQUEST);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
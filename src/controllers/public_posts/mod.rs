@@ -0,0 +1,3 @@
+pub mod get_public_post_by_id_controller;
+pub mod get_public_post_by_slug_controller;
+pub mod get_public_posts_controller;
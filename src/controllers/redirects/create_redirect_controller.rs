@@ -0,0 +1,42 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::redirect_dtos::CreateRedirectDTO,
+    extractors::permission_extractor::{Authorized, RedirectsWrite},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::redirects_service::create_redirect_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/redirects",
+    tag = "Redirects",
+    request_body = CreateRedirectDTO,
+    responses(
+        (status = 201, description = "Create redirect", body = RedirectDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/redirects")]
+pub async fn create_redirect_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RedirectsWrite>,
+    redirect_dto: Json<CreateRedirectDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match create_redirect_service(pool.get_ref(), redirect_dto.into_inner())
+        .await
+    {
+        Ok(created_redirect) => {
+            Ok(HttpResponse::Created().json(&created_redirect))
+        }
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,43 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::redirect_dtos::DeleteRedirectIdsDTO,
+    extractors::permission_extractor::{Authorized, RedirectsDelete},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::redirects_service::delete_redirect_by_id_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/redirects",
+    tag = "Redirects",
+    request_body = DeleteRedirectIdsDTO,
+    responses(
+        (status = 200, description = "Redirects deleted", body = i32),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/redirects")]
+pub async fn delete_redirect_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RedirectsDelete>,
+    delete_redirect_ids_dto: Json<DeleteRedirectIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match delete_redirect_by_id_service(
+        pool.get_ref(),
+        delete_redirect_ids_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
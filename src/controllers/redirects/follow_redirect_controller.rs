@@ -0,0 +1,140 @@
+use ntex::http::{header, StatusCode};
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::redirect_dtos::FollowRedirectParamsDTO,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::redirects_service::follow_redirect_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/redirects/follow",
+    tag = "Redirects",
+    params(FollowRedirectParamsDTO),
+    responses(
+        (status = 301, description = "Moved permanently to the Location header"),
+        (status = 302, description = "Found at the Location header"),
+        (status = 404, description = "No redirect from this path", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/redirects/follow")]
+pub async fn follow_redirect_controller(
+    pool: State<PgPool>,
+    params: Query<FollowRedirectParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match follow_redirect_service(pool.get_ref(), &params.path).await {
+        Ok(redirect) => {
+            let status = StatusCode::from_u16(redirect.status_code as u16)
+                .unwrap_or(StatusCode::MOVED_PERMANENTLY);
+            Ok(HttpResponse::build(status)
+                .header(header::LOCATION, redirect.target)
+                .finish())
+        }
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::redirects::{
+        create_redirect_controller::create_redirect_controller,
+        get_redirect_by_id_controller::get_redirect_by_id_controller,
+    };
+    use crate::dtos::redirect_dtos::{CreateRedirectDTO, RedirectDTO};
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, setup_test_db,
+    };
+
+    const SOURCE_PATH: &str = "/redirect-test-old";
+
+    #[ntex::test]
+    async fn test_follow_redirect() {
+        // Arrange
+        let pool = setup_test_db().await;
+        clean_data_test(&pool, "redirects", "source_path", SOURCE_PATH)
+            .await
+            .expect("Failed to clean up test data");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(follow_redirect_controller)
+                .service(create_redirect_controller)
+                .service(get_redirect_by_id_controller),
+        )
+        .await;
+        let token = bearer_token(&["redirects:read", "redirects:write"]);
+        let create = |target: &str, status_code: i32| {
+            test::TestRequest::post()
+                .uri("/redirects")
+                .header(http::header::AUTHORIZATION, token.clone())
+                .set_json(&CreateRedirectDTO {
+                    source_path: String::from(SOURCE_PATH),
+                    target: String::from(target),
+                    status_code: Some(status_code),
+                })
+                .to_request()
+        };
+        let follow = |path: &str| {
+            test::TestRequest::get()
+                .uri(&format!("/redirects/follow?path={}", path))
+                .to_request()
+        };
+
+        // Act
+        let invalid =
+            test::call_service(&app, create("javascript:alert(1)", 301)).await;
+        let wrong_status =
+            test::call_service(&app, create("/redirect-test-new", 307)).await;
+        let created =
+            test::call_service(&app, create("/redirect-test-new", 302)).await;
+        let duplicate =
+            test::call_service(&app, create("/redirect-test-other", 301)).await;
+        let first = test::call_service(&app, follow(SOURCE_PATH)).await;
+        let second = test::call_service(&app, follow(SOURCE_PATH)).await;
+        let unknown =
+            test::call_service(&app, follow("/redirect-test-unknown")).await;
+
+        // Assert
+        assert_eq!(invalid.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(wrong_status.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(created.status(), http::StatusCode::CREATED);
+        let created: RedirectDTO =
+            serde_json::from_slice(&test::read_body(created).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(duplicate.status(), http::StatusCode::CONFLICT);
+        assert_eq!(first.status(), http::StatusCode::FOUND);
+        assert_eq!(
+            first.headers().get(http::header::LOCATION).unwrap(),
+            "/redirect-test-new"
+        );
+        assert_eq!(second.status(), http::StatusCode::FOUND);
+        assert_eq!(unknown.status(), http::StatusCode::NOT_FOUND);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/redirects/{}", created.id.unwrap()))
+            .header(http::header::AUTHORIZATION, token.clone())
+            .to_request();
+        let redirect: RedirectDTO = serde_json::from_slice(
+            &test::read_body(test::call_service(&app, req).await).await,
+        )
+        .expect("Failed to parse JSON");
+        assert_eq!(redirect.hits, 2);
+        assert!(redirect.last_hit_at.is_some());
+
+        // Clean up test data
+        clean_data_test(&pool, "redirects", "source_path", SOURCE_PATH)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,45 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::pagination_dto::PaginationParamsDTO,
+    extractors::permission_extractor::{Authorized, RedirectsRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::redirects_service::get_all_redirects_service,
+};
+
+#[utoipa::path(
+  get,
+  path = "/redirects",
+  tag = "Redirects",
+  params(
+    ("page" = Option<i32>, Query, description = "The page number for pagination"),
+    ("limit" = Option<i32>, Query, description = "The number of items per page"),
+    ("sort" = Option<Vec<RedirectSortField>>, Query, style = Form, explode = false, description = "Comma-separated fields to sort by, descending if prefixed with '-' (default '-id')")
+  ),
+  responses(
+    (status = 200, description = "Get all redirects", body = [RedirectDTO]),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
+    (status = 500, description = "Internal Server Error", body = Error)
+  ),
+)]
+#[web::get("/redirects")]
+pub async fn get_all_redirects_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RedirectsRead>,
+    params: Query<PaginationParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort = params.sort.as_deref().unwrap_or("-id");
+
+    match get_all_redirects_service(pool.get_ref(), page, limit, sort).await {
+        Ok(redirects) => Ok(HttpResponse::Ok().json(&redirects)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
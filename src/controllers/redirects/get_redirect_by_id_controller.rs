@@ -0,0 +1,41 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, RedirectsRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::redirects_service::get_redirect_by_id_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/redirects/{id}",
+    tag = "Redirects",
+    params(
+        ("id" = i32, description = "ID of the redirect")
+    ),
+    responses(
+        (status = 200, description = "Redirect retrieved", body = RedirectDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Redirect not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/redirects/{id}")]
+pub async fn get_redirect_by_id_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RedirectsRead>,
+    redirect_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match get_redirect_by_id_service(pool.get_ref(), redirect_id.into_inner())
+        .await
+    {
+        Ok(redirect) => Ok(HttpResponse::Ok().json(&redirect)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
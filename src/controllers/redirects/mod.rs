@@ -0,0 +1,6 @@
+pub mod create_redirect_controller;
+pub mod delete_redirect_controller;
+pub mod follow_redirect_controller;
+pub mod get_all_redirects_controller;
+pub mod get_redirect_by_id_controller;
+pub mod update_redirect_controller;
@@ -0,0 +1,49 @@
+use ntex::web::{
+    self,
+    types::{Json, Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::redirect_dtos::CreateRedirectDTO,
+    extractors::permission_extractor::{Authorized, RedirectsWrite},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::redirects_service::update_redirect_service,
+};
+
+#[utoipa::path(
+    put,
+    path = "/redirects/{id}",
+    tag = "Redirects",
+    request_body = CreateRedirectDTO,
+    params(
+        ("id" = i32, description = "ID of the redirect")
+    ),
+    responses(
+        (status = 200, description = "Redirect updated", body = RedirectDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Redirect not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::put("/redirects/{id}")]
+pub async fn update_redirect_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RedirectsWrite>,
+    redirect_id: Path<i32>,
+    redirect_dto: Json<CreateRedirectDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match update_redirect_service(
+        pool.get_ref(),
+        redirect_id.into_inner(),
+        redirect_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(updated_redirect) => Ok(HttpResponse::Ok().json(&updated_redirect)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
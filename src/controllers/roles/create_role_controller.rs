@@ -0,0 +1,38 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::role_dto::CreateRoleDTO,
+    extractors::permission_extractor::{Authorized, RolesWrite},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::roles_service::create_role_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/roles",
+    tag = "Roles",
+    request_body = CreateRoleDTO,
+    responses(
+        (status = 201, description = "Create role", body = RoleDTO),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    ),
+)]
+#[web::post("/roles")]
+pub async fn create_role_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesWrite>,
+    role_dto: Json<CreateRoleDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match create_role_service(pool.get_ref(), role_dto.into_inner()).await {
+        Ok(created_role) => Ok(HttpResponse::Created().json(&created_role)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,39 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, RolesRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::roles_service::get_role_by_id_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/roles/{id}",
+    tag = "Roles",
+    params(
+        ("id" = i32, description = "ID of the role")
+    ),
+    responses(
+        (status = 200, description = "Role retrieved", body = RoleDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Role not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/roles/{id}")]
+pub async fn get_role_by_id_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesRead>,
+    role_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match get_role_by_id_service(pool.get_ref(), role_id.into_inner()).await {
+        Ok(role) => Ok(HttpResponse::Ok().json(&role)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -0,0 +1,40 @@
+use ntex::web::types::{Json, State};
+use ntex::web::{self, HttpResponse};
+use sqlx::PgPool;
+
+use crate::dtos::roles_permissions_dto::CreateRolesPermissionsDTO;
+use crate::extractors::permission_extractor::{Authorized, RolesWrite};
+use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
+use crate::services::roles_permissions_service::create_role_permission_service;
+
+#[utoipa::path(
+    post,
+    path = "/roles-permissions",
+    tag = "Roles Permissions",
+    request_body = CreateRolesPermissionsDTO,
+    responses(
+        (status = 201, description = "Roles Permissions created successfully", body = RolesPermissionsDTO),
+        (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal server error", body = Error)
+    )
+)]
+#[web::post("/roles-permissions")]
+pub async fn create_roles_permissions_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesWrite>,
+    roles_permissions_dto: Json<CreateRolesPermissionsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match create_role_permission_service(
+        pool.get_ref(),
+        roles_permissions_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(roles_permissions) => {
+            Ok(HttpResponse::Created().json(&roles_permissions))
+        }
+        Err(err) => Err(convert_anyhow_to_ntex(err)),
+    }
+}
@@ -0,0 +1,38 @@
+use ntex::web::types::{Json, State};
+use ntex::web::{self, HttpResponse};
+use sqlx::PgPool;
+
+use crate::dtos::roles_permissions_dto::DeleteRolesPermissionsIdsDTO;
+use crate::extractors::permission_extractor::{Authorized, RolesWrite};
+use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
+use crate::services::roles_permissions_service::delete_role_permission_service;
+
+#[utoipa::path(
+    delete,
+    path = "/roles-permissions",
+    tag = "Roles Permissions",
+    request_body = DeleteRolesPermissionsIdsDTO,
+    responses(
+        (status = 200, description = "Roles Permissions deleted", body = i32),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/roles-permissions")]
+pub async fn delete_roles_permissions_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesWrite>,
+    delete_ids_dto: Json<DeleteRolesPermissionsIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match delete_role_permission_service(
+        pool.get_ref(),
+        delete_ids_dto.into_inner(),
+    )
+    .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(err) => Err(convert_anyhow_to_ntex(err)),
+    }
+}
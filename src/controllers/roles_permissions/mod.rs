@@ -0,0 +1,2 @@
+pub mod create_roles_permissions_controller;
+pub mod delete_roles_permissions_controller;
@@ -0,0 +1,155 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::scheduler_dtos::NextRunsParamsDTO,
+    extractors::permission_extractor::{Authorized, PostsPublish},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::scheduler_service::get_next_runs_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/scheduler/next-runs",
+    tag = "Scheduler",
+    params(NextRunsParamsDTO),
+    responses(
+        (status = 200, description = "Scheduled posts, the next to be published first", body = NextRunsDTO),
+        (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/scheduler/next-runs")]
+pub async fn get_next_runs_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsPublish>,
+    params: Query<NextRunsParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match get_next_runs_service(pool.get_ref(), params.into_inner()).await {
+        Ok(next_runs) => Ok(HttpResponse::Ok().json(&next_runs)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::scheduler_dtos::NextRunsDTO;
+    use crate::services::scheduler_service::publish_due_posts_service;
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "scheduler@test.com";
+
+    #[ntex::test]
+    async fn test_publish_scheduled_posts() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let now = Utc::now().naive_utc();
+        let mut post_ids = vec![];
+        for (slug, date_published) in [
+            ("scheduler-due", now - Duration::minutes(1)),
+            ("scheduler-upcoming", now + Duration::days(1)),
+        ] {
+            let post_id: i32 = sqlx::query_scalar(
+                r#"
+                INSERT INTO posts (
+                    title, content, slug, author_id, status, date_published
+                )
+                VALUES ($1, '', $1, $2, 'Scheduled', $3)
+                RETURNING id
+                "#,
+            )
+            .bind(slug)
+            .bind(user_id)
+            .bind(date_published)
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to insert test post");
+            post_ids.push(post_id);
+        }
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_next_runs_controller),
+        )
+        .await;
+
+        // Act
+        // Two instances running at the same time
+        let (first, second) = tokio::join!(
+            publish_due_posts_service(&pool),
+            publish_due_posts_service(&pool)
+        );
+        let req = test::TestRequest::get()
+            .uri("/scheduler/next-runs?limit=100")
+            .header(
+                http::header::AUTHORIZATION,
+                bearer_token(&["posts:publish"]),
+            )
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        let published: Vec<i32> = first
+            .unwrap()
+            .into_iter()
+            .chain(second.unwrap())
+            .filter(|id| post_ids.contains(id))
+            .collect();
+        assert_eq!(published, vec![post_ids[0]]);
+        let statuses: Vec<String> = sqlx::query_scalar(
+            "SELECT status::TEXT FROM posts WHERE id = ANY($1) ORDER BY id",
+        )
+        .bind(&post_ids)
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to select posts");
+        assert_eq!(statuses, vec!["Published", "Scheduled"]);
+        let transitions: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM post_status_transitions
+            WHERE post_id = $1
+                AND from_status = 'Scheduled'
+                AND to_status = 'Published'
+                AND changed_by IS NULL
+            "#,
+        )
+        .bind(post_ids[0])
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count transitions");
+        assert_eq!(transitions, 1);
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let next_runs: NextRunsDTO =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse JSON");
+        let run_ids: Vec<i32> =
+            next_runs.runs.iter().map(|run| run.post_id).collect();
+        assert!(run_ids.contains(&post_ids[1]));
+        assert!(!run_ids.contains(&post_ids[0]));
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
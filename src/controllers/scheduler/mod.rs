@@ -0,0 +1 @@
+pub mod get_next_runs_controller;
@@ -0,0 +1,142 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::slug_dtos::SlugCheckParamsDTO,
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::slugs_service::check_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/slugs/check",
+    tag = "Slugs",
+    params(SlugCheckParamsDTO),
+    responses(
+        (status = 200, description = "Availability of the slug", body = SlugAvailabilityDTO),
+        (status = 400, description = "Invalid slug", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/slugs/check")]
+pub async fn check_slug_controller(
+    pool: State<PgPool>,
+    _current_user: CurrentUser,
+    params: Query<SlugCheckParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match check_slug_service(pool.get_ref(), params.into_inner()).await {
+        Ok(availability) => Ok(HttpResponse::Ok().json(&availability)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::posts::create_post_controller::create_post_controller;
+    use crate::dtos::post_dto::{CreatePostDTO, PostDTO};
+    use crate::dtos::slug_dtos::SlugAvailabilityDTO;
+    use crate::models::posts_model::{ContentFormat, PostsStatus};
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "slugs@test.com";
+
+    fn post_dto(slug: Option<&str>) -> CreatePostDTO {
+        CreatePostDTO {
+            title: String::from("Slug Allocation Post"),
+            content: String::from("Content"),
+            content_format: ContentFormat::default(),
+            content_blocks: None,
+            slug: slug.map(String::from),
+            status: PostsStatus::Draft,
+            date_published: None,
+            categories_ids: vec![],
+            tag_ids: vec![],
+            tags: vec![],
+        }
+    }
+
+    #[ntex::test]
+    async fn test_unique_post_slugs() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(create_post_controller)
+                .service(check_slug_controller),
+        )
+        .await;
+        let token = bearer_token_for(user_id, &["posts:write"]);
+        let create = |dto: CreatePostDTO| {
+            test::TestRequest::post()
+                .uri("/posts")
+                .header(http::header::AUTHORIZATION, token.clone())
+                .set_json(&dto)
+                .to_request()
+        };
+        let check = |slug: &str| {
+            test::TestRequest::get()
+                .uri(&format!("/slugs/check?type=post&slug={}", slug))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request()
+        };
+        let slug_of = |body: ntex::util::Bytes| {
+            let post: PostDTO =
+                serde_json::from_slice(&body).expect("Failed to parse JSON");
+            post.slug.expect("Missing slug")
+        };
+
+        // Act
+        let first = test::call_service(&app, create(post_dto(None))).await;
+        let second = test::call_service(&app, create(post_dto(None))).await;
+        let taken = test::call_service(
+            &app,
+            create(post_dto(Some("slug-allocation-post"))),
+        )
+        .await;
+        let availability =
+            test::call_service(&app, check("slug-allocation-post")).await;
+        let invalid = test::call_service(&app, check("not%20a%20slug")).await;
+
+        // Assert
+        assert_eq!(
+            slug_of(test::read_body(first).await),
+            "slug-allocation-post"
+        );
+        assert_eq!(
+            slug_of(test::read_body(second).await),
+            "slug-allocation-post-2"
+        );
+        assert_eq!(taken.status(), http::StatusCode::CONFLICT);
+        assert_eq!(availability.status(), http::StatusCode::OK);
+        let availability: SlugAvailabilityDTO =
+            serde_json::from_slice(&test::read_body(availability).await)
+                .expect("Failed to parse JSON");
+        assert!(!availability.available);
+        assert_eq!(availability.suggestion, "slug-allocation-post-3");
+        assert_eq!(invalid.status(), http::StatusCode::BAD_REQUEST);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod check_slug_controller;
+pub mod resolve_slug_controller;
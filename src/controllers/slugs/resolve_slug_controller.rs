@@ -0,0 +1,138 @@
+use ntex::web::{
+    self,
+    types::{Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::slug_dtos::SlugResolveParamsDTO,
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::slugs_service::resolve_slug_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/slugs/resolve",
+    tag = "Slugs",
+    params(SlugResolveParamsDTO),
+    responses(
+        (status = 200, description = "Content the slug points to", body = SlugResolutionDTO),
+        (status = 400, description = "Invalid parameters", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 404, description = "No content has this slug", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/slugs/resolve")]
+pub async fn resolve_slug_controller(
+    pool: State<PgPool>,
+    _current_user: CurrentUser,
+    params: Query<SlugResolveParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match resolve_slug_service(pool.get_ref(), params.into_inner()).await {
+        Ok(resolution) => Ok(HttpResponse::Ok().json(&resolution)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::category_dto::CreateCategoryDTO;
+    use crate::dtos::slug_dtos::SlugResolutionDTO;
+    use crate::extractors::if_match_extractor::IfMatch;
+    use crate::services::categories_service::{
+        create_category_service, update_category_service,
+    };
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "slug-history@test.com";
+
+    fn category_dto(slug: &str) -> CreateCategoryDTO {
+        CreateCategoryDTO {
+            parent_id: None,
+            name: String::from("Slug History Category"),
+            slug: Some(String::from(slug)),
+            description: None,
+        }
+    }
+
+    #[ntex::test]
+    async fn test_resolve_previous_slugs() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let category =
+            create_category_service(&pool, category_dto("slug-history-old"))
+                .await
+                .expect("Failed to create category");
+        let id = category.id.expect("Missing ID");
+        for slug in ["slug-history-new", "slug-history-newest"] {
+            update_category_service(
+                &pool,
+                id,
+                &IfMatch::default(),
+                category_dto(slug),
+            )
+            .await
+            .expect("Failed to update category");
+        }
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(resolve_slug_controller),
+        )
+        .await;
+        let token = bearer_token_for(user_id, &[]);
+        let resolve = |slug: &str| {
+            test::TestRequest::get()
+                .uri(&format!("/slugs/resolve?type=category&slug={}", slug))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .to_request()
+        };
+
+        // Act
+        let current =
+            test::call_service(&app, resolve("slug-history-newest")).await;
+        let oldest =
+            test::call_service(&app, resolve("slug-history-old")).await;
+        let unknown =
+            test::call_service(&app, resolve("slug-history-unknown")).await;
+
+        // Assert
+        assert_eq!(current.status(), http::StatusCode::OK);
+        let current: SlugResolutionDTO =
+            serde_json::from_slice(&test::read_body(current).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(current.id, id);
+        assert!(!current.redirected);
+        assert_eq!(oldest.status(), http::StatusCode::OK);
+        let oldest: SlugResolutionDTO =
+            serde_json::from_slice(&test::read_body(oldest).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(oldest.id, id);
+        assert_eq!(oldest.slug, "slug-history-newest");
+        assert!(oldest.redirected);
+        assert_eq!(unknown.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM slug_redirects WHERE target_id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "categories", "slug", "slug-history-newest")
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -7,6 +7,7 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::tag_dto::CreateTagDTO,
+    extractors::permission_extractor::{Authorized, TagsWrite},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::tags_service::create_tag_service,
 };
@@ -19,12 +20,15 @@ use crate::{
     responses(
         (status = 201, description = "Create tag", body = TagDTO),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     ),
 )]
 #[web::post("/tags")]
 pub async fn create_tag_controller(
     pool: State<PgPool>,
+    _auth: Authorized<TagsWrite>,
     tag_dto: Json<CreateTagDTO>,
 ) -> Result<HttpResponse, web::Error> {
     match create_tag_service(pool.get_ref(), tag_dto.into_inner()).await {
@@ -40,7 +44,9 @@ mod tests {
 
     use super::*;
     use crate::dtos::tag_dto::CreateTagDTO;
-    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, setup_test_db,
+    };
 
     #[ntex::test]
     async fn test_create_tag_success() {
@@ -55,13 +61,14 @@ mod tests {
 
         let tag = CreateTagDTO {
             name: String::from("Test Create Tag Success"),
-            slug: String::from("test-create-tag-success"),
+            slug: Some(String::from("test-create-tag-success")),
             description: None,
         };
 
         // Act
         let req = test::TestRequest::post()
             .uri("/tags")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:write"]))
             .set_json(&tag)
             .to_request();
         let resp = test::call_service(&app, req).await;
@@ -88,13 +95,14 @@ mod tests {
 
         let tag = CreateTagDTO {
             name: String::from("Test Validation Failure"),
-            slug: String::from("test-validation-failure-*/!"),
+            slug: Some(String::from("test-validation-failure-*/!")),
             description: None,
         };
 
         // Act
         let req = test::TestRequest::post()
             .uri("/tags")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:write"]))
             .set_json(&tag)
             .to_request();
         let resp = test::call_service(&app, req).await;
@@ -116,13 +124,14 @@ mod tests {
 
         let tag = CreateTagDTO {
             name: String::from("Test Tag Internal Error"),
-            slug: String::from("test-tag-internal-error"),
+            slug: Some(String::from("test-tag-internal-error")),
             description: None,
         };
 
         // Act
         let req = test::TestRequest::post()
             .uri("/tags")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:write"]))
             .set_json(&tag)
             .to_request();
         let resp = test::call_service(&app, req).await;
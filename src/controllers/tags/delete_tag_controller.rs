@@ -7,6 +7,10 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::tag_dto::DeleteTagIdsDTO,
+    extractors::{
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, TagsDelete},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::tags_service::delete_tag_by_id_service,
 };
@@ -16,18 +20,38 @@ use crate::{
     path = "/tags",
     tag = "Tags",
     request_body = DeleteTagIdsDTO,
+    params(
+        ("If-Match" = String, Header, description = "ETag of the single tag to delete, or * to delete any number of tags at any version")
+    ),
     responses(
         (status = 200, description = "Tags deleted", body = i32),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Tag not found", body = Error),
+        (status = 412, description = "The tag has changed since it was read", body = Error),
+        (status = 428, description = "Missing If-Match header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::delete("/tags")]
 pub async fn delete_tag_controller(
     pool: State<PgPool>,
+    _auth: Authorized<TagsDelete>,
+    if_match: IfMatch,
     tag_id: Json<DeleteTagIdsDTO>,
 ) -> Result<HttpResponse, Error> {
-    match delete_tag_by_id_service(pool.get_ref(), tag_id.into_inner()).await {
+    if_match
+        .require()
+        .map_err(|e| convert_anyhow_to_ntex(e.into()))?;
+
+    match delete_tag_by_id_service(
+        pool.get_ref(),
+        &if_match,
+        tag_id.into_inner(),
+    )
+    .await
+    {
         Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
@@ -40,7 +64,7 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::tests::helpers::setup::{bearer_token, setup_test_db};
 
     #[ntex::test]
     async fn test_delete_tag_success() {
@@ -74,6 +98,8 @@ mod tests {
         // Act
         let req = test::TestRequest::delete()
             .uri("/tags")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:delete"]))
+            .header(http::header::IF_MATCH, "*")
             .set_json(&delete_ids)
             .to_request();
         let resp = test::call_service(&app, req).await;
@@ -7,6 +7,7 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::pagination_dto::PaginationParamsDTO,
+    extractors::permission_extractor::{Authorized, TagsRead},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::tags_service::get_all_tags_service,
 };
@@ -18,11 +19,12 @@ use crate::{
   params(
     ("page" = Option<i32>, Query, description = "The page number for pagination"),
     ("limit" = Option<i32>, Query, description = "The number of items per page"),
-    ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'name')"),
-    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ("sort" = Option<Vec<TagSortField>>, Query, style = Form, explode = false, description = "Comma-separated fields to sort by, descending if prefixed with '-' (default '-id')")
   ),
   responses(
     (status = 200, description = "Get all tags", body = [TagDTO]),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
     (status = 404, description = "Tags not found", body = Error),
     (status = 500, description = "Internal Server Error", body = Error)
   ),
@@ -30,22 +32,14 @@ use crate::{
 #[web::get("/tags")]
 pub async fn get_all_tags_controller(
     pool: State<PgPool>,
+    _auth: Authorized<TagsRead>,
     params: Query<PaginationParamsDTO>,
 ) -> Result<HttpResponse, web::Error> {
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(25);
-    let sort_column = params.sort_column.as_deref().unwrap_or("id");
-    let sort_order = params.sort_order.as_deref().unwrap_or("desc");
-
-    match get_all_tags_service(
-        pool.get_ref(),
-        page,
-        limit,
-        sort_column,
-        sort_order,
-    )
-    .await
-    {
+    let sort = params.sort.as_deref().unwrap_or("-id");
+
+    match get_all_tags_service(pool.get_ref(), page, limit, sort).await {
         Ok(tags) => Ok(HttpResponse::Ok().json(&tags)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
@@ -54,8 +48,11 @@ pub async fn get_all_tags_controller(
 #[cfg(test)]
 mod tests {
     use crate::controllers::tags::get_all_tags_controller::get_all_tags_controller;
+    use crate::dtos::pagination_dto::PaginationDTO;
     use crate::dtos::tag_dto::TagDTO;
-    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -69,6 +66,10 @@ mod tests {
                 .service(get_all_tags_controller),
         )
         .await;
+        // Remove any leftover from a previous failed run
+        clean_data_test(&pool, "tags", "slug", "test-get-all-tags")
+            .await
+            .expect("Failed to clean up test data");
 
         sqlx::query!(
             r#"
@@ -76,7 +77,7 @@ mod tests {
             VALUES ($1, $2, $3)
             "#,
             "Test Tag",
-            "test-tag",
+            "test-get-all-tags",
             "Description for test tag"
         )
         .execute(&pool)
@@ -84,19 +85,23 @@ mod tests {
         .expect("Failed to insert test data");
 
         // Act
-        let req = test::TestRequest::get().uri("/tags").to_request();
+        let req = test::TestRequest::get()
+            .uri("/tags")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:read"]))
+            .to_request();
         let resp = test::call_service(&app, req).await;
 
         // Assert
         assert_eq!(resp.status(), http::StatusCode::OK);
 
         let body = test::read_body(resp).await;
-        let response_body: Vec<TagDTO> =
+        let response_body: PaginationDTO<TagDTO> =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
 
-        let tag = response_body
-            .iter()
-            .find(|&tag| tag.name == "Test Tag" && tag.slug == "test-tag");
+        let tag = response_body.data.iter().find(|&tag| {
+            tag.name == "Test Tag"
+                && tag.slug.as_deref() == Some("test-get-all-tags")
+        });
 
         assert!(tag.is_some(), "Tag not found in the response");
         assert_eq!(
@@ -105,7 +110,7 @@ mod tests {
         );
 
         // Clean up test data
-        clean_data_test(&pool, "tags", "name", "Test Tag")
+        clean_data_test(&pool, "tags", "slug", "test-get-all-tags")
             .await
             .expect("Failed to clean up test data");
     }
@@ -121,29 +126,22 @@ mod tests {
         )
         .await;
 
-        // Clear the tags table to ensure the database is empty
-        sqlx::query!("DELETE FROM tags")
-            .execute(&pool)
-            .await
-            .expect("Failed to clear tags table");
-
-        // Ensure the database is empty
-        let row_count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM tags")
-            .fetch_one(&pool)
-            .await
-            .expect("Failed to count tags");
-
-        assert_eq!(row_count.0, 0, "The database is not empty before the test");
+        // Other tests insert tags concurrently, so rather than emptying the
+        // table, ask for a page far past the last one
+        let page_after_last = 2_147_483_647;
 
         // Act
-        let req = test::TestRequest::get().uri("/tags").to_request();
+        let req = test::TestRequest::get()
+            .uri(&format!("/tags?limit=1&page={}", page_after_last))
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:read"]))
+            .to_request();
         let resp = test::call_service(&app, req).await;
 
         // Assert
         assert_eq!(resp.status(), http::StatusCode::OK);
         let body = test::read_body(resp).await;
-        let response_body: Vec<TagDTO> =
+        let response_body: PaginationDTO<TagDTO> =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
-        assert!(response_body.is_empty(), "Expected an empty array");
+        assert!(response_body.data.is_empty(), "Expected an empty page");
     }
 }
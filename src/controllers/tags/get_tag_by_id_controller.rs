@@ -6,7 +6,11 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    extractors::permission_extractor::{Authorized, TagsRead},
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
     services::tags_service::get_tag_by_id_service,
 };
 
@@ -18,7 +22,11 @@ use crate::{
         ("id" = i32, description = "ID of the tag")
     ),
     responses(
-        (status = 200, description = "Tag retrieved", body = TagDTO),
+        (status = 200, description = "Tag retrieved", body = TagDTO, headers(
+            ("ETag" = String, description = "Version of the tag")
+        )),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 404, description = "Tag not found", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
@@ -26,10 +34,11 @@ use crate::{
 #[web::get("/tags/{id}")]
 pub async fn get_tag_by_id_controller(
     pool: State<PgPool>,
+    _auth: Authorized<TagsRead>,
     tag_id: Path<i32>,
 ) -> Result<HttpResponse, web::Error> {
     match get_tag_by_id_service(pool.get_ref(), tag_id.into_inner()).await {
-        Ok(tag) => Ok(HttpResponse::Ok().json(&tag)),
+        Ok(tag) => Ok(ok_with_etag(&tag, tag.version)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
 }
@@ -38,7 +47,7 @@ pub async fn get_tag_by_id_controller(
 mod tests {
     use crate::controllers::tags::get_tag_by_id_controller::get_tag_by_id_controller;
     use crate::dtos::tag_dto::TagDTO;
-    use crate::tests::helpers::setup::setup_test_db;
+    use crate::tests::helpers::setup::{bearer_token, setup_test_db};
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -70,6 +79,7 @@ mod tests {
         // Act
         let req = test::TestRequest::get()
             .uri(&format!("/tags/{}", inserted_tag.id))
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:read"]))
             .to_request();
         let resp = test::call_service(&app, req).await;
 
@@ -82,7 +92,7 @@ mod tests {
 
         let tag = &response_body;
         assert_eq!(tag.name, "Test Tag By ID");
-        assert_eq!(tag.slug, "test-tag-by-id");
+        assert_eq!(tag.slug.as_deref(), Some("test-tag-by-id"));
         assert_eq!(
             tag.description.as_deref(),
             Some("Description for test tag by id")
@@ -0,0 +1,152 @@
+use ntex::web::{
+    self,
+    types::{Path, Query, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::pagination_dto::PaginationParamsDTO,
+    extractors::permission_extractor::{Authorized, PostsRead},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::posts_services::get_posts_by_tag_service,
+};
+
+#[utoipa::path(
+    get,
+    path = "/tags/{id}/posts",
+    tag = "Tags",
+    params(
+        ("id" = i32, description = "ID of the tag"),
+        ("page" = Option<i32>, Query, description = "The page number for pagination"),
+        ("limit" = Option<i32>, Query, description = "The number of items per page"),
+        ("sort" = Option<Vec<PostSortField>>, Query, style = Form, explode = false, description = "Comma-separated fields to sort by, descending if prefixed with '-' (default '-id')")
+    ),
+    responses(
+        (status = 200, description = "Posts having the tag", body = [PostDTO]),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "Tag not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::get("/tags/{id}/posts")]
+pub async fn get_tag_posts_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<PostsRead>,
+    tag_id: Path<i32>,
+    params: Query<PaginationParamsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(25);
+    let sort = params.sort.as_deref().unwrap_or("-id");
+
+    match get_posts_by_tag_service(
+        pool.get_ref(),
+        tag_id.into_inner(),
+        page,
+        limit,
+        sort,
+    )
+    .await
+    {
+        Ok(posts) => Ok(HttpResponse::Ok().json(&posts)),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::dtos::pagination_dto::PaginationDTO;
+    use crate::dtos::post_dto::PostDTO;
+    use crate::repositories::posts_tags_repository::replace_post_tags;
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    const EMAIL: &str = "tag-posts@test.com";
+
+    #[ntex::test]
+    async fn test_get_tag_posts() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id = insert_test_user(&pool, EMAIL, "password").await;
+        let tag_id: i32 = sqlx::query_scalar(
+            "INSERT INTO tags (name, slug) VALUES ($1, $2) RETURNING id",
+        )
+        .bind("Tagged Posts")
+        .bind("tagged-posts")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to insert test tag");
+        let mut post_ids = vec![];
+        for title in ["Tagged post", "Untagged post"] {
+            let post_id: i32 = sqlx::query_scalar(
+                r#"
+                INSERT INTO posts (title, content, slug, author_id)
+                VALUES ($1, '', $2, $3)
+                RETURNING id
+                "#,
+            )
+            .bind(title)
+            .bind(title.to_lowercase().replace(' ', "-"))
+            .bind(user_id)
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to insert test post");
+            post_ids.push(post_id);
+        }
+        let mut conn = pool.acquire().await.expect("Failed to connect");
+        replace_post_tags(&mut conn, post_ids[0], &[tag_id])
+            .await
+            .expect("Failed to tag test post");
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(get_tag_posts_controller),
+        )
+        .await;
+        let list = |tag_id: i32| {
+            test::TestRequest::get()
+                .uri(&format!("/tags/{}/posts", tag_id))
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token(&["posts:read"]),
+                )
+                .to_request()
+        };
+
+        // Act
+        let resp = test::call_service(&app, list(tag_id)).await;
+        let unknown = test::call_service(&app, list(i32::MAX)).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let posts: PaginationDTO<PostDTO> =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(posts.total_items, 1);
+        assert_eq!(posts.data[0].id, Some(post_ids[0]));
+        let tags = posts.data[0].tags.as_ref().expect("Missing tags");
+        assert_eq!(tags[0]["id"], tag_id);
+        assert_eq!(tags[0]["slug"], "tagged-posts");
+        assert_eq!(unknown.status(), http::StatusCode::NOT_FOUND);
+
+        // Clean up test data
+        sqlx::query("DELETE FROM posts WHERE author_id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "users", "email", EMAIL)
+            .await
+            .expect("Failed to clean up test data");
+        clean_data_test(&pool, "tags", "slug", "tagged-posts")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -2,4 +2,5 @@ pub mod create_tag_controller;
 pub mod delete_tag_controller;
 pub mod get_all_tags_controller;
 pub mod get_tag_by_id_controller;
+pub mod get_tag_posts_controller;
 pub mod update_tag_controller;
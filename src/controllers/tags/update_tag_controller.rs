@@ -7,8 +7,15 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::tag_dto::{CreateTagDTO, TagDTO},
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    dtos::tag_dto::CreateTagDTO,
+    extractors::{
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, TagsWrite},
+    },
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
     services::tags_service::update_tag_service,
 };
 
@@ -18,31 +25,43 @@ use crate::{
     tag = "Tags",
     request_body = CreateTagDTO,
     params(
-        ("id" = i32, description = "ID of the tag")
+        ("id" = i32, description = "ID of the tag"),
+        ("If-Match" = String, Header, description = "ETag the tag was read with, or * to overwrite any version")
     ),
     responses(
-        (status = 200, description = "Tag updated", body = TagDTO),
+        (status = 200, description = "Tag updated", body = TagDTO, headers(
+            ("ETag" = String, description = "New version of the tag")
+        )),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 404, description = "Tag not found", body = Error),
+        (status = 412, description = "The tag has changed since it was read", body = Error),
+        (status = 428, description = "Missing If-Match header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::put("/tags/{id}")]
 pub async fn update_tag_controller(
     pool: State<PgPool>,
+    _auth: Authorized<TagsWrite>,
     tag_id: Path<i32>,
+    if_match: IfMatch,
     tag_dto: Json<CreateTagDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    if_match
+        .require()
+        .map_err(|e| convert_anyhow_to_ntex(e.into()))?;
+
     match update_tag_service(
         pool.get_ref(),
         tag_id.into_inner(),
+        &if_match,
         tag_dto.into_inner(),
     )
     .await
     {
-        Ok(updated_tag) => {
-            Ok(HttpResponse::Ok().json(&TagDTO::from(updated_tag)))
-        }
+        Ok(updated_tag) => Ok(ok_with_etag(&updated_tag, updated_tag.version)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
 }
@@ -50,9 +69,14 @@ pub async fn update_tag_controller(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::controllers::tags::create_tag_controller::create_tag_controller;
-    use crate::dtos::tag_dto::CreateTagDTO;
-    use crate::tests::helpers::setup::{clean_data_test, setup_test_db};
+    use crate::controllers::tags::{
+        create_tag_controller::create_tag_controller,
+        delete_tag_controller::delete_tag_controller,
+    };
+    use crate::dtos::tag_dto::{CreateTagDTO, DeleteTagIdsDTO, TagDTO};
+    use crate::tests::helpers::setup::{
+        bearer_token, clean_data_test, setup_test_db,
+    };
     use ntex::http;
     use ntex::web::{self, test};
 
@@ -70,13 +94,14 @@ mod tests {
 
         let new_tag = CreateTagDTO {
             name: String::from("Test Update Tag Success"),
-            slug: String::from("test-update-tag-success"),
+            slug: Some(String::from("test-update-tag-success")),
             description: None,
         };
 
         // Insert a tag first
         let req = test::TestRequest::post()
             .uri("/tags")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:write"]))
             .set_json(&new_tag)
             .to_request();
         let resp = test::call_service(&app, req).await;
@@ -97,12 +122,14 @@ mod tests {
         // Act
         let updated_tag = CreateTagDTO {
             name: String::from("Test Updated Tag New Name"),
-            slug: String::from("test-updated-tag-new-name"),
+            slug: Some(String::from("test-updated-tag-new-name")),
             description: Some(String::from("Updated description")),
         };
 
         let req = test::TestRequest::put()
             .uri(&format!("/tags/{}", tag_id))
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:write"]))
+            .header(http::header::IF_MATCH, "*")
             .set_json(&updated_tag)
             .to_request();
         let resp = test::call_service(&app, req).await;
@@ -112,7 +139,10 @@ mod tests {
         let updated_tag_response: TagDTO =
             serde_json::from_slice(&body).expect("Failed to parse JSON");
         assert_eq!(updated_tag_response.name, "Test Updated Tag New Name");
-        assert_eq!(updated_tag_response.slug, "test-updated-tag-new-name");
+        assert_eq!(
+            updated_tag_response.slug.as_deref(),
+            Some("test-updated-tag-new-name")
+        );
         assert_eq!(
             updated_tag_response.description.unwrap(),
             "Updated description"
@@ -124,6 +154,96 @@ mod tests {
             .expect("Failed to clean up test data");
     }
 
+    #[ntex::test]
+    async fn test_update_tag_if_match() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(create_tag_controller)
+                .service(update_tag_controller)
+                .service(delete_tag_controller),
+        )
+        .await;
+        let token = bearer_token(&["tags:write", "tags:delete"]);
+        let tag_dto = |name: &str| CreateTagDTO {
+            name: String::from(name),
+            slug: None,
+            description: None,
+        };
+        let req = test::TestRequest::post()
+            .uri("/tags")
+            .header(http::header::AUTHORIZATION, token.clone())
+            .set_json(&tag_dto("Test If-Match Tag"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        let created: TagDTO =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse JSON");
+        let tag_id = created.id.unwrap();
+        let update = |if_match: &str, name: &str| {
+            test::TestRequest::put()
+                .uri(&format!("/tags/{}", tag_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .header(http::header::IF_MATCH, if_match)
+                .set_json(&tag_dto(name))
+                .to_request()
+        };
+        let delete = |if_match: &str| {
+            test::TestRequest::delete()
+                .uri("/tags")
+                .header(http::header::AUTHORIZATION, token.clone())
+                .header(http::header::IF_MATCH, if_match)
+                .set_json(&DeleteTagIdsDTO { ids: vec![tag_id] })
+                .to_request()
+        };
+
+        // Act
+        let unconditional = test::call_service(
+            &app,
+            test::TestRequest::put()
+                .uri(&format!("/tags/{}", tag_id))
+                .header(http::header::AUTHORIZATION, token.clone())
+                .set_json(&tag_dto("Test If-Match Tag Blind"))
+                .to_request(),
+        )
+        .await;
+        let updated = test::call_service(
+            &app,
+            update("\"1\"", "Test If-Match Tag Updated"),
+        )
+        .await;
+        let etag = updated.headers().get(http::header::ETAG).cloned();
+        let stale_update =
+            test::call_service(&app, update("\"1\"", "Test If-Match Tag Lost"))
+                .await;
+        let stale_delete = test::call_service(&app, delete("\"1\"")).await;
+        let deleted = test::call_service(&app, delete("\"2\"")).await;
+
+        // Assert
+        assert_eq!(
+            unconditional.status(),
+            http::StatusCode::PRECONDITION_REQUIRED
+        );
+        assert_eq!(updated.status(), http::StatusCode::OK);
+        assert_eq!(etag.unwrap(), "\"2\"");
+        assert_eq!(
+            stale_update.status(),
+            http::StatusCode::PRECONDITION_FAILED
+        );
+        assert_eq!(
+            stale_delete.status(),
+            http::StatusCode::PRECONDITION_FAILED
+        );
+        assert_eq!(deleted.status(), http::StatusCode::OK);
+
+        // Clean up test data
+        clean_data_test(&pool, "tags", "name", "Test If-Match Tag Updated")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
     #[ntex::test]
     async fn test_update_tag_not_found() {
         // Arrange
@@ -137,13 +257,15 @@ mod tests {
 
         let updated_tag = CreateTagDTO {
             name: String::from("Non-existent Tag"),
-            slug: String::from("non-existent-tag"),
+            slug: Some(String::from("non-existent-tag")),
             description: Some(String::from("Description of non-existent tag")),
         };
 
         // Act
         let req = test::TestRequest::put()
             .uri("/tags/999")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:write"]))
+            .header(http::header::IF_MATCH, "*")
             .set_json(&updated_tag)
             .to_request();
         let resp = test::call_service(&app, req).await;
@@ -165,13 +287,15 @@ mod tests {
 
         let invalid_tag = CreateTagDTO {
             name: String::from(""),
-            slug: String::from("invalid-tag-*"),
+            slug: Some(String::from("invalid-tag-*")),
             description: None,
         };
 
         // Act
         let req = test::TestRequest::put()
             .uri("/tags/1")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:write"]))
+            .header(http::header::IF_MATCH, "*")
             .set_json(&invalid_tag)
             .to_request();
         let resp = test::call_service(&app, req).await;
@@ -193,13 +317,15 @@ mod tests {
 
         let valid_tag = CreateTagDTO {
             name: String::from("Valid Tag"),
-            slug: String::from("valid-tag"),
+            slug: Some(String::from("valid-tag")),
             description: Some(String::from("Description of valid tag")),
         };
 
         // Act
         let req = test::TestRequest::put()
             .uri("/tags/1")
+            .header(http::header::AUTHORIZATION, bearer_token(&["tags:write"]))
+            .header(http::header::IF_MATCH, "*")
             .set_json(&valid_tag)
             .to_request();
         let resp = test::call_service(&app, req).await;
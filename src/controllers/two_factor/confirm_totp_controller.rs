@@ -0,0 +1,50 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::two_factor_dtos::TotpCodeDTO,
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::error_middleware::Error,
+    services::two_factor_service::confirm_totp_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/me/2fa/totp/confirm",
+    tag = "Two-factor authentication",
+    request_body = TotpCodeDTO,
+    responses(
+        (status = 200, description = "Two-factor authentication enabled, the recovery codes are only shown once", body = RecoveryCodesDTO),
+        (status = 400, description = "Wrong code or no pending enrolment", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/me/2fa/totp/confirm")]
+pub async fn confirm_totp_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUser,
+    request: Json<TotpCodeDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match confirm_totp_service(
+        pool.get_ref(),
+        current_user.id,
+        request.into_inner(),
+    )
+    .await
+    {
+        Ok(Some(recovery_codes)) => {
+            Ok(HttpResponse::Ok().json(&recovery_codes))
+        }
+        Ok(None) => Ok(HttpResponse::BadRequest().json(&Error {
+            message: String::from("Invalid code or no pending enrolment"),
+            backtrace: None,
+        })),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
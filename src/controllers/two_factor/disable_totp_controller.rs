@@ -0,0 +1,48 @@
+use ntex::web::{
+    self,
+    types::{Json, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    dtos::two_factor_dtos::TotpCodeDTO,
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::error_middleware::Error,
+    services::two_factor_service::disable_totp_service,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/me/2fa/totp",
+    tag = "Two-factor authentication",
+    request_body = TotpCodeDTO,
+    responses(
+        (status = 204, description = "Two-factor authentication disabled"),
+        (status = 400, description = "Wrong code", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/me/2fa/totp")]
+pub async fn disable_totp_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUser,
+    request: Json<TotpCodeDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match disable_totp_service(
+        pool.get_ref(),
+        current_user.id,
+        request.into_inner(),
+    )
+    .await
+    {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::BadRequest().json(&Error {
+            message: String::from("Invalid code"),
+            backtrace: None,
+        })),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
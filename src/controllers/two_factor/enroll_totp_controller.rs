@@ -0,0 +1,119 @@
+use ntex::web::{self, types::State, HttpResponse};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::current_user_extractor::CurrentUser,
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::error_middleware::Error,
+    services::two_factor_service::enroll_totp_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/me/2fa/totp",
+    tag = "Two-factor authentication",
+    responses(
+        (status = 201, description = "Enrolment started, confirm it with a first code", body = TotpEnrollmentDTO),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 409, description = "Two-factor authentication is already enabled", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/me/2fa/totp")]
+pub async fn enroll_totp_controller(
+    pool: State<PgPool>,
+    current_user: CurrentUser,
+) -> Result<HttpResponse, web::Error> {
+    match enroll_totp_service(pool.get_ref(), current_user.id).await {
+        Ok(Some(enrollment)) => Ok(HttpResponse::Created().json(&enrollment)),
+        Ok(None) => Ok(HttpResponse::Conflict().json(&Error {
+            message: String::from(
+                "Two-factor authentication is already enabled",
+            ),
+            backtrace: None,
+        })),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test};
+
+    use super::*;
+    use crate::controllers::two_factor::confirm_totp_controller::confirm_totp_controller;
+    use crate::dtos::two_factor_dtos::{
+        RecoveryCodesDTO, TotpCodeDTO, TotpEnrollmentDTO,
+    };
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+        totp_code,
+    };
+
+    #[ntex::test]
+    async fn test_enroll_and_confirm_totp() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id =
+            insert_test_user(&pool, "enroll-2fa@test.com", "password").await;
+        let app = test::init_service(
+            web::App::new()
+                .state(pool.clone())
+                .service(enroll_totp_controller)
+                .service(confirm_totp_controller),
+        )
+        .await;
+        let enroll = || {
+            test::TestRequest::post()
+                .uri("/me/2fa/totp")
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token_for(user_id, &[]),
+                )
+                .to_request()
+        };
+        let confirm = |code: String| {
+            test::TestRequest::post()
+                .uri("/me/2fa/totp/confirm")
+                .header(
+                    http::header::AUTHORIZATION,
+                    bearer_token_for(user_id, &[]),
+                )
+                .set_json(&TotpCodeDTO { code })
+                .to_request()
+        };
+
+        // Act
+        let resp = test::call_service(&app, enroll()).await;
+        assert_eq!(resp.status(), http::StatusCode::CREATED);
+        let enrollment: TotpEnrollmentDTO =
+            serde_json::from_slice(&test::read_body(resp).await)
+                .expect("Failed to parse JSON");
+
+        let wrong =
+            test::call_service(&app, confirm(String::from("12345"))).await;
+        let confirmed =
+            test::call_service(&app, confirm(totp_code(&enrollment.secret, 0)))
+                .await;
+        let enrolled_again = test::call_service(&app, enroll()).await;
+
+        // Assert
+        assert!(enrollment.otpauth_uri.starts_with("otpauth://totp/"));
+        assert!(enrollment
+            .otpauth_uri
+            .contains(&format!("secret={}", enrollment.secret)));
+        assert_eq!(wrong.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(confirmed.status(), http::StatusCode::OK);
+        let recovery_codes: RecoveryCodesDTO =
+            serde_json::from_slice(&test::read_body(confirmed).await)
+                .expect("Failed to parse JSON");
+        assert_eq!(recovery_codes.recovery_codes.len(), 10);
+        assert_eq!(enrolled_again.status(), http::StatusCode::CONFLICT);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "enroll-2fa@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
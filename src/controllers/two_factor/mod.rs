@@ -0,0 +1,3 @@
+pub mod confirm_totp_controller;
+pub mod disable_totp_controller;
+pub mod enroll_totp_controller;
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use ntex::web::{
     self,
     types::{Json, State},
@@ -7,7 +9,9 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::user_dtos::CreateUserDTO,
+    extractors::permission_extractor::{Authorized, UsersWrite},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    mailers::Mailer,
     services::users_service::create_user_service,
 };
 
@@ -19,15 +23,25 @@ use crate::{
     responses(
         (status = 201, description = "Create user", body = UserDTO),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     ),
 )]
 #[web::post("/users")]
 pub async fn create_user_controller(
     pool: State<PgPool>,
+    mailer: State<Arc<dyn Mailer>>,
+    _auth: Authorized<UsersWrite>,
     user_dto: Json<CreateUserDTO>,
 ) -> Result<HttpResponse, web::Error> {
-    match create_user_service(pool.get_ref(), user_dto.into_inner()).await {
+    match create_user_service(
+        pool.get_ref(),
+        mailer.get_ref().as_ref(),
+        user_dto.into_inner(),
+    )
+    .await
+    {
         Ok(created_user) => Ok(HttpResponse::Created().json(&created_user)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
@@ -7,6 +7,10 @@ use sqlx::PgPool;
 
 use crate::{
     dtos::user_dtos::DeleteUserIdsDTO,
+    extractors::{
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, UsersDelete},
+    },
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::users_service::delete_user_by_id_service,
 };
@@ -16,18 +20,37 @@ use crate::{
     path = "/users",
     tag = "Users",
     request_body = DeleteUserIdsDTO,
+    params(
+        ("If-Match" = String, Header, description = "ETag of the single user to delete, or * to delete any number of users at any version")
+    ),
     responses(
         (status = 200, description = "Users deleted", body = i32),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "User not found", body = Error),
+        (status = 412, description = "The user has changed since it was read", body = Error),
+        (status = 428, description = "Missing If-Match header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::delete("/users")]
 pub async fn delete_user_controller(
     pool: State<PgPool>,
+    _auth: Authorized<UsersDelete>,
+    if_match: IfMatch,
     user_id: Json<DeleteUserIdsDTO>,
 ) -> Result<HttpResponse, Error> {
-    match delete_user_by_id_service(pool.get_ref(), user_id.into_inner()).await
+    if_match
+        .require()
+        .map_err(|e| convert_anyhow_to_ntex(e.into()))?;
+
+    match delete_user_by_id_service(
+        pool.get_ref(),
+        &if_match,
+        user_id.into_inner(),
+    )
+    .await
     {
         Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
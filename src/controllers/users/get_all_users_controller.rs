@@ -6,7 +6,8 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::pagination_dto::PaginationParamsDTO,
+    dtos::{pagination_dto::PaginationParamsDTO, user_dtos::UserFiltersDTO},
+    extractors::permission_extractor::{Authorized, UsersRead},
     handlers::error_to_response_handler::convert_anyhow_to_ntex,
     services::users_service::get_all_users_service,
 };
@@ -18,11 +19,13 @@ use crate::{
   params(
     ("page" = Option<i32>, Query, description = "The page number for pagination"),
     ("limit" = Option<i32>, Query, description = "The number of items per page"),
-    ("sort_column" = Option<String>, Query, description = "Column to sort by (e.g., 'id', 'username')"),
-    ("sort_order" = Option<String>, Query, description = "Sort order ('asc' or 'desc')")
+    ("sort" = Option<Vec<UserSortField>>, Query, style = Form, explode = false, description = "Comma-separated fields to sort by, descending if prefixed with '-' (default '-id')"),
+    UserFiltersDTO
   ),
   responses(
     (status = 200, description = "Get all users", body = [UserDTO]),
+    (status = 401, description = "Unauthorized", body = Error),
+    (status = 403, description = "Forbidden", body = Error),
     (status = 404, description = "Users not found", body = Error),
     (status = 500, description = "Internal Server Error", body = Error)
   ),
@@ -30,19 +33,20 @@ use crate::{
 #[web::get("/users")]
 pub async fn get_all_users_controller(
     pool: State<PgPool>,
+    _auth: Authorized<UsersRead>,
     params: Query<PaginationParamsDTO>,
+    filters: Query<UserFiltersDTO>,
 ) -> Result<HttpResponse, web::Error> {
     let page = params.page.unwrap_or(1);
     let limit = params.limit.unwrap_or(25);
-    let sort_column = params.sort_column.as_deref().unwrap_or("id");
-    let sort_order = params.sort_order.as_deref().unwrap_or("desc");
+    let sort = params.sort.as_deref().unwrap_or("-id");
 
     match get_all_users_service(
         pool.get_ref(),
+        filters.into_inner(),
         page,
         limit,
-        sort_column,
-        sort_order,
+        sort,
     )
     .await
     {
@@ -6,7 +6,11 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    extractors::permission_extractor::{Authorized, UsersRead},
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
     services::users_service::get_user_by_id_service,
 };
 
@@ -18,7 +22,11 @@ use crate::{
         ("id" = i32, description = "ID of the user")
     ),
     responses(
-        (status = 200, description = "User retrieved", body = TagDTO),
+        (status = 200, description = "User retrieved", body = TagDTO, headers(
+            ("ETag" = String, description = "Version of the user")
+        )),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 404, description = "User not found", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
@@ -26,10 +34,11 @@ use crate::{
 #[web::get("/users/{id}")]
 pub async fn get_user_by_id_controller(
     pool: State<PgPool>,
+    _auth: Authorized<UsersRead>,
     user_id: Path<i32>,
 ) -> Result<HttpResponse, web::Error> {
     match get_user_by_id_service(pool.get_ref(), user_id.into_inner()).await {
-        Ok(user) => Ok(HttpResponse::Ok().json(&user)),
+        Ok(user) => Ok(ok_with_etag(&user, user.version)),
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
 }
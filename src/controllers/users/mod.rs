@@ -2,4 +2,5 @@ pub mod create_user_controller;
 pub mod delete_user_controller;
 pub mod get_all_users_controller;
 pub mod get_user_by_id_controller;
+pub mod unlock_user_controller;
 pub mod update_user_controller;
@@ -0,0 +1,39 @@
+use ntex::web::{
+    self,
+    types::{Path, State},
+    HttpResponse,
+};
+use sqlx::PgPool;
+
+use crate::{
+    extractors::permission_extractor::{Authorized, UsersWrite},
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    services::auth_service::unlock_user_service,
+};
+
+#[utoipa::path(
+    post,
+    path = "/users/{id}/unlock",
+    tag = "Users",
+    params(
+        ("id" = i32, description = "ID of the user")
+    ),
+    responses(
+        (status = 204, description = "Failed login attempts cleared"),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 404, description = "User not found", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::post("/users/{id}/unlock")]
+pub async fn unlock_user_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<UsersWrite>,
+    user_id: Path<i32>,
+) -> Result<HttpResponse, web::Error> {
+    match unlock_user_service(pool.get_ref(), user_id.into_inner()).await {
+        Ok(()) => Ok(HttpResponse::NoContent().finish()),
+        Err(e) => Err(convert_anyhow_to_ntex(e)),
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use anyhow::Result;
 use ntex::web::{
     self,
@@ -7,8 +9,16 @@ use ntex::web::{
 use sqlx::PgPool;
 
 use crate::{
-    dtos::user_dtos::{CreateUserDTO, UserDTO},
-    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    dtos::user_dtos::UpdateUserDTO,
+    extractors::{
+        if_match_extractor::IfMatch,
+        permission_extractor::{Authorized, UsersWrite},
+    },
+    handlers::{
+        error_to_response_handler::convert_anyhow_to_ntex,
+        etag_handler::ok_with_etag,
+    },
+    mailers::Mailer,
     services::users_service::update_user_service,
 };
 
@@ -16,32 +26,48 @@ use crate::{
     put,
     path = "/users/{id}",
     tag = "Users",
-    request_body = CreateUserDTO,
+    request_body = UpdateUserDTO,
     params(
-        ("id" = i32, description = "ID of the user")
+        ("id" = i32, description = "ID of the user"),
+        ("If-Match" = String, Header, description = "ETag the user was read with, or * to overwrite any version")
     ),
     responses(
-        (status = 200, description = "User updated", body = TagDTO),
+        (status = 200, description = "User updated", body = UserDTO, headers(
+            ("ETag" = String, description = "New version of the user")
+        )),
         (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
         (status = 404, description = "User not found", body = Error),
+        (status = 412, description = "The user has changed since it was read", body = Error),
+        (status = 428, description = "Missing If-Match header", body = Error),
         (status = 500, description = "Internal Server Error", body = Error)
     )
 )]
 #[web::put("/users/{id}")]
 pub async fn update_user_controller(
     pool: State<PgPool>,
+    mailer: State<Arc<dyn Mailer>>,
+    _auth: Authorized<UsersWrite>,
     user_id: Path<i32>,
-    user_dto: Json<CreateUserDTO>,
+    if_match: IfMatch,
+    user_dto: Json<UpdateUserDTO>,
 ) -> Result<HttpResponse, web::Error> {
+    if_match
+        .require()
+        .map_err(|e| convert_anyhow_to_ntex(e.into()))?;
+
     match update_user_service(
         pool.get_ref(),
+        mailer.get_ref().as_ref(),
         user_id.into_inner(),
+        &if_match,
         user_dto.into_inner(),
     )
     .await
     {
         Ok(updated_user) => {
-            Ok(HttpResponse::Ok().json(&UserDTO::from(updated_user)))
+            Ok(ok_with_etag(&updated_user, updated_user.version))
         }
         Err(e) => Err(convert_anyhow_to_ntex(e)),
     }
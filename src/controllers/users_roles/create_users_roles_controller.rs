@@ -0,0 +1,35 @@
+use ntex::web::types::{Json, State};
+use ntex::web::{self, HttpResponse};
+use sqlx::PgPool;
+
+use crate::dtos::users_roles_dto::CreateUsersRolesDTO;
+use crate::extractors::permission_extractor::{Authorized, RolesWrite};
+use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
+use crate::services::users_roles_service::create_user_role_service;
+
+#[utoipa::path(
+    post,
+    path = "/users-roles",
+    tag = "Users Roles",
+    request_body = CreateUsersRolesDTO,
+    responses(
+        (status = 201, description = "Users Roles created successfully", body = UsersRolesDTO),
+        (status = 400, description = "Validation error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal server error", body = Error)
+    )
+)]
+#[web::post("/users-roles")]
+pub async fn create_users_roles_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesWrite>,
+    users_roles_dto: Json<CreateUsersRolesDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match create_user_role_service(pool.get_ref(), users_roles_dto.into_inner())
+        .await
+    {
+        Ok(users_roles) => Ok(HttpResponse::Created().json(&users_roles)),
+        Err(err) => Err(convert_anyhow_to_ntex(err)),
+    }
+}
@@ -0,0 +1,35 @@
+use ntex::web::types::{Json, State};
+use ntex::web::{self, HttpResponse};
+use sqlx::PgPool;
+
+use crate::dtos::users_roles_dto::DeleteUsersRolesIdsDTO;
+use crate::extractors::permission_extractor::{Authorized, RolesWrite};
+use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
+use crate::services::users_roles_service::delete_user_role_service;
+
+#[utoipa::path(
+    delete,
+    path = "/users-roles",
+    tag = "Users Roles",
+    request_body = DeleteUsersRolesIdsDTO,
+    responses(
+        (status = 200, description = "Users Roles deleted", body = i32),
+        (status = 400, description = "Validation Error", body = Error),
+        (status = 401, description = "Unauthorized", body = Error),
+        (status = 403, description = "Forbidden", body = Error),
+        (status = 500, description = "Internal Server Error", body = Error)
+    )
+)]
+#[web::delete("/users-roles")]
+pub async fn delete_users_roles_controller(
+    pool: State<PgPool>,
+    _auth: Authorized<RolesWrite>,
+    delete_ids_dto: Json<DeleteUsersRolesIdsDTO>,
+) -> Result<HttpResponse, web::Error> {
+    match delete_user_role_service(pool.get_ref(), delete_ids_dto.into_inner())
+        .await
+    {
+        Ok(deleted_ids) => Ok(HttpResponse::Ok().json(&deleted_ids)),
+        Err(err) => Err(convert_anyhow_to_ntex(err)),
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::models::api_keys_model::ApiKeyModel;
+
+/// Creating an API key
+#[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
+pub struct CreateApiKeyDTO {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    /// Permissions the key is limited to, among those of the user
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// The key never expires if omitted
+    #[schema(value_type = Option<String>, format = "date-time", example = "2025-01-01T00:00:00")]
+    pub expires_at: Option<NaiveDateTime>,
+}
+
+/// An API key, without its secret
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiKeyDTO {
+    pub id: i32,
+    pub name: String,
+    /// Public part of the key, to tell keys apart
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    #[schema(value_type = Option<String>, format = "date-time", example = "2025-01-01T00:00:00")]
+    pub expires_at: Option<NaiveDateTime>,
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub last_used_at: Option<NaiveDateTime>,
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub revoked_at: Option<NaiveDateTime>,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: NaiveDateTime,
+}
+
+/// Converts `ApiKeyModel` to `ApiKeyDTO`
+impl From<ApiKeyModel> for ApiKeyDTO {
+    fn from(api_key: ApiKeyModel) -> Self {
+        ApiKeyDTO {
+            id: api_key.id,
+            name: api_key.name,
+            prefix: api_key.prefix,
+            scopes: api_key.scopes,
+            expires_at: api_key.expires_at,
+            last_used_at: api_key.last_used_at,
+            revoked_at: api_key.revoked_at,
+            date_created: api_key.date_created,
+        }
+    }
+}
+
+/// A new API key, the only time its secret is shown
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreatedApiKeyDTO {
+    /// The full key, to be sent as `Authorization: Bearer <key>`
+    pub key: String,
+    pub api_key: ApiKeyDTO,
+}
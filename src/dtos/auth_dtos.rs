@@ -7,12 +7,16 @@ pub struct TokenDTO {
     pub token: String,
     pub token_type: String,
     pub expires_in: usize,
+    pub refresh_token: String,
+    pub refresh_expires_in: usize,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ClaimsDTO {
     pub sub: String,
     pub exp: usize,
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
 }
 
 #[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
@@ -31,3 +35,91 @@ pub struct LoginRequestDTO {
     ))]
     pub password: String,
 }
+
+#[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
+pub struct RefreshTokenRequestDTO {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Refresh token must be between 1 and 255 characters"
+    ))]
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
+pub struct ForgotPasswordRequestDTO {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Email must be between 1 and 100 characters"
+    ))]
+    #[validate(email(message = "Email must be a valid email address"))]
+    pub email: String,
+}
+
+#[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
+pub struct ResetPasswordRequestDTO {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Token must be between 1 and 255 characters"
+    ))]
+    pub token: String,
+
+    #[validate(length(
+        min = 6,
+        message = "Password must be at least 6 characters long"
+    ))]
+    pub password: String,
+}
+
+#[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
+pub struct ChangePasswordRequestDTO {
+    #[validate(length(
+        min = 1,
+        message = "Current password must not be empty"
+    ))]
+    pub current_password: String,
+
+    #[validate(length(
+        min = 6,
+        message = "Password must be at least 6 characters long"
+    ))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
+pub struct VerifyEmailRequestDTO {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Token must be between 1 and 255 characters"
+    ))]
+    pub token: String,
+}
+
+/// Returned by the password step of a login when the account has two-factor
+/// authentication enabled.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorChallengeDTO {
+    pub challenge_token: String,
+    pub expires_in: usize,
+}
+
+#[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
+pub struct TwoFactorLoginRequestDTO {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Challenge token must be between 1 and 255 characters"
+    ))]
+    pub challenge_token: String,
+
+    /// A code from the authenticator app, or an unused recovery code.
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Code must be between 1 and 64 characters"
+    ))]
+    pub code: String,
+}
@@ -1,12 +1,12 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationErrors};
 
 use crate::{
     handlers::generate_slug_handler::generate_slug,
-    models::categories_model::CategoryModel,
+    models::categories_model::{CategoryFilters, CategoryModel},
     validators::slug_validator::validate_slug,
 };
 
@@ -50,6 +50,7 @@ impl TryFrom<CreateCategoryDTO> for CategoryModel {
             slug: Some(slug),
             description: dto.description.map(|desc| desc.trim().to_string()),
             date_created: None,
+            version: None,
         };
 
         category.validate()?;
@@ -67,6 +68,7 @@ pub struct CategoryDTO {
     pub description: Option<String>,
     #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
     pub date_created: Option<NaiveDateTime>,
+    pub version: Option<i32>,
 }
 
 /// Converts `CategoryModel` to `CategoryDTO`
@@ -79,6 +81,7 @@ impl From<CategoryModel> for CategoryDTO {
             slug: category.slug,
             description: category.description,
             date_created: category.date_created,
+            version: category.version,
         }
     }
 }
@@ -95,9 +98,31 @@ impl TryFrom<CategoryDTO> for CategoryModel {
             slug: dto.slug,
             description: dto.description,
             date_created: dto.date_created,
+            version: dto.version,
         };
 
         category.validate()?;
         Ok(category)
     }
 }
+
+/// Query parameters filtering a list of categories
+#[derive(Validate, Deserialize, Debug, Default, ToSchema, IntoParams)]
+pub struct CategoryFiltersDTO {
+    /// The ID of the parent of the categories
+    #[validate(range(min = 1, message = "Parent ID must be at least 1"))]
+    pub parent_id: Option<i32>,
+}
+
+/// Converts `CategoryFiltersDTO` to `CategoryFilters`
+impl TryFrom<CategoryFiltersDTO> for CategoryFilters {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CategoryFiltersDTO) -> Result<Self, Self::Error> {
+        dto.validate()?;
+
+        Ok(CategoryFilters {
+            parent_id: dto.parent_id,
+        })
+    }
+}
@@ -1,7 +1,19 @@
+pub mod api_key_dtos;
 pub mod auth_dtos;
 pub mod category_dto;
 pub mod pagination_dto;
+pub mod permission_dto;
+pub mod post_draft_dtos;
 pub mod post_dto;
+pub mod post_revision_dtos;
 pub mod posts_categories_dto;
+pub mod public_post_dtos;
+pub mod redirect_dtos;
+pub mod role_dto;
+pub mod roles_permissions_dto;
+pub mod scheduler_dtos;
+pub mod slug_dtos;
 pub mod tag_dto;
+pub mod two_factor_dtos;
 pub mod user_dtos;
+pub mod users_roles_dto;
@@ -21,11 +21,9 @@ pub struct PaginationParamsDTO {
     #[schema(example = 1)]
     pub page: Option<i64>,
 
-    #[schema(example = "id")]
-    pub sort_column: Option<String>,
-
-    #[schema(example = "asc")]
-    pub sort_order: Option<String>,
+    /// Comma-separated fields to sort by, descending if prefixed with `-`
+    #[schema(example = "-date_created,name")]
+    pub sort: Option<String>,
 }
 
 #[derive(Validate, Serialize, Deserialize, FromRow, Debug, ToSchema)]
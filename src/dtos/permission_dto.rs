@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationErrors};
+
+use crate::models::permissions_model::PermissionModel;
+
+/// Batch deletion of permissions
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeletePermissionIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Creating a permission
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct CreatePermissionDTO {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Converts `CreatePermissionDTO` to `PermissionModel`
+impl TryFrom<CreatePermissionDTO> for PermissionModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreatePermissionDTO) -> Result<Self, Self::Error> {
+        let permission = PermissionModel {
+            id: None,
+            name: dto.name.trim().to_string(),
+            description: dto.description.map(|desc| desc.trim().to_string()),
+            date_created: None,
+        };
+
+        permission.validate()?;
+        Ok(permission)
+    }
+}
+
+/// Full permission data
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct PermissionDTO {
+    pub id: Option<i32>,
+    pub name: String,
+    pub description: Option<String>,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `PermissionModel` to `PermissionDTO`
+impl From<PermissionModel> for PermissionDTO {
+    fn from(permission: PermissionModel) -> Self {
+        PermissionDTO {
+            id: permission.id,
+            name: permission.name,
+            description: permission.description,
+            date_created: permission.date_created,
+        }
+    }
+}
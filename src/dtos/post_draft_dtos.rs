@@ -0,0 +1,82 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+    handlers::generate_slug_handler::generate_slug,
+    models::post_drafts_model::PostDraftModel,
+};
+
+/// Autosaving the working draft of a post
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SavePostDraftDTO {
+    pub title: String,
+    pub content: String,
+    /// Generated from the title when missing
+    pub slug: Option<String>,
+}
+
+/// Converts `SavePostDraftDTO` to `PostDraftModel`, for the given post and
+/// editor IDs and the current version of the post
+impl TryFrom<(SavePostDraftDTO, i32, i32, i32)> for PostDraftModel {
+    type Error = ValidationErrors;
+
+    fn try_from(
+        (dto, post_id, editor_id, base_version): (
+            SavePostDraftDTO,
+            i32,
+            i32,
+            i32,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let draft = PostDraftModel {
+            post_id,
+            slug_generated: dto.slug.is_none(),
+            slug: dto.slug.unwrap_or_else(|| generate_slug(&dto.title)),
+            title: dto.title,
+            content: dto.content,
+            editor_id: Some(editor_id),
+            base_version,
+            date_created: None,
+            date_updated: None,
+        };
+
+        draft.validate()?;
+        Ok(draft)
+    }
+}
+
+/// Working draft data
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PostDraftDTO {
+    pub post_id: i32,
+    pub title: String,
+    pub content: String,
+    pub slug: String,
+    pub editor_id: Option<i32>,
+    /// The version of the post the draft was started from, see `ETag`
+    pub base_version: i32,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_updated: Option<NaiveDateTime>,
+}
+
+/// Converts `PostDraftModel` to `PostDraftDTO`
+impl From<PostDraftModel> for PostDraftDTO {
+    fn from(draft: PostDraftModel) -> Self {
+        PostDraftDTO {
+            post_id: draft.post_id,
+            title: draft.title,
+            content: draft.content,
+            slug: draft.slug,
+            editor_id: draft.editor_id,
+            base_version: draft.base_version,
+            date_created: draft.date_created,
+            date_updated: draft.date_updated,
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationErrors};
 
 use crate::{
-    handlers::generate_slug_handler::generate_slug,
-    models::posts_model::{PostModel, PostsStatus},
+    handlers::{
+        generate_slug_handler::generate_slug,
+        render_content_handler::{render_blocks_text, render_content},
+    },
+    models::{
+        post_blocks_model::PostBlocks,
+        posts_model::{ContentFormat, PostFilters, PostModel, PostsStatus},
+    },
     validators::slug_validator::validate_slug,
 };
 
@@ -16,6 +22,12 @@ pub struct DeletePostIdsDTO {
     pub ids: Vec<i32>,
 }
 
+/// Batch restoration or purge of trashed posts
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct TrashedPostIdsDTO {
+    pub ids: Vec<i32>,
+}
+
 #[derive(FromRow)]
 pub struct PostId {
     pub id: i32,
@@ -25,19 +37,35 @@ pub struct PostId {
 #[derive(FromRow, Serialize, Deserialize, ToSchema)]
 pub struct CreatePostDTO {
     pub title: String,
+    /// The source of the post, the blocks as JSON in the `blocks` format
+    #[serde(default)]
     pub content: String,
+    /// How the content is written, `html` by default
+    #[serde(default)]
+    pub content_format: ContentFormat,
+    /// Blocks replacing `content`, setting the `blocks` format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
+    pub content_blocks: Option<PostBlocks>,
     pub slug: Option<String>,
-    pub author_id: i32,
     pub status: PostsStatus,
     pub date_published: Option<NaiveDateTime>,
     pub categories_ids: Vec<i32>,
+    /// IDs of existing tags
+    #[serde(default)]
+    pub tag_ids: Vec<i32>,
+    /// Names of tags to attach, created unless a tag has the same slug
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
-/// Converts `CreatePostDTO` to `PostModel`
-impl TryFrom<CreatePostDTO> for PostModel {
+/// Converts `CreatePostDTO` to `PostModel`, authored by the given user ID
+impl TryFrom<(CreatePostDTO, i32)> for PostModel {
     type Error = ValidationErrors;
 
-    fn try_from(dto: CreatePostDTO) -> Result<Self, Self::Error> {
+    fn try_from(
+        (dto, author_id): (CreatePostDTO, i32),
+    ) -> Result<Self, Self::Error> {
         let mut errors = ValidationErrors::new();
         let slug = dto.slug.unwrap_or_else(|| generate_slug(&dto.title));
         let min_length = 1;
@@ -51,29 +79,74 @@ impl TryFrom<CreatePostDTO> for PostModel {
             return Err(errors);
         }
 
-        let post = PostModel {
+        // Blocks are stored as their canonical JSON
+        let (content, content_format) = match dto.content_blocks {
+            Some(blocks) => {
+                blocks.validate()?;
+                (blocks.to_content(), ContentFormat::Blocks)
+            }
+            None if dto.content_format == ContentFormat::Blocks => {
+                let blocks = PostBlocks::parse(&dto.content)?;
+                (blocks.to_content(), ContentFormat::Blocks)
+            }
+            None => (dto.content, dto.content_format),
+        };
+
+        let mut post = PostModel {
             id: None,
             title: dto.title,
-            content: dto.content,
+            content,
+            content_format,
+            content_html: None,
             slug: Some(slug),
-            author_id: dto.author_id,
+            author_id,
             status: dto.status,
             date_published: dto.date_published,
             date_created: None,
+            updated_by: None,
+            date_updated: None,
+            version: None,
+            deleted_at: None,
             categories: None,
+            tags: None,
         };
 
         post.validate()?;
+        post.content_html =
+            Some(render_content(&post.content_format, &post.content));
         Ok(post)
     }
 }
 
+/// Scheduling a post
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SchedulePostDTO {
+    /// When the post is published, in UTC
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_published: NaiveDateTime,
+}
+
 /// Full post data
 #[derive(FromRow, Serialize, Deserialize, ToSchema)]
 pub struct PostDTO {
     pub id: Option<i32>,
     pub title: String,
     pub content: String,
+    #[serde(default)]
+    pub content_format: ContentFormat,
+
+    /// The sanitized HTML rendering of `content`
+    pub content_html: Option<String>,
+
+    /// The blocks of a post in the `blocks` format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
+    pub content_blocks: Option<PostBlocks>,
+
+    /// The plain text of a post in the `blocks` format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
+    pub content_text: Option<String>,
     pub slug: Option<String>,
     pub author_id: i32,
     pub status: PostsStatus,
@@ -83,22 +156,48 @@ pub struct PostDTO {
 
     #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
     pub date_created: Option<NaiveDateTime>,
+    pub updated_by: Option<i32>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_updated: Option<NaiveDateTime>,
+    pub version: Option<i32>,
+
+    /// When the post was moved to the trash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub deleted_at: Option<NaiveDateTime>,
     pub categories: Option<serde_json::Value>,
+    pub tags: Option<serde_json::Value>,
 }
 
 /// Converts `PostModel` to `PostDTO`
 impl From<PostModel> for PostDTO {
     fn from(post: PostModel) -> Self {
+        let content_blocks = match post.content_format {
+            ContentFormat::Blocks => PostBlocks::parse(&post.content).ok(),
+            _ => None,
+        };
+        let content_text = content_blocks.as_ref().map(render_blocks_text);
+
         PostDTO {
             id: post.id,
             title: post.title,
             content: post.content,
+            content_format: post.content_format,
+            content_html: post.content_html,
+            content_blocks,
+            content_text,
             slug: post.slug,
             author_id: post.author_id,
             status: post.status,
             date_published: post.date_published,
             date_created: post.date_created,
+            updated_by: post.updated_by,
+            date_updated: post.date_updated,
+            version: post.version,
+            deleted_at: post.deleted_at,
             categories: post.categories,
+            tags: post.tags,
         }
     }
 }
@@ -112,15 +211,86 @@ impl TryFrom<PostDTO> for PostModel {
             id: dto.id,
             title: dto.title,
             content: dto.content,
+            content_format: dto.content_format,
+            content_html: dto.content_html,
             slug: dto.slug,
             author_id: dto.author_id,
             status: dto.status,
             date_published: dto.date_published,
             date_created: dto.date_created,
+            updated_by: dto.updated_by,
+            date_updated: dto.date_updated,
+            version: dto.version,
+            deleted_at: dto.deleted_at,
             categories: dto.categories,
+            tags: dto.tags,
         };
 
         tag.validate()?;
         Ok(tag)
     }
 }
+
+/// Query parameters filtering a list of posts, combined with AND
+#[derive(Validate, Deserialize, Debug, Default, ToSchema, IntoParams)]
+pub struct PostFiltersDTO {
+    pub status: Option<PostsStatus>,
+
+    #[validate(range(min = 1, message = "Author ID must be at least 1"))]
+    pub author_id: Option<i32>,
+
+    #[validate(range(min = 1, message = "Category ID must be at least 1"))]
+    pub category_id: Option<i32>,
+
+    /// The slug of a tag of the posts
+    #[validate(length(
+        max = 200,
+        message = "Tag cannot exceed 200 characters"
+    ))]
+    #[schema(example = "rust")]
+    pub tag: Option<String>,
+
+    /// Published at or after this date
+    #[serde(rename = "date_published[gte]")]
+    #[param(rename = "date_published[gte]")]
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_published_gte: Option<NaiveDateTime>,
+
+    /// Published at or before this date
+    #[serde(rename = "date_published[lte]")]
+    #[param(rename = "date_published[lte]")]
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-12-31T23:59:59")]
+    pub date_published_lte: Option<NaiveDateTime>,
+
+    /// Words searched for in the title and content, case-insensitively
+    #[validate(length(
+        max = 200,
+        message = "Search cannot exceed 200 characters"
+    ))]
+    pub q: Option<String>,
+}
+
+/// Converts `PostFiltersDTO` to `PostFilters`, ignoring empty text filters
+impl TryFrom<PostFiltersDTO> for PostFilters {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: PostFiltersDTO) -> Result<Self, Self::Error> {
+        dto.validate()?;
+
+        Ok(PostFilters {
+            status: dto.status,
+            author_id: dto.author_id,
+            category_id: dto.category_id,
+            tag: non_empty(dto.tag),
+            date_published_gte: dto.date_published_gte,
+            date_published_lte: dto.date_published_lte,
+            q: non_empty(dto.q),
+        })
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
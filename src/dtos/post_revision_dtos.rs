@@ -0,0 +1,81 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    handlers::line_diff_handler::DiffLine,
+    models::{
+        post_revisions_model::PostRevisionModel,
+        posts_model::{ContentFormat, PostsStatus},
+    },
+};
+
+/// A revision of a post, listed without its content
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PostRevisionSummaryDTO {
+    pub revision_number: i32,
+    pub title: String,
+    pub slug: String,
+    pub status: PostsStatus,
+    pub editor_id: Option<i32>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: NaiveDateTime,
+}
+
+/// Converts `PostRevisionModel` to `PostRevisionSummaryDTO`
+impl From<PostRevisionModel> for PostRevisionSummaryDTO {
+    fn from(revision: PostRevisionModel) -> Self {
+        PostRevisionSummaryDTO {
+            revision_number: revision.revision_number,
+            title: revision.title,
+            slug: revision.slug,
+            status: revision.status,
+            editor_id: revision.editor_id,
+            date_created: revision.date_created,
+        }
+    }
+}
+
+/// Full revision data
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PostRevisionDTO {
+    pub post_id: i32,
+    pub revision_number: i32,
+    pub title: String,
+    pub content: String,
+    pub content_format: ContentFormat,
+    pub slug: String,
+    pub status: PostsStatus,
+    pub editor_id: Option<i32>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: NaiveDateTime,
+}
+
+/// Converts `PostRevisionModel` to `PostRevisionDTO`
+impl From<PostRevisionModel> for PostRevisionDTO {
+    fn from(revision: PostRevisionModel) -> Self {
+        PostRevisionDTO {
+            post_id: revision.post_id,
+            revision_number: revision.revision_number,
+            title: revision.title,
+            content: revision.content,
+            content_format: revision.content_format,
+            slug: revision.slug,
+            status: revision.status,
+            editor_id: revision.editor_id,
+            date_created: revision.date_created,
+        }
+    }
+}
+
+/// Line diffs turning a revision into another
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PostRevisionDiffDTO {
+    pub from_revision: i32,
+    pub to_revision: i32,
+    pub title: Vec<DiffLine>,
+    pub slug: Vec<DiffLine>,
+    pub content: Vec<DiffLine>,
+}
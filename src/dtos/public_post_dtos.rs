@@ -0,0 +1,83 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::handlers::render_content_handler::render_blocks_text;
+use crate::models::post_blocks_model::PostBlocks;
+use crate::models::posts_model::{ContentFormat, PublicPostModel};
+
+/// Pagination of the public post list, most recent first
+#[derive(Validate, Deserialize, Debug, ToSchema, IntoParams)]
+pub struct PublicPostsParamsDTO {
+    #[validate(range(min = 1, message = "Page must be at least 1"))]
+    #[schema(example = 1)]
+    pub page: Option<i64>,
+
+    #[validate(range(
+        min = 1,
+        max = 50,
+        message = "Limit must be between 1 and 50"
+    ))]
+    #[schema(example = 10)]
+    pub limit: Option<i64>,
+}
+
+/// A published post, without its editorial data
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PublicPostDTO {
+    pub id: i32,
+    pub title: String,
+    pub slug: Option<String>,
+
+    /// The sanitized HTML rendering of the post
+    pub content_html: Option<String>,
+
+    /// The blocks of a post in the `blocks` format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_blocks: Option<PostBlocks>,
+
+    /// The plain text of a post in the `blocks` format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_text: Option<String>,
+
+    /// The display name of the author
+    pub author_name: String,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_published: Option<NaiveDateTime>,
+
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_updated: Option<NaiveDateTime>,
+
+    /// The categories of the post, with their `id`, `name` and `slug`
+    pub categories: serde_json::Value,
+
+    /// The tags of the post, with their `id`, `name` and `slug`
+    pub tags: serde_json::Value,
+}
+
+/// Converts `PublicPostModel` to `PublicPostDTO`
+impl From<PublicPostModel> for PublicPostDTO {
+    fn from(post: PublicPostModel) -> Self {
+        let content_blocks = match post.content_format {
+            ContentFormat::Blocks => PostBlocks::parse(&post.content).ok(),
+            _ => None,
+        };
+        let content_text = content_blocks.as_ref().map(render_blocks_text);
+
+        PublicPostDTO {
+            id: post.id,
+            title: post.title,
+            slug: post.slug,
+            content_html: post.content_html,
+            content_blocks,
+            content_text,
+            author_name: post.author_name,
+            date_published: post.date_published,
+            date_updated: post.date_updated,
+            categories: post.categories,
+            tags: post.tags,
+        }
+    }
+}
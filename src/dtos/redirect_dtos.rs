@@ -0,0 +1,84 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationErrors};
+
+use crate::models::redirects_model::RedirectModel;
+
+/// Batch deletion of redirects
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteRedirectIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Creating or updating a redirect
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateRedirectDTO {
+    /// The path on this site to redirect from, with its query string if any
+    #[schema(example = "/old-page")]
+    pub source_path: String,
+    /// A path on this site or an absolute http or https URL
+    #[schema(example = "/new-page")]
+    pub target: String,
+    /// 301 for a permanent redirect, the default, or 302 for a temporary one
+    #[schema(example = 301)]
+    pub status_code: Option<i32>,
+}
+
+/// Converts `CreateRedirectDTO` to `RedirectModel`
+impl TryFrom<CreateRedirectDTO> for RedirectModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreateRedirectDTO) -> Result<Self, Self::Error> {
+        let redirect = RedirectModel {
+            id: None,
+            source_path: dto.source_path.trim().to_string(),
+            target: dto.target.trim().to_string(),
+            status_code: dto.status_code.unwrap_or(301),
+            hits: 0,
+            last_hit_at: None,
+            date_created: None,
+        };
+
+        redirect.validate()?;
+        Ok(redirect)
+    }
+}
+
+/// Full redirect data
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RedirectDTO {
+    pub id: Option<i32>,
+    pub source_path: String,
+    pub target: String,
+    pub status_code: i32,
+    /// The number of times the redirect was followed
+    pub hits: i64,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub last_hit_at: Option<NaiveDateTime>,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `RedirectModel` to `RedirectDTO`
+impl From<RedirectModel> for RedirectDTO {
+    fn from(redirect: RedirectModel) -> Self {
+        RedirectDTO {
+            id: redirect.id,
+            source_path: redirect.source_path,
+            target: redirect.target,
+            status_code: redirect.status_code,
+            hits: redirect.hits,
+            last_hit_at: redirect.last_hit_at,
+            date_created: redirect.date_created,
+        }
+    }
+}
+
+/// Query parameters of a followed redirect
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct FollowRedirectParamsDTO {
+    /// The requested path on this site
+    #[schema(example = "/old-page")]
+    pub path: String,
+}
@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationErrors};
+
+use crate::models::roles_model::RoleModel;
+
+/// Batch deletion of roles
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteRoleIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Creating a role
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct CreateRoleDTO {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Converts `CreateRoleDTO` to `RoleModel`
+impl TryFrom<CreateRoleDTO> for RoleModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreateRoleDTO) -> Result<Self, Self::Error> {
+        let role = RoleModel {
+            id: None,
+            name: dto.name.trim().to_string(),
+            description: dto.description.map(|desc| desc.trim().to_string()),
+            date_created: None,
+        };
+
+        role.validate()?;
+        Ok(role)
+    }
+}
+
+/// Full role data
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct RoleDTO {
+    pub id: Option<i32>,
+    pub name: String,
+    pub description: Option<String>,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `RoleModel` to `RoleDTO`
+impl From<RoleModel> for RoleDTO {
+    fn from(role: RoleModel) -> Self {
+        RoleDTO {
+            id: role.id,
+            name: role.name,
+            description: role.description,
+            date_created: role.date_created,
+        }
+    }
+}
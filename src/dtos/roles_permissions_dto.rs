@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationErrors};
+
+use crate::models::roles_permissions_model::RolesPermissionsModel;
+
+/// Batch deletion of roles permissions
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct DeleteRolesPermissionsIdsDTO {
+    pub ids: Vec<i32>,
+}
+
+/// Creating a roles permissions relationship
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct CreateRolesPermissionsDTO {
+    pub role_id: i32,
+    pub permission_id: i32,
+}
+
+/// Converts `CreateRolesPermissionsDTO` to `RolesPermissionsModel`
+impl TryFrom<CreateRolesPermissionsDTO> for RolesPermissionsModel {
+    type Error = ValidationErrors;
+
+    fn try_from(dto: CreateRolesPermissionsDTO) -> Result<Self, Self::Error> {
+        let roles_permissions = RolesPermissionsModel {
+            id: None,
+            role_id: dto.role_id,
+            permission_id: dto.permission_id,
+            date_created: None,
+        };
+
+        roles_permissions.validate()?;
+        Ok(roles_permissions)
+    }
+}
+
+#[derive(FromRow, Serialize, Deserialize, ToSchema)]
+pub struct RolesPermissionsDTO {
+    pub id: Option<i32>,
+    pub role_id: i32,
+    pub permission_id: i32,
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+/// Converts `RolesPermissionsModel` to `RolesPermissionsDTO`
+impl From<RolesPermissionsModel> for RolesPermissionsDTO {
+    fn from(roles_permissions: RolesPermissionsModel) -> Self {
+        RolesPermissionsDTO {
+            id: roles_permissions.id,
+            role_id: roles_permissions.role_id,
+            permission_id: roles_permissions.permission_id,
+            date_created: roles_permissions.date_created,
+        }
+    }
+}
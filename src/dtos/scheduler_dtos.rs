@@ -0,0 +1,50 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::models::posts_model::PostModel;
+
+/// Query parameters of the scheduled runs listing
+#[derive(Validate, Deserialize, Debug, ToSchema, IntoParams)]
+pub struct NextRunsParamsDTO {
+    #[validate(range(
+        min = 1,
+        max = 100,
+        message = "Limit must be between 1 and 100"
+    ))]
+    #[schema(example = 20)]
+    pub limit: Option<i64>,
+}
+
+/// A scheduled post and the time it is due to be published
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ScheduledRunDTO {
+    pub post_id: i32,
+    pub title: String,
+    pub slug: Option<String>,
+
+    /// Due runs are published on the next pass of the worker
+    #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub run_at: Option<NaiveDateTime>,
+}
+
+/// Converts a scheduled `PostModel` to `ScheduledRunDTO`
+impl From<PostModel> for ScheduledRunDTO {
+    fn from(post: PostModel) -> Self {
+        ScheduledRunDTO {
+            post_id: post.id.unwrap_or_default(),
+            title: post.title,
+            slug: post.slug,
+            run_at: post.date_published,
+        }
+    }
+}
+
+/// The upcoming work of the scheduled publishing worker
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct NextRunsDTO {
+    /// Seconds between two passes of the worker, `0` if it is disabled
+    pub interval_seconds: u16,
+    pub runs: Vec<ScheduledRunDTO>,
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::slugs_model::SlugType;
+
+/// Query parameters of the slug availability check
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct SlugCheckParamsDTO {
+    /// The content type the slug is for
+    #[serde(rename = "type")]
+    #[param(rename = "type")]
+    pub slug_type: SlugType,
+
+    #[schema(example = "hello-world")]
+    pub slug: String,
+
+    /// The ID of the content being edited, whose own slug is available
+    pub exclude_id: Option<i32>,
+}
+
+/// Whether a slug is free, and the slug a new content would get otherwise
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SlugAvailabilityDTO {
+    pub slug: String,
+    pub available: bool,
+    /// The slug itself if it is available, otherwise the first free one
+    /// with a `-2`, `-3`… suffix
+    pub suggestion: String,
+}
+
+/// Query parameters of the slug lookup
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct SlugResolveParamsDTO {
+    /// The content type the slug is for
+    #[serde(rename = "type")]
+    #[param(rename = "type")]
+    pub slug_type: SlugType,
+
+    /// A current or previous slug
+    #[schema(example = "hello-world")]
+    pub slug: String,
+}
+
+/// The content a slug points to
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SlugResolutionDTO {
+    #[serde(rename = "type")]
+    pub slug_type: SlugType,
+    pub id: i32,
+    /// The current slug of the content
+    pub slug: String,
+    /// Whether the requested slug is a previous one, to redirect to `slug`
+    pub redirected: bool,
+}
@@ -46,6 +46,7 @@ impl TryFrom<CreateTagDTO> for TagModel {
             slug: Some(slug),
             description: dto.description,
             date_created: None,
+            version: None,
         };
 
         tag.validate()?;
@@ -62,6 +63,7 @@ pub struct TagDTO {
     pub description: Option<String>,
     #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
     pub date_created: Option<NaiveDateTime>,
+    pub version: Option<i32>,
 }
 
 /// Converts `TagModel` to `TagDTO`
@@ -73,6 +75,7 @@ impl From<TagModel> for TagDTO {
             slug: tag.slug,
             description: tag.description,
             date_created: tag.date_created,
+            version: tag.version,
         }
     }
 }
@@ -88,6 +91,7 @@ impl TryFrom<TagDTO> for TagModel {
             slug: dto.slug,
             description: dto.description,
             date_created: dto.date_created,
+            version: dto.version,
         };
 
         tag.validate()?;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// A pending TOTP enrolment, to be added to an authenticator app
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TotpEnrollmentDTO {
+    /// Base32 encoded secret, for manual entry
+    pub secret: String,
+    /// `otpauth://` URI, to be rendered as a QR code
+    pub otpauth_uri: String,
+}
+
+/// A code proving possession of the second factor
+#[derive(Debug, Validate, Serialize, Deserialize, ToSchema)]
+pub struct TotpCodeDTO {
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Code must be between 1 and 64 characters"
+    ))]
+    pub code: String,
+}
+
+/// One-time recovery codes, shown only once
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RecoveryCodesDTO {
+    pub recovery_codes: Vec<String>,
+}
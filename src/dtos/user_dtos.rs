@@ -1,10 +1,10 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::{Validate, ValidationErrors};
 
-use crate::models::users_models::UserModel;
+use crate::models::users_models::{UserFilters, UserModel};
 
 /// Batch deletion of user
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -38,7 +38,9 @@ impl TryFrom<CreateUserDTO> for UserModel {
             lastname: dto.lastname,
             url: dto.url,
             active: dto.active,
+            email_verified_at: None,
             date_created: None,
+            version: None,
         };
 
         user.validate()?;
@@ -46,19 +48,99 @@ impl TryFrom<CreateUserDTO> for UserModel {
     }
 }
 
+/// Partial update of a user by an administrator, omitted fields are left
+/// unchanged
+#[derive(Default, Validate, Serialize, Deserialize, ToSchema)]
+pub struct UpdateUserDTO {
+    pub username: Option<String>,
+    /// Replaces the password and signs the user out of every session
+    #[validate(length(
+        min = 6,
+        message = "Password must be at least 6 characters long"
+    ))]
+    pub password: Option<String>,
+    pub email: Option<String>,
+    pub firstname: Option<String>,
+    pub lastname: Option<String>,
+    /// An empty string removes the URL
+    pub url: Option<String>,
+    pub active: Option<bool>,
+}
+
+impl UpdateUserDTO {
+    /// Applies the provided fields to `user`, leaving its password untouched.
+    pub fn apply_to(
+        self,
+        mut user: UserModel,
+    ) -> Result<UserModel, ValidationErrors> {
+        if let Some(username) = self.username {
+            user.username = username;
+        }
+        if let Some(email) = self.email {
+            user.email = email;
+        }
+        if let Some(firstname) = self.firstname {
+            user.firstname = firstname;
+        }
+        if let Some(lastname) = self.lastname {
+            user.lastname = lastname;
+        }
+        if let Some(url) = self.url {
+            user.url = Some(url).filter(|url| !url.trim().is_empty());
+        }
+        if let Some(active) = self.active {
+            user.active = active;
+        }
+
+        user.validate()?;
+        Ok(user)
+    }
+}
+
+/// Partial update of the current user's own profile
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UpdateProfileDTO {
+    pub username: Option<String>,
+    pub email: Option<String>,
+    pub firstname: Option<String>,
+    pub lastname: Option<String>,
+    /// An empty string removes the URL
+    pub url: Option<String>,
+}
+
+/// Converts `UpdateProfileDTO` to `UpdateUserDTO`
+impl From<UpdateProfileDTO> for UpdateUserDTO {
+    fn from(dto: UpdateProfileDTO) -> Self {
+        UpdateUserDTO {
+            username: dto.username,
+            email: dto.email,
+            firstname: dto.firstname,
+            lastname: dto.lastname,
+            url: dto.url,
+            ..Default::default()
+        }
+    }
+}
+
 /// Full user data
 #[derive(FromRow, Serialize, Deserialize, ToSchema)]
 pub struct UserDTO {
     pub id: Option<i32>,
     pub username: String,
+    /// The password hash is never sent back
+    #[serde(skip_serializing, default)]
+    #[schema(write_only)]
     pub password: String,
     pub email: String,
     pub firstname: String,
     pub lastname: String,
     pub url: Option<String>,
     pub active: bool,
+    #[schema(value_type = Option<String>, format = "date-time", example = "2022-01-01T00:00:00")]
+    pub email_verified_at: Option<NaiveDateTime>,
     #[schema(value_type = String, format = "date-time", example = "2022-01-01T00:00:00")]
     pub date_created: Option<NaiveDateTime>,
+    pub version: Option<i32>,
 }
 
 /// Converts `UserModel` to `UserDTO`
@@ -73,7 +155,9 @@ impl From<UserModel> for UserDTO {
             lastname: user.lastname,
             url: user.url,
             active: user.active,
+            email_verified_at: user.email_verified_at,
             date_created: user.date_created,
+            version: user.version,
         }
     }
 }
@@ -92,10 +176,26 @@ impl TryFrom<UserDTO> for UserModel {
             lastname: dto.lastname,
             url: dto.url,
             active: dto.active,
+            email_verified_at: dto.email_verified_at,
             date_created: dto.date_created,
+            version: dto.version,
         };
 
         user.validate()?;
         Ok(user)
     }
 }
+
+/// Query parameters filtering a list of users
+#[derive(Deserialize, Debug, Default, ToSchema, IntoParams)]
+pub struct UserFiltersDTO {
+    /// Whether the accounts are active
+    pub active: Option<bool>,
+}
+
+/// Converts `UserFiltersDTO` to `UserFilters`
+impl From<UserFiltersDTO> for UserFilters {
+    fn from(dto: UserFiltersDTO) -> Self {
+        UserFilters { active: dto.active }
+    }
+}
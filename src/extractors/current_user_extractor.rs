@@ -0,0 +1,155 @@
+use ntex::http::Payload;
+use ntex::web::{self, DefaultError, FromRequest, HttpRequest};
+use sqlx::PgPool;
+
+use crate::{
+    handlers::error_to_response_handler::convert_anyhow_to_ntex,
+    middlewares::auth_middleware::{ApiKeyId, TokenError},
+    repositories::users_repository::select_user_by_id,
+};
+
+use super::{forbidden, request_claims, unauthorized};
+
+/// Extractor resolving the authenticated user behind the request.
+///
+/// The user is loaded from the database on each request, so a deactivated
+/// or deleted account is rejected even while its token has not expired.
+pub struct CurrentUser {
+    pub id: i32,
+    /// The ID of the API key the request was authenticated with, if it was
+    /// not authenticated with a JWT.
+    pub api_key_id: Option<i32>,
+}
+
+impl FromRequest<DefaultError> for CurrentUser {
+    type Error = web::Error;
+
+    async fn from_request(
+        req: &HttpRequest,
+        _: &mut Payload,
+    ) -> Result<Self, Self::Error> {
+        let claims = request_claims(req)?;
+
+        let id = match claims.sub.parse::<i32>() {
+            Ok(id) => id,
+            Err(_) => return Err(unauthorized(TokenError::Invalid)),
+        };
+
+        let pool = req
+            .app_state::<PgPool>()
+            .expect("PgPool must be registered as application state");
+
+        let user = match select_user_by_id(pool, id).await {
+            Ok(user) => user,
+            Err(e) => match e.downcast_ref::<sqlx::Error>() {
+                Some(sqlx::Error::RowNotFound) => {
+                    return Err(unauthorized(TokenError::Invalid))
+                }
+                _ => return Err(convert_anyhow_to_ntex(e)),
+            },
+        };
+
+        if !user.active {
+            return Err(forbidden("User account is inactive".to_string()));
+        }
+
+        let api_key_id =
+            req.extensions().get::<ApiKeyId>().map(|api_key| api_key.0);
+
+        Ok(CurrentUser { id, api_key_id })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test, App, HttpResponse};
+
+    use super::*;
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    #[web::get("/me")]
+    async fn me_controller(user: CurrentUser) -> HttpResponse {
+        HttpResponse::Ok().body(user.id.to_string())
+    }
+
+    #[ntex::test]
+    async fn test_current_user_missing_token() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let app = test::init_service(
+            App::new().state(pool.clone()).service(me_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get().uri("/me").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[ntex::test]
+    async fn test_current_user_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id =
+            insert_test_user(&pool, "current-user@test.com", "password").await;
+        let app = test::init_service(
+            App::new().state(pool.clone()).service(me_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/me")
+            .header(http::header::AUTHORIZATION, bearer_token_for(user_id, &[]))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let body = test::read_body(resp).await;
+        assert_eq!(body, user_id.to_string());
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "current-user@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_current_user_inactive() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id =
+            insert_test_user(&pool, "inactive-user@test.com", "password").await;
+        sqlx::query("UPDATE users SET active = FALSE WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to deactivate test user");
+        let app = test::init_service(
+            App::new().state(pool.clone()).service(me_controller),
+        )
+        .await;
+
+        // Act
+        let req = test::TestRequest::get()
+            .uri("/me")
+            .header(http::header::AUTHORIZATION, bearer_token_for(user_id, &[]))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "inactive-user@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+
+use ntex::http::{header, Payload};
+use ntex::web::{self, DefaultError, FromRequest, HttpRequest};
+
+use crate::handlers::etag_handler::etag;
+
+/// Extractor for the `If-Match` header, guarding a write against a resource
+/// that changed since the client read it.
+///
+/// The header is optional unless the controller calls `require`: without
+/// it, the write applies to the current version of the resource.
+#[derive(Default)]
+pub struct IfMatch {
+    /// The listed entity tags, `None` if the header is missing.
+    tags: Option<Vec<String>>,
+}
+
+impl IfMatch {
+    /// Builds the precondition from the values of the `If-Match` header.
+    fn parse<'a>(values: impl Iterator<Item = &'a str>) -> Self {
+        let tags: Vec<String> = values
+            .flat_map(|value| value.split(','))
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+
+        IfMatch {
+            tags: (!tags.is_empty()).then_some(tags),
+        }
+    }
+
+    /// Whether the request sent an `If-Match` header.
+    pub fn is_present(&self) -> bool {
+        self.tags.is_some()
+    }
+
+    /// Whether the header is `*`, matching any version of the resource.
+    pub fn is_any(&self) -> bool {
+        self.tags.as_deref() == Some(&[String::from("*")][..])
+    }
+
+    /// Rejects a write that did not send the header, for resources where an
+    /// unconditional write would silently overwrite concurrent edits.
+    pub fn require(&self) -> Result<(), PreconditionRequiredError> {
+        if !self.is_present() {
+            return Err(PreconditionRequiredError);
+        }
+
+        Ok(())
+    }
+
+    /// Checks the precondition against the current version of the resource.
+    ///
+    /// Passes without the header, or if it is `*` or lists the `ETag` of the
+    /// version. Weak tags never match.
+    pub fn check(
+        &self,
+        version: Option<i32>,
+    ) -> Result<(), PreconditionFailedError> {
+        let tags = match &self.tags {
+            Some(tags) => tags,
+            None => return Ok(()),
+        };
+
+        let current = version.map(etag);
+        let matches = tags
+            .iter()
+            .any(|tag| tag == "*" || Some(tag) == current.as_ref());
+
+        if !matches {
+            return Err(PreconditionFailedError);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromRequest<DefaultError> for IfMatch {
+    type Error = web::Error;
+
+    async fn from_request(
+        req: &HttpRequest,
+        _: &mut Payload,
+    ) -> Result<Self, Self::Error> {
+        // A value that is not valid UTF-8 cannot be one of our tags
+        let values = req
+            .headers()
+            .get_all(header::IF_MATCH)
+            .map(|value| value.to_str().unwrap_or("\"\""));
+
+        Ok(IfMatch::parse(values))
+    }
+}
+
+/// A write whose `If-Match` header does not match the current version of the
+/// resource, or that lost the race against a concurrent write.
+#[derive(Debug)]
+pub struct PreconditionFailedError;
+
+impl PreconditionFailedError {
+    /// Maps the `RowNotFound` of a write restricted to the version that was
+    /// just checked: the row changed in the meantime.
+    pub fn on_row_not_found(e: anyhow::Error) -> anyhow::Error {
+        match e.downcast_ref::<sqlx::Error>() {
+            Some(sqlx::Error::RowNotFound) => PreconditionFailedError.into(),
+            _ => e,
+        }
+    }
+}
+
+impl fmt::Display for PreconditionFailedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The resource has changed since it was read, fetch it again \
+             and retry with its new ETag"
+        )
+    }
+}
+
+impl std::error::Error for PreconditionFailedError {}
+
+/// A write sent without the `If-Match` header a resource requires.
+#[derive(Debug)]
+pub struct PreconditionRequiredError;
+
+impl fmt::Display for PreconditionRequiredError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "This request requires an If-Match header with the ETag the \
+             resource was read with, or * to apply it to any version"
+        )
+    }
+}
+
+impl std::error::Error for PreconditionRequiredError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_if_match_missing_header() {
+        // Arrange
+        let if_match = IfMatch::parse(std::iter::empty());
+
+        // Act
+        let result = if_match.check(Some(3));
+
+        // Assert
+        assert!(!if_match.is_present());
+        assert!(result.is_ok());
+        assert!(if_match.require().is_err());
+    }
+
+    #[test]
+    fn test_if_match_lists_version() {
+        // Arrange
+        let if_match = IfMatch::parse(["\"2\", \"3\""].into_iter());
+
+        // Act
+        let current = if_match.check(Some(3));
+        let stale = if_match.check(Some(4));
+
+        // Assert
+        assert!(if_match.is_present());
+        assert!(current.is_ok());
+        assert!(stale.is_err());
+    }
+
+    #[test]
+    fn test_if_match_any_and_weak_tags() {
+        // Arrange
+        let any = IfMatch::parse(["*"].into_iter());
+        let weak = IfMatch::parse(["W/\"3\""].into_iter());
+
+        // Act & Assert
+        assert!(any.check(Some(3)).is_ok());
+        assert!(any.is_any());
+        assert!(any.require().is_ok());
+        assert!(weak.check(Some(3)).is_err());
+        assert!(!weak.is_any());
+    }
+}
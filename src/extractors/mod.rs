@@ -0,0 +1,43 @@
+use ntex::web::{self, error::InternalError, HttpRequest, HttpResponse};
+
+use crate::{
+    dtos::auth_dtos::ClaimsDTO,
+    middlewares::auth_middleware::{
+        decode_bearer_claims, forbidden_response, unauthorized_response,
+        TokenError,
+    },
+};
+
+pub mod current_user_extractor;
+pub mod if_match_extractor;
+pub mod permission_extractor;
+
+/// Returns the claims of the request's bearer token.
+///
+/// The token is decoded once per request; the claims are then kept in the
+/// request extensions for the other extractors.
+fn request_claims(req: &HttpRequest) -> Result<ClaimsDTO, web::Error> {
+    if let Some(claims) = req.extensions().get::<ClaimsDTO>() {
+        return Ok(claims.clone());
+    }
+
+    let claims = decode_bearer_claims(req.head()).map_err(unauthorized)?;
+    req.extensions_mut().insert(claims.clone());
+
+    Ok(claims)
+}
+
+/// Rejects the request with a 401 and the JSON `Error` body.
+fn unauthorized(error: TokenError) -> web::Error {
+    into_error(unauthorized_response(&error))
+}
+
+/// Rejects the request with a 403 and the JSON `Error` body.
+fn forbidden(message: String) -> web::Error {
+    into_error(forbidden_response(&message))
+}
+
+fn into_error(response: HttpResponse) -> web::Error {
+    let status = response.status();
+    InternalError::from_response(status, response).into()
+}
@@ -0,0 +1,163 @@
+use std::marker::PhantomData;
+
+use ntex::http::Payload;
+use ntex::web::{self, DefaultError, FromRequest, HttpRequest};
+
+use crate::dtos::auth_dtos::ClaimsDTO;
+
+use super::{forbidden, request_claims};
+
+/// A permission that a controller can require through `Authorized<P>`.
+pub trait RequiredPermission {
+    const NAME: &'static str;
+}
+
+macro_rules! permissions {
+    ($($permission:ident => $name:literal),* $(,)?) => {
+        $(
+            pub struct $permission;
+
+            impl RequiredPermission for $permission {
+                const NAME: &'static str = $name;
+            }
+        )*
+    };
+}
+
+permissions! {
+    PostsRead => "posts:read",
+    PostsWrite => "posts:write",
+    PostsDelete => "posts:delete",
+    PostsPublish => "posts:publish",
+    CategoriesRead => "categories:read",
+    CategoriesWrite => "categories:write",
+    CategoriesDelete => "categories:delete",
+    TagsRead => "tags:read",
+    TagsWrite => "tags:write",
+    TagsDelete => "tags:delete",
+    UsersRead => "users:read",
+    UsersWrite => "users:write",
+    UsersDelete => "users:delete",
+    RolesRead => "roles:read",
+    RolesWrite => "roles:write",
+    RolesDelete => "roles:delete",
+    RedirectsRead => "redirects:read",
+    RedirectsWrite => "redirects:write",
+    RedirectsDelete => "redirects:delete",
+}
+
+/// Extractor that only lets the request through if its token grants `P`.
+///
+/// Declaring `_: Authorized<UsersDelete>` in a controller signature is enough
+/// to restrict it; the decoded claims remain available for the controller.
+pub struct Authorized<P: RequiredPermission> {
+    pub claims: ClaimsDTO,
+    _permission: PhantomData<P>,
+}
+
+impl<P: RequiredPermission> FromRequest<DefaultError> for Authorized<P> {
+    type Error = web::Error;
+
+    async fn from_request(
+        req: &HttpRequest,
+        _: &mut Payload,
+    ) -> Result<Self, Self::Error> {
+        let claims = request_claims(req)?;
+
+        check_permission(&claims, P::NAME)?;
+
+        Ok(Authorized {
+            claims,
+            _permission: PhantomData,
+        })
+    }
+}
+
+impl<P: RequiredPermission> Authorized<P> {
+    /// Requires an additional permission on top of `P`, for checks that
+    /// depend on the request body (e.g. publishing while writing a post).
+    pub fn require<Q: RequiredPermission>(&self) -> Result<(), web::Error> {
+        check_permission(&self.claims, Q::NAME)
+    }
+}
+
+fn check_permission(
+    claims: &ClaimsDTO,
+    permission: &str,
+) -> Result<(), web::Error> {
+    if claims.permissions.iter().any(|p| p == permission) {
+        return Ok(());
+    }
+
+    Err(forbidden(format!("Missing permission: {}", permission)))
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::http;
+    use ntex::web::{self, test, App, HttpResponse};
+
+    use super::*;
+    use crate::tests::helpers::setup::bearer_token;
+
+    #[web::delete("/protected")]
+    async fn protected_controller(
+        _auth: Authorized<UsersDelete>,
+    ) -> HttpResponse {
+        HttpResponse::Ok().finish()
+    }
+
+    #[ntex::test]
+    async fn test_authorized_missing_token() {
+        // Arrange
+        let app =
+            test::init_service(App::new().service(protected_controller)).await;
+
+        // Act
+        let req = test::TestRequest::delete().uri("/protected").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[ntex::test]
+    async fn test_authorized_missing_permission() {
+        // Arrange
+        let app =
+            test::init_service(App::new().service(protected_controller)).await;
+
+        // Act
+        let req = test::TestRequest::delete()
+            .uri("/protected")
+            .header(
+                http::header::AUTHORIZATION,
+                bearer_token(&["posts:write", "posts:delete"]),
+            )
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[ntex::test]
+    async fn test_authorized_success() {
+        // Arrange
+        let app =
+            test::init_service(App::new().service(protected_controller)).await;
+
+        // Act
+        let req = test::TestRequest::delete()
+            .uri("/protected")
+            .header(
+                http::header::AUTHORIZATION,
+                bearer_token(&["users:delete"]),
+            )
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
+}
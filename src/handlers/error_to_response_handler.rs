@@ -7,7 +7,11 @@ use serde_json::error::Error as SerdeJsonError;
 use sqlx::Error as SqlxError; // Importer le type d'erreur SQLx
 use validator::ValidationErrors;
 
+use crate::extractors::if_match_extractor::{
+    PreconditionFailedError, PreconditionRequiredError,
+};
 use crate::middlewares::error_middleware::Error;
+use crate::models::posts_model::IllegalTransitionError;
 
 /// Maps `anyhow::Error` to `ntex::web::Error`, handling specific error types and logging them.
 pub fn convert_anyhow_to_ntex(e: AnyhowError) -> web::Error {
@@ -15,44 +19,71 @@ pub fn convert_anyhow_to_ntex(e: AnyhowError) -> web::Error {
     let mut backtrace = None;
 
     // Determine the type of error and assign an appropriate HTTP status code
-    let status_code =
-        if let Some(validation_errors) = e.downcast_ref::<ValidationErrors>() {
-            // Handle validation errors
-            error_message = format!(
-                "Validation error: {}",
-                format_validation_errors(validation_errors)
-            );
-            warn!("Validation error occurred: {:?}", validation_errors);
-            StatusCode::BAD_REQUEST
-        } else if let Some(json_error) = e.downcast_ref::<SerdeJsonError>() {
-            // Handle JSON serialization/deserialization errors
-            error_message = format!("JSON deserialize error: {}", json_error);
-            warn!("JSON deserialization error: {}", json_error);
-            StatusCode::BAD_REQUEST
-        } else if let Some(sqlx_error) = e.downcast_ref::<SqlxError>() {
-            // Handle SQLx database errors
-            error_message = format_sqlx_error(sqlx_error);
-            match sqlx_error {
-                SqlxError::RowNotFound => {
-                    info!("Row not found in the database");
-                    StatusCode::NOT_FOUND
-                }
-                SqlxError::Database(_) => {
-                    error!("Database error: {:?}", sqlx_error);
-                    StatusCode::INTERNAL_SERVER_ERROR
-                }
-                _ => {
-                    warn!("SQLx error: {:?}", sqlx_error);
-                    StatusCode::BAD_REQUEST
-                }
+    let status_code = if let Some(validation_errors) =
+        e.downcast_ref::<ValidationErrors>()
+    {
+        // Handle validation errors
+        error_message = format!(
+            "Validation error: {}",
+            format_validation_errors(validation_errors)
+        );
+        warn!("Validation error occurred: {:?}", validation_errors);
+        StatusCode::BAD_REQUEST
+    } else if let Some(transition_error) =
+        e.downcast_ref::<IllegalTransitionError>()
+    {
+        // Handle status changes the post workflow forbids
+        error_message = transition_error.to_string();
+        info!("Illegal post transition: {}", transition_error);
+        StatusCode::CONFLICT
+    } else if let Some(precondition_error) =
+        e.downcast_ref::<PreconditionFailedError>()
+    {
+        // Handle writes based on a stale copy of the resource
+        error_message = precondition_error.to_string();
+        info!("Precondition failed: {}", precondition_error);
+        StatusCode::PRECONDITION_FAILED
+    } else if let Some(required_error) =
+        e.downcast_ref::<PreconditionRequiredError>()
+    {
+        // Handle writes that must be conditional but sent no If-Match
+        error_message = required_error.to_string();
+        info!("Precondition required: {}", required_error);
+        StatusCode::PRECONDITION_REQUIRED
+    } else if let Some(json_error) = e.downcast_ref::<SerdeJsonError>() {
+        // Handle JSON serialization/deserialization errors
+        error_message = format!("JSON deserialize error: {}", json_error);
+        warn!("JSON deserialization error: {}", json_error);
+        StatusCode::BAD_REQUEST
+    } else if let Some(sqlx_error) = e.downcast_ref::<SqlxError>() {
+        // Handle SQLx database errors
+        error_message = format_sqlx_error(sqlx_error);
+        match sqlx_error {
+            SqlxError::RowNotFound => {
+                info!("Row not found in the database");
+                StatusCode::NOT_FOUND
             }
-        } else {
-            // Generic error, include a backtrace if available
-            error_message = format!("{:?}", e);
-            backtrace = Some(format!("{:?}", e.backtrace()));
-            error!("Internal server error: {:?}", e);
-            StatusCode::INTERNAL_SERVER_ERROR
-        };
+            SqlxError::Database(db_error) if db_error.is_unique_violation() => {
+                // Handle values already taken, such as slugs
+                info!("Unique constraint violated: {:?}", db_error);
+                StatusCode::CONFLICT
+            }
+            SqlxError::Database(_) => {
+                error!("Database error: {:?}", sqlx_error);
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            _ => {
+                warn!("SQLx error: {:?}", sqlx_error);
+                StatusCode::BAD_REQUEST
+            }
+        }
+    } else {
+        // Generic error, include a backtrace if available
+        error_message = format!("{:?}", e);
+        backtrace = Some(format!("{:?}", e.backtrace()));
+        error!("Internal server error: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
 
     // Create an HTTP response with the determined status code and error details
     let response = HttpResponse::build(status_code).json(&Error {
@@ -0,0 +1,22 @@
+use ntex::http::header;
+use ntex::web::HttpResponse;
+use serde::Serialize;
+
+/// The strong entity tag of a resource at the given version.
+pub fn etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Builds a 200 response with the resource as JSON and its version as the
+/// `ETag` header, for the client to send back in `If-Match`.
+pub fn ok_with_etag<T: Serialize>(
+    body: &T,
+    version: Option<i32>,
+) -> HttpResponse {
+    let mut response = HttpResponse::Ok();
+    if let Some(version) = version {
+        response.header(header::ETAG, etag(version));
+    }
+
+    response.json(body)
+}
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Above this many pairs of lines to compare, the changed lines are shown as
+/// removed then added rather than matched line by line.
+const MAX_COMPARED_LINE_PAIRS: usize = 4_000_000;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffOperation {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// A line of a diff, kept, removed from the old text or added by the new one
+#[derive(Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct DiffLine {
+    pub operation: DiffOperation,
+    pub line: String,
+}
+
+/// Computes a line diff turning `old` into `new`, keeping the longest common
+/// subsequence of lines.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old
+        .iter()
+        .zip(&new)
+        .take_while(|(old_line, new_line)| old_line == new_line)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old_line, new_line)| old_line == new_line)
+        .count();
+
+    let mut lines = to_diff_lines(DiffOperation::Equal, &old[..prefix]);
+    lines.extend(diff_changed_lines(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    ));
+    lines.extend(to_diff_lines(
+        DiffOperation::Equal,
+        &old[old.len() - suffix..],
+    ));
+
+    lines
+}
+
+fn diff_changed_lines(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    if old.len() * new.len() > MAX_COMPARED_LINE_PAIRS {
+        let mut lines = to_diff_lines(DiffOperation::Delete, old);
+        lines.extend(to_diff_lines(DiffOperation::Insert, new));
+        return lines;
+    }
+
+    // Length of the longest common subsequence of old[i..] and new[j..],
+    // at most 2000 lines given the limit above
+    let width = new.len() + 1;
+    let mut lcs = vec![0u16; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i * width + j] = if old[i] == new[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(diff_line(DiffOperation::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            lines.push(diff_line(DiffOperation::Delete, old[i]));
+            i += 1;
+        } else {
+            lines.push(diff_line(DiffOperation::Insert, new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(to_diff_lines(DiffOperation::Delete, &old[i..]));
+    lines.extend(to_diff_lines(DiffOperation::Insert, &new[j..]));
+
+    lines
+}
+
+fn diff_line(operation: DiffOperation, line: &str) -> DiffLine {
+    DiffLine {
+        operation,
+        line: line.to_string(),
+    }
+}
+
+fn to_diff_lines(operation: DiffOperation, lines: &[&str]) -> Vec<DiffLine> {
+    lines
+        .iter()
+        .map(|line| diff_line(operation, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DiffOperation::*;
+
+    fn operations(diff: &[DiffLine]) -> Vec<(DiffOperation, &str)> {
+        diff.iter()
+            .map(|line| (line.operation, line.line.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_lines_identical() {
+        let diff = diff_lines("a\nb", "a\nb");
+        assert_eq!(operations(&diff), vec![(Equal, "a"), (Equal, "b")]);
+    }
+
+    #[test]
+    fn test_diff_lines_changed_line() {
+        let diff = diff_lines("a\nb\nc", "a\nB\nc");
+        assert_eq!(
+            operations(&diff),
+            vec![(Equal, "a"), (Delete, "b"), (Insert, "B"), (Equal, "c")]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_insert_and_delete() {
+        let diff = diff_lines("a\nb\nc\nd", "x\na\nc\nd\ny");
+        assert_eq!(
+            operations(&diff),
+            vec![
+                (Insert, "x"),
+                (Equal, "a"),
+                (Delete, "b"),
+                (Equal, "c"),
+                (Equal, "d"),
+                (Insert, "y"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_empty() {
+        assert!(diff_lines("", "").is_empty());
+        assert_eq!(operations(&diff_lines("", "a")), vec![(Insert, "a")]);
+        assert_eq!(operations(&diff_lines("a", "")), vec![(Delete, "a")]);
+    }
+}
@@ -1,3 +1,6 @@
 pub mod error_to_response_handler;
+pub mod etag_handler;
 pub mod generate_slug_handler;
+pub mod line_diff_handler;
 pub mod openapi_handler;
+pub mod render_content_handler;
@@ -8,16 +8,68 @@ use utoipa::{
 
 use crate::{
     dtos::{
-        auth_dtos::{ClaimsDTO, LoginRequestDTO, TokenDTO},
-        category_dto::{CategoryDTO, CreateCategoryDTO, DeleteCategoryIdsDTO},
+        api_key_dtos::{ApiKeyDTO, CreateApiKeyDTO, CreatedApiKeyDTO},
+        auth_dtos::{
+            ChangePasswordRequestDTO, ClaimsDTO, ForgotPasswordRequestDTO,
+            LoginRequestDTO, RefreshTokenRequestDTO, ResetPasswordRequestDTO,
+            TokenDTO, TwoFactorChallengeDTO, TwoFactorLoginRequestDTO,
+            VerifyEmailRequestDTO,
+        },
+        category_dto::{
+            CategoryDTO, CategoryFiltersDTO, CreateCategoryDTO,
+            DeleteCategoryIdsDTO,
+        },
         pagination_dto::PaginationParamsDTO,
-        post_dto::{CreatePostDTO, DeletePostIdsDTO, PostDTO},
+        permission_dto::{
+            CreatePermissionDTO, DeletePermissionIdsDTO, PermissionDTO,
+        },
+        post_draft_dtos::{PostDraftDTO, SavePostDraftDTO},
+        post_dto::{
+            CreatePostDTO, DeletePostIdsDTO, PostDTO, PostFiltersDTO,
+            SchedulePostDTO, TrashedPostIdsDTO,
+        },
+        post_revision_dtos::{
+            PostRevisionDTO, PostRevisionDiffDTO, PostRevisionSummaryDTO,
+        },
         posts_categories_dto::{CreatePostsCategoriesDTO, PostsCategoriesDTO},
+        public_post_dtos::{PublicPostDTO, PublicPostsParamsDTO},
+        redirect_dtos::{
+            CreateRedirectDTO, DeleteRedirectIdsDTO, FollowRedirectParamsDTO,
+            RedirectDTO,
+        },
+        role_dto::{CreateRoleDTO, DeleteRoleIdsDTO, RoleDTO},
+        roles_permissions_dto::{
+            CreateRolesPermissionsDTO, DeleteRolesPermissionsIdsDTO,
+            RolesPermissionsDTO,
+        },
+        scheduler_dtos::{NextRunsDTO, NextRunsParamsDTO, ScheduledRunDTO},
+        slug_dtos::{
+            SlugAvailabilityDTO, SlugCheckParamsDTO, SlugResolutionDTO,
+            SlugResolveParamsDTO,
+        },
         tag_dto::{CreateTagDTO, DeleteTagIdsDTO, TagDTO},
-        user_dtos::{CreateUserDTO, DeleteUserIdsDTO, UserDTO},
+        two_factor_dtos::{RecoveryCodesDTO, TotpCodeDTO, TotpEnrollmentDTO},
+        user_dtos::{
+            CreateUserDTO, DeleteUserIdsDTO, UpdateProfileDTO, UpdateUserDTO,
+            UserDTO, UserFiltersDTO,
+        },
+        users_roles_dto::{
+            CreateUsersRolesDTO, DeleteUsersRolesIdsDTO, UsersRolesDTO,
+        },
     },
+    handlers::line_diff_handler::{DiffLine, DiffOperation},
     middlewares::error_middleware::Error,
-    models::posts_model::PostsStatus,
+    models::{
+        categories_model::CategorySortField,
+        permissions_model::PermissionSortField,
+        post_blocks_model::{PostBlock, PostBlocks},
+        posts_model::{ContentFormat, PostSortField, PostsStatus},
+        redirects_model::RedirectSortField,
+        roles_model::RoleSortField,
+        slugs_model::SlugType,
+        tags_model::TagSortField,
+        users_models::UserSortField,
+    },
 };
 
 /// Main structure to generate OpenAPI documentation
@@ -25,10 +77,30 @@ use crate::{
 #[openapi(
     components(
         schemas(Error, DeleteCategoryIdsDTO, CategoryDTO, CreateCategoryDTO,
-        TagDTO, PostDTO, CreateTagDTO, DeleteTagIdsDTO, CreatePostDTO, DeletePostIdsDTO,
-        DeleteUserIdsDTO, CreateUserDTO, UserDTO, PaginationParamsDTO, LoginRequestDTO,
+        TagDTO, PostDTO, CreateTagDTO, DeleteTagIdsDTO, CreatePostDTO, DeletePostIdsDTO, SchedulePostDTO,
+        DeleteUserIdsDTO, CreateUserDTO, UpdateUserDTO, UserDTO,
+        UpdateProfileDTO, ChangePasswordRequestDTO, PaginationParamsDTO, LoginRequestDTO,
         PostsCategoriesDTO, CreatePostsCategoriesDTO,
-        TokenDTO, ClaimsDTO, PostsStatus
+        TokenDTO, ClaimsDTO, RefreshTokenRequestDTO, PostsStatus, ContentFormat, PostBlocks, PostBlock,
+        ForgotPasswordRequestDTO, ResetPasswordRequestDTO, VerifyEmailRequestDTO,
+        TwoFactorChallengeDTO, TwoFactorLoginRequestDTO,
+        TotpEnrollmentDTO, TotpCodeDTO, RecoveryCodesDTO,
+        ApiKeyDTO, CreateApiKeyDTO, CreatedApiKeyDTO,
+        NextRunsDTO, NextRunsParamsDTO, ScheduledRunDTO,
+        SlugCheckParamsDTO, SlugAvailabilityDTO, SlugType,
+        SlugResolveParamsDTO, SlugResolutionDTO,
+        RedirectDTO, CreateRedirectDTO, DeleteRedirectIdsDTO, FollowRedirectParamsDTO,
+        PostDraftDTO, SavePostDraftDTO,
+        PostRevisionSummaryDTO, PostRevisionDTO, PostRevisionDiffDTO, DiffLine, DiffOperation,
+        TrashedPostIdsDTO,
+        PostFiltersDTO, CategoryFiltersDTO, UserFiltersDTO,
+        PostSortField, CategorySortField, TagSortField, UserSortField,
+        RoleSortField, PermissionSortField, RedirectSortField,
+        PublicPostDTO, PublicPostsParamsDTO,
+        RoleDTO, CreateRoleDTO, DeleteRoleIdsDTO,
+        PermissionDTO, CreatePermissionDTO, DeletePermissionIdsDTO,
+        UsersRolesDTO, CreateUsersRolesDTO, DeleteUsersRolesIdsDTO,
+        RolesPermissionsDTO, CreateRolesPermissionsDTO, DeleteRolesPermissionsIdsDTO
         )
     ),
     modifiers(&SecurityAddon),
@@ -43,19 +115,78 @@ use crate::{
         crate::controllers::tags::delete_tag_controller::delete_tag_controller,
         crate::controllers::tags::get_tag_by_id_controller::get_tag_by_id_controller,
         crate::controllers::tags::get_all_tags_controller::get_all_tags_controller,
+        crate::controllers::tags::get_tag_posts_controller::get_tag_posts_controller,
         crate::controllers::posts::create_post_controller::create_post_controller,
         crate::controllers::posts::get_all_posts_controller::get_all_posts_controller,
         crate::controllers::posts::create_post_controller::create_post_controller,
         crate::controllers::posts::delete_post_controller::delete_post_controller,
         crate::controllers::posts::update_post_controller::update_post_controller,
         crate::controllers::posts::get_post_by_id_controller::get_post_by_id_controller,
+        crate::controllers::posts::submit_post_controller::submit_post_controller,
+        crate::controllers::posts::publish_post_controller::publish_post_controller,
+        crate::controllers::posts::schedule_post_controller::schedule_post_controller,
+        crate::controllers::posts::unpublish_post_controller::unpublish_post_controller,
+        crate::controllers::post_drafts::get_post_draft_controller::get_post_draft_controller,
+        crate::controllers::post_drafts::save_post_draft_controller::save_post_draft_controller,
+        crate::controllers::post_drafts::delete_post_draft_controller::delete_post_draft_controller,
+        crate::controllers::post_drafts::publish_post_draft_controller::publish_post_draft_controller,
+        crate::controllers::post_revisions::get_post_revisions_controller::get_post_revisions_controller,
+        crate::controllers::post_revisions::get_post_revision_controller::get_post_revision_controller,
+        crate::controllers::post_revisions::get_post_revision_diff_controller::get_post_revision_diff_controller,
+        crate::controllers::post_revisions::restore_post_revision_controller::restore_post_revision_controller,
+        crate::controllers::post_trash::get_trashed_posts_controller::get_trashed_posts_controller,
+        crate::controllers::post_trash::restore_trashed_posts_controller::restore_trashed_posts_controller,
+        crate::controllers::post_trash::purge_trashed_posts_controller::purge_trashed_posts_controller,
+        crate::controllers::public_posts::get_public_posts_controller::get_public_posts_controller,
+        crate::controllers::public_posts::get_public_post_by_id_controller::get_public_post_by_id_controller,
+        crate::controllers::public_posts::get_public_post_by_slug_controller::get_public_post_by_slug_controller,
+        crate::controllers::scheduler::get_next_runs_controller::get_next_runs_controller,
+        crate::controllers::slugs::check_slug_controller::check_slug_controller,
+        crate::controllers::slugs::resolve_slug_controller::resolve_slug_controller,
+        crate::controllers::redirects::create_redirect_controller::create_redirect_controller,
+        crate::controllers::redirects::get_all_redirects_controller::get_all_redirects_controller,
+        crate::controllers::redirects::get_redirect_by_id_controller::get_redirect_by_id_controller,
+        crate::controllers::redirects::update_redirect_controller::update_redirect_controller,
+        crate::controllers::redirects::delete_redirect_controller::delete_redirect_controller,
+        crate::controllers::redirects::follow_redirect_controller::follow_redirect_controller,
         crate::controllers::users::get_user_by_id_controller::get_user_by_id_controller,
         crate::controllers::users::get_all_users_controller::get_all_users_controller,
         crate::controllers::users::create_user_controller::create_user_controller,
         crate::controllers::users::update_user_controller::update_user_controller,
         crate::controllers::users::delete_user_controller::delete_user_controller,
+        crate::controllers::users::unlock_user_controller::unlock_user_controller,
+        crate::controllers::profile::get_profile_controller::get_profile_controller,
+        crate::controllers::profile::update_profile_controller::update_profile_controller,
+        crate::controllers::profile::change_password_controller::change_password_controller,
         crate::controllers::auth::login_controller::login_controller,
+        crate::controllers::auth::login_two_factor_controller::login_two_factor_controller,
+        crate::controllers::auth::refresh_controller::refresh_controller,
+        crate::controllers::auth::logout_controller::logout_controller,
+        crate::controllers::auth::logout_all_controller::logout_all_controller,
+        crate::controllers::auth::forgot_password_controller::forgot_password_controller,
+        crate::controllers::auth::reset_password_controller::reset_password_controller,
+        crate::controllers::auth::verify_email_controller::verify_email_controller,
+        crate::controllers::two_factor::enroll_totp_controller::enroll_totp_controller,
+        crate::controllers::two_factor::confirm_totp_controller::confirm_totp_controller,
+        crate::controllers::two_factor::disable_totp_controller::disable_totp_controller,
+        crate::controllers::api_keys::create_api_key_controller::create_api_key_controller,
+        crate::controllers::api_keys::get_api_keys_controller::get_api_keys_controller,
+        crate::controllers::api_keys::revoke_api_key_controller::revoke_api_key_controller,
         crate::controllers::posts_categories::create_posts_categories_controller::create_posts_categories_controller,
+        crate::controllers::roles::create_role_controller::create_role_controller,
+        crate::controllers::roles::get_all_roles_controller::get_all_roles_controller,
+        crate::controllers::roles::get_role_by_id_controller::get_role_by_id_controller,
+        crate::controllers::roles::update_role_controller::update_role_controller,
+        crate::controllers::roles::delete_role_controller::delete_role_controller,
+        crate::controllers::permissions::create_permission_controller::create_permission_controller,
+        crate::controllers::permissions::get_all_permissions_controller::get_all_permissions_controller,
+        crate::controllers::permissions::get_permission_by_id_controller::get_permission_by_id_controller,
+        crate::controllers::permissions::update_permission_controller::update_permission_controller,
+        crate::controllers::permissions::delete_permission_controller::delete_permission_controller,
+        crate::controllers::users_roles::create_users_roles_controller::create_users_roles_controller,
+        crate::controllers::users_roles::delete_users_roles_controller::delete_users_roles_controller,
+        crate::controllers::roles_permissions::create_roles_permissions_controller::create_roles_permissions_controller,
+        crate::controllers::roles_permissions::delete_roles_permissions_controller::delete_roles_permissions_controller,
     ),
     servers(
         (url = "/api/v1", description = "API v1")
@@ -0,0 +1,259 @@
+use ammonia::Builder;
+use pulldown_cmark::{html, Options, Parser};
+
+use crate::models::{
+    post_blocks_model::{PostBlock, PostBlocks},
+    posts_model::ContentFormat,
+};
+
+/// Renders the content of a post to HTML that is safe to embed in a page.
+///
+/// Markdown and HTML are cleaned against an allowlist of tags and
+/// attributes: scripts, styles, event handlers and `javascript:` URLs are
+/// dropped. Plain text is escaped, blank lines separating paragraphs.
+/// Blocks are rendered by `render_blocks_html`, nothing if they are invalid.
+pub fn render_content(format: &ContentFormat, content: &str) -> String {
+    match format {
+        ContentFormat::Markdown => {
+            let options = Options::ENABLE_TABLES
+                | Options::ENABLE_STRIKETHROUGH
+                | Options::ENABLE_FOOTNOTES;
+            let mut rendered = String::with_capacity(content.len());
+            html::push_html(&mut rendered, Parser::new_ext(content, options));
+
+            sanitize_html(&rendered)
+        }
+        ContentFormat::Html => sanitize_html(content),
+        ContentFormat::Plain => content
+            .replace("\r\n", "\n")
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|paragraph| !paragraph.is_empty())
+            .map(|paragraph| {
+                format!(
+                    "<p>{}</p>",
+                    escape_html(paragraph).replace('\n', "<br>")
+                )
+            })
+            .collect(),
+        ContentFormat::Blocks => PostBlocks::parse(content)
+            .map(|blocks| render_blocks_html(&blocks))
+            .unwrap_or_default(),
+    }
+}
+
+/// Renders blocks to HTML, one element per block. Their text is escaped and
+/// only `http` and `https` URLs are linked; embeds are rendered as links for
+/// the frontend to replace.
+pub fn render_blocks_html(blocks: &PostBlocks) -> String {
+    blocks
+        .blocks
+        .iter()
+        .map(|block| match block {
+            PostBlock::Paragraph { text } => {
+                format!("<p>{}</p>", escape_html(text))
+            }
+            PostBlock::Heading { level, text } => {
+                let level = level.clamp(&1, &6);
+                format!("<h{}>{}</h{}>", level, escape_html(text), level)
+            }
+            PostBlock::Image { url, alt, caption } => format!(
+                "<figure><img src=\"{}\" alt=\"{}\">{}</figure>",
+                escape_html(safe_url(url)),
+                escape_html(alt),
+                render_caption(caption)
+            ),
+            PostBlock::Quote { text, citation } => format!(
+                "<blockquote><p>{}</p>{}</blockquote>",
+                escape_html(text),
+                citation
+                    .as_ref()
+                    .map(|citation| {
+                        format!("<cite>{}</cite>", escape_html(citation))
+                    })
+                    .unwrap_or_default()
+            ),
+            PostBlock::Code { code, language } => format!(
+                "<pre><code{}>{}</code></pre>",
+                language
+                    .as_ref()
+                    .map(|language| {
+                        format!(" class=\"language-{}\"", escape_html(language))
+                    })
+                    .unwrap_or_default(),
+                escape_html(code)
+            ),
+            PostBlock::Embed { url, caption } => format!(
+                "<figure class=\"embed\"><a href=\"{}\" rel=\"noopener \
+                 noreferrer nofollow\">{}</a>{}</figure>",
+                escape_html(safe_url(url)),
+                escape_html(url),
+                render_caption(caption)
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders blocks to plain text, for excerpts, feeds or search, separating
+/// blocks with blank lines.
+pub fn render_blocks_text(blocks: &PostBlocks) -> String {
+    blocks
+        .blocks
+        .iter()
+        .filter_map(|block| match block {
+            PostBlock::Paragraph { text } | PostBlock::Heading { text, .. } => {
+                Some(text.clone())
+            }
+            PostBlock::Image { alt, caption, .. } => caption
+                .clone()
+                .or_else(|| (!alt.trim().is_empty()).then(|| alt.clone())),
+            PostBlock::Quote { text, citation } => Some(match citation {
+                Some(citation) => format!("{}\n— {}", text, citation),
+                None => text.clone(),
+            }),
+            PostBlock::Code { code, .. } => Some(code.clone()),
+            PostBlock::Embed { url, caption } => {
+                Some(caption.clone().unwrap_or_else(|| url.clone()))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn render_caption(caption: &Option<String>) -> String {
+    caption
+        .as_ref()
+        .map(|caption| {
+            format!("<figcaption>{}</figcaption>", escape_html(caption))
+        })
+        .unwrap_or_default()
+}
+
+/// The URL if it is an `http` or `https` one, validated on save, otherwise
+/// an empty link.
+fn safe_url(url: &str) -> &str {
+    if url.starts_with("https://") || url.starts_with("http://") {
+        url
+    } else {
+        ""
+    }
+}
+
+fn sanitize_html(html: &str) -> String {
+    Builder::default()
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .clean(html)
+        .to_string()
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_content_markdown() {
+        // Arrange
+        let content =
+            "# Title\n\nSome *emphasis* and a [link](https://example.com).";
+
+        // Act
+        let rendered = render_content(&ContentFormat::Markdown, content);
+
+        // Assert
+        assert_eq!(
+            rendered,
+            "<h1>Title</h1>\n<p>Some <em>emphasis</em> and a <a \
+             href=\"https://example.com\" rel=\"noopener noreferrer \
+             nofollow\">link</a>.</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_content_strips_scripts_and_handlers() {
+        // Arrange
+        let content = "<p onclick=\"steal()\">Hi</p><script>steal()</script>\
+                       <a href=\"javascript:steal()\">link</a>";
+
+        // Act
+        let html = render_content(&ContentFormat::Html, content);
+        let markdown = render_content(&ContentFormat::Markdown, content);
+
+        // Assert
+        for rendered in [html, markdown] {
+            assert!(!rendered.contains("script"));
+            assert!(!rendered.contains("onclick"));
+            assert!(!rendered.contains("javascript:"));
+            assert!(rendered.contains("Hi"));
+        }
+    }
+
+    #[test]
+    fn test_render_blocks() {
+        // Arrange
+        let blocks = PostBlocks {
+            version: 1,
+            blocks: vec![
+                PostBlock::Heading {
+                    level: 2,
+                    text: String::from("Fish & chips"),
+                },
+                PostBlock::Image {
+                    url: String::from("https://example.com/a.png"),
+                    alt: String::from("A <plate>"),
+                    caption: None,
+                },
+                PostBlock::Code {
+                    code: String::from("<script>"),
+                    language: Some(String::from("html")),
+                },
+            ],
+        };
+
+        // Act
+        let html = render_blocks_html(&blocks);
+        let text = render_blocks_text(&blocks);
+
+        // Assert
+        assert_eq!(
+            html,
+            "<h2>Fish &amp; chips</h2>\n\
+             <figure><img src=\"https://example.com/a.png\" \
+             alt=\"A &lt;plate&gt;\"></figure>\n\
+             <pre><code class=\"language-html\">&lt;script&gt;</code></pre>"
+        );
+        assert_eq!(text, "Fish & chips\n\nA <plate>\n\n<script>");
+    }
+
+    #[test]
+    fn test_render_content_plain() {
+        // Arrange
+        let content = "1 < 2 & 3\nsame paragraph\n\n<b>not bold</b>";
+
+        // Act
+        let rendered = render_content(&ContentFormat::Plain, content);
+
+        // Assert
+        assert_eq!(
+            rendered,
+            "<p>1 &lt; 2 &amp; 3<br>same paragraph</p>\
+             <p>&lt;b&gt;not bold&lt;/b&gt;</p>"
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::{Email, Mailer};
+
+/// Writes each email to a text file instead of sending it.
+pub struct FileMailer {
+    dir: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        FileMailer { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, email: Email) -> Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+
+        let file_name = format!(
+            "{}-{}.txt",
+            chrono::Utc::now().format("%Y%m%d%H%M%S%f"),
+            email.to.replace(|c: char| !c.is_alphanumeric(), "_")
+        );
+        let content = format!(
+            "To: {}\nSubject: {}\n\n{}\n",
+            email.to, email.subject, email.body
+        );
+
+        tokio::fs::write(self.dir.join(file_name), content).await?;
+
+        Ok(())
+    }
+}
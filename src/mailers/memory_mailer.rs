@@ -0,0 +1,34 @@
+use std::sync::Mutex;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::{Email, Mailer};
+
+/// Keeps the sent emails in memory so that tests can inspect them.
+#[derive(Default)]
+pub struct MemoryMailer {
+    sent: Mutex<Vec<Email>>,
+}
+
+impl MemoryMailer {
+    /// The emails sent to the given address, oldest first.
+    pub fn sent_to(&self, to: &str) -> Vec<Email> {
+        self.sent
+            .lock()
+            .expect("Mailer lock poisoned")
+            .iter()
+            .filter(|email| email.to == to)
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Mailer for MemoryMailer {
+    async fn send(&self, email: Email) -> Result<()> {
+        self.sent.lock().expect("Mailer lock poisoned").push(email);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::warn;
+
+use crate::config::config::{
+    get_mail_dir, get_mail_from, get_smtp_credentials, get_smtp_host,
+    get_smtp_port, get_smtp_tls,
+};
+
+use self::{file_mailer::FileMailer, smtp_mailer::SmtpMailer};
+
+pub mod file_mailer;
+#[cfg(test)]
+pub mod memory_mailer;
+pub mod smtp_mailer;
+
+/// A plain text email.
+#[derive(Debug, Clone)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Delivers the emails sent by the application, such as password reset
+/// links. Registered as application state as an `Arc<dyn Mailer>`.
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: Email) -> Result<()>;
+}
+
+/// Builds the mailer configured by the environment.
+///
+/// Emails go through SMTP when `SMTP_HOST` is set, otherwise they are written
+/// to `MAIL_DIR` so that the links can be followed during development.
+pub fn mailer_from_env() -> Result<Arc<dyn Mailer>> {
+    match get_smtp_host() {
+        Some(host) => Ok(Arc::new(SmtpMailer::new(
+            &host,
+            get_smtp_port(),
+            &get_smtp_tls(),
+            get_smtp_credentials(),
+            &get_mail_from(),
+        )?)),
+        None => {
+            let dir = get_mail_dir();
+            warn!("SMTP_HOST is not set, emails are written to {}", dir);
+            Ok(Arc::new(FileMailer::new(dir)))
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use super::{Email, Mailer};
+
+/// Sends emails through an SMTP relay.
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    /// Configures the relay; no connection is made until an email is sent.
+    ///
+    /// `tls` is `starttls`, `tls` (implicit TLS) or `none`, the latter being
+    /// meant for local test servers only.
+    pub fn new(
+        host: &str,
+        port: u16,
+        tls: &str,
+        credentials: Option<(String, String)>,
+        from: &str,
+    ) -> Result<Self> {
+        let mut builder = match tls {
+            "starttls" => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?
+            }
+            "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host)?,
+            "none" => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+            }
+            _ => return Err(anyhow!("SMTP_TLS must be starttls, tls or none")),
+        }
+        .port(port);
+
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(SmtpMailer {
+            transport: builder.build(),
+            from: from
+                .parse()
+                .map_err(|e| anyhow!("Invalid MAIL_FROM address: {}", e))?,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: Email) -> Result<()> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(email
+                .to
+                .parse()
+                .map_err(|e| anyhow!("Invalid recipient address: {}", e))?)
+            .subject(email.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(email.body)?;
+
+        self.transport.send(message).await?;
+
+        Ok(())
+    }
+}
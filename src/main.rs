@@ -8,7 +8,9 @@ mod config;
 mod controllers;
 mod db;
 mod dtos;
+mod extractors;
 mod handlers;
+mod mailers;
 mod middlewares;
 mod models;
 mod repositories;
@@ -16,6 +18,7 @@ mod routes;
 mod services;
 mod tests;
 mod validators;
+mod workers;
 
 #[ntex::main]
 async fn main() -> std::io::Result<()> {
@@ -29,6 +32,23 @@ async fn main() -> std::io::Result<()> {
     let api_url = config::config::get_api_url();
     let api_port = config::config::get_api_port();
 
+    // Fail fast on missing or invalid signing keys
+    config::jwt_keys::jwt_keys();
+
+    let mailer =
+        mailers::mailer_from_env().expect("Failed to configure the mailer");
+
+    let rendered =
+        services::posts_services::render_missing_content_html_service(&pool)
+            .await
+            .expect("Failed to render the content of posts");
+    if rendered > 0 {
+        log::info!("Rendered the content of {} posts", rendered);
+    }
+
+    workers::publish_scheduler::start_publish_scheduler(pool.clone());
+    workers::trash_purger::start_trash_purger(pool.clone());
+
     HttpServer::new(move || {
         App::new()
             .wrap(Error {
@@ -38,16 +58,21 @@ async fn main() -> std::io::Result<()> {
             .wrap(
                 Cors::new()
                     .allowed_origin(&cors_allowed_url)
-                    .allowed_methods(vec!["GET", "POST", "PUT", "DELETE"])
+                    .allowed_methods(vec![
+                        "GET", "POST", "PUT", "PATCH", "DELETE",
+                    ])
                     .allowed_headers(vec![
                         http::header::AUTHORIZATION,
                         http::header::ACCEPT,
+                        http::header::IF_MATCH,
                     ])
                     .allowed_header(http::header::CONTENT_TYPE)
+                    .expose_headers(vec![http::header::ETAG])
                     .max_age(3600)
                     .finish(),
             )
             .state(pool.clone())
+            .state(mailer.clone())
             .configure(handlers::openapi_handler::ntex_config)
             .configure(routes::init)
     })
@@ -1,33 +1,439 @@
-use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
-use ntex::http;
-use ntex::web::guard::Guard;
+use jsonwebtoken::errors::ErrorKind;
+use ntex::http::{self, header};
+use ntex::service::{Middleware, Service, ServiceCtx};
+use ntex::web::{self, HttpResponse};
+use sqlx::PgPool;
 
-use crate::config::config::get_secret_key;
+use crate::config::jwt_keys::jwt_keys;
 use crate::dtos::auth_dtos::ClaimsDTO;
+use crate::handlers::error_to_response_handler::convert_anyhow_to_ntex;
+use crate::middlewares::error_middleware::Error;
+use crate::repositories::users_repository::select_user_by_id;
+use crate::services::api_keys_service::{
+    authenticate_api_key_service, ApiKeyAuthentication, API_KEY_PREFIX,
+};
 
-pub struct JwtGuard;
+/// Reasons a bearer token can be rejected.
+#[derive(Debug)]
+pub enum TokenError {
+    Missing,
+    Expired,
+    Invalid,
+}
 
-impl Guard for JwtGuard {
-    fn check(&self, req: &http::RequestHead) -> bool {
-        if let Some(auth_header) =
-            req.headers().get(http::header::AUTHORIZATION)
-        {
-            if let Ok(auth_str) = auth_header.to_str() {
-                if auth_str.starts_with("Bearer ") {
-                    let token = &auth_str[7..];
-
-                    let decoding_key =
-                        DecodingKey::from_secret(get_secret_key().as_ref());
-                    let validation = Validation::new(Algorithm::HS256);
-
-                    if decode::<ClaimsDTO>(token, &decoding_key, &validation)
-                        .is_ok()
-                    {
-                        return true;
-                    }
-                }
+impl TokenError {
+    fn message(&self) -> &'static str {
+        match self {
+            TokenError::Missing => "Missing bearer token",
+            TokenError::Expired => "Token has expired",
+            TokenError::Invalid => "Invalid token",
+        }
+    }
+
+    /// Value of the `WWW-Authenticate` header, as described by RFC 6750.
+    fn challenge(&self) -> String {
+        match self {
+            TokenError::Missing => String::from("Bearer realm=\"api\""),
+            _ => format!(
+                "Bearer realm=\"api\", error=\"invalid_token\", error_description=\"{}\"",
+                self.message()
+            ),
+        }
+    }
+}
+
+/// Stored in the request extensions when the request was authenticated with
+/// an API key rather than a JWT.
+#[derive(Debug, Clone, Copy)]
+pub struct ApiKeyId(pub i32);
+
+/// Returns the bearer token of a request.
+fn bearer_token(req: &http::RequestHead) -> Result<&str, TokenError> {
+    let auth_header = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .ok_or(TokenError::Missing)?;
+    let auth_str = auth_header.to_str().map_err(|_| TokenError::Invalid)?;
+
+    auth_str.strip_prefix("Bearer ").ok_or(TokenError::Invalid)
+}
+
+/// Decodes the bearer token of a request, returning its claims if the token is valid.
+pub fn decode_bearer_claims(
+    req: &http::RequestHead,
+) -> Result<ClaimsDTO, TokenError> {
+    let token = bearer_token(req)?;
+
+    jwt_keys()
+        .decode::<ClaimsDTO>(token)
+        .map_err(|e| match e.kind() {
+            ErrorKind::ExpiredSignature => TokenError::Expired,
+            _ => TokenError::Invalid,
+        })
+}
+
+/// Builds the 401 response sent when a token is rejected.
+pub fn unauthorized_response(error: &TokenError) -> HttpResponse {
+    HttpResponse::Unauthorized()
+        .header(header::WWW_AUTHENTICATE, error.challenge())
+        .json(&Error {
+            message: error.message().to_string(),
+            backtrace: None,
+        })
+}
+
+/// Builds the 403 response sent when a valid token lacks a permission.
+pub fn forbidden_response(message: &str) -> HttpResponse {
+    HttpResponse::Forbidden()
+        .header(
+            header::WWW_AUTHENTICATE,
+            "Bearer realm=\"api\", error=\"insufficient_scope\"",
+        )
+        .json(&Error {
+            message: message.to_string(),
+            backtrace: None,
+        })
+}
+
+/// Middleware rejecting requests without a valid bearer token, either a JWT
+/// or an API key, and requests of users that were deactivated or deleted
+/// since the token was issued.
+///
+/// The decoded claims are stored in the request extensions, where the
+/// `Authorized` and `CurrentUser` extractors pick them up.
+pub struct JwtAuth;
+
+impl<S> Middleware<S> for JwtAuth {
+    type Service = JwtAuthMiddleware<S>;
+
+    fn create(&self, service: S) -> Self::Service {
+        JwtAuthMiddleware { service }
+    }
+}
+
+pub struct JwtAuthMiddleware<S> {
+    service: S,
+}
+
+impl<S, Err> Service<web::WebRequest<Err>> for JwtAuthMiddleware<S>
+where
+    S: Service<
+        web::WebRequest<Err>,
+        Response = web::WebResponse,
+        Error = web::Error,
+    >,
+    Err: web::ErrorRenderer,
+{
+    type Response = web::WebResponse;
+    type Error = web::Error;
+
+    ntex::forward_ready!(service);
+
+    async fn call(
+        &self,
+        req: web::WebRequest<Err>,
+        ctx: ServiceCtx<'_, Self>,
+    ) -> Result<Self::Response, Self::Error> {
+        let is_api_key = bearer_token(req.head())
+            .is_ok_and(|token| token.starts_with(API_KEY_PREFIX));
+        if is_api_key {
+            return self.call_with_api_key(req, ctx).await;
+        }
+
+        match decode_bearer_claims(req.head()) {
+            Ok(claims) => self.call_with_jwt(req, ctx, claims).await,
+            Err(error) => Ok(req.into_response(unauthorized_response(&error))),
+        }
+    }
+}
+
+impl<S> JwtAuthMiddleware<S> {
+    /// Lets a request with a valid JWT through if its user is still active.
+    /// The user is checked against the database, so deactivating an account
+    /// takes effect immediately rather than when its tokens expire.
+    async fn call_with_jwt<Err>(
+        &self,
+        req: web::WebRequest<Err>,
+        ctx: ServiceCtx<'_, JwtAuthMiddleware<S>>,
+        claims: ClaimsDTO,
+    ) -> Result<web::WebResponse, web::Error>
+    where
+        S: Service<
+            web::WebRequest<Err>,
+            Response = web::WebResponse,
+            Error = web::Error,
+        >,
+        Err: web::ErrorRenderer,
+    {
+        let pool = req
+            .app_state::<PgPool>()
+            .expect("PgPool must be registered as application state")
+            .clone();
+        let user = match claims.sub.parse::<i32>() {
+            Ok(id) => select_user_by_id(&pool, id).await,
+            Err(_) => Err(sqlx::Error::RowNotFound.into()),
+        };
+
+        match user {
+            Ok(user) if user.active => {
+                req.extensions_mut().insert(claims);
+                ctx.call(&self.service, req).await
+            }
+            Ok(_) => Ok(req
+                .into_response(forbidden_response("User account is inactive"))),
+            Err(e) => match e.downcast_ref::<sqlx::Error>() {
+                Some(sqlx::Error::RowNotFound) => Ok(req.into_response(
+                    unauthorized_response(&TokenError::Invalid),
+                )),
+                _ => Err(convert_anyhow_to_ntex(e)),
+            },
+        }
+    }
+
+    /// Authenticates the request with the API key of its bearer token. The
+    /// key is checked against the database, so revoking it takes effect
+    /// immediately.
+    async fn call_with_api_key<Err>(
+        &self,
+        req: web::WebRequest<Err>,
+        ctx: ServiceCtx<'_, JwtAuthMiddleware<S>>,
+    ) -> Result<web::WebResponse, web::Error>
+    where
+        S: Service<
+            web::WebRequest<Err>,
+            Response = web::WebResponse,
+            Error = web::Error,
+        >,
+        Err: web::ErrorRenderer,
+    {
+        let pool = req
+            .app_state::<PgPool>()
+            .expect("PgPool must be registered as application state")
+            .clone();
+        let key = bearer_token(req.head()).unwrap_or_default().to_string();
+
+        match authenticate_api_key_service(&pool, &key).await {
+            Ok(ApiKeyAuthentication::Valid { api_key_id, claims }) => {
+                req.extensions_mut().insert(claims);
+                req.extensions_mut().insert(ApiKeyId(api_key_id));
+                ctx.call(&self.service, req).await
+            }
+            Ok(ApiKeyAuthentication::Expired) => {
+                Ok(req
+                    .into_response(unauthorized_response(&TokenError::Expired)))
             }
+            Ok(ApiKeyAuthentication::Invalid) => {
+                Ok(req
+                    .into_response(unauthorized_response(&TokenError::Invalid)))
+            }
+            Ok(ApiKeyAuthentication::InactiveUser) => Ok(req
+                .into_response(forbidden_response("User account is inactive"))),
+            Err(e) => Err(convert_anyhow_to_ntex(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ntex::web::{self, test, App, HttpResponse};
+
+    use super::*;
+    use crate::repositories::api_keys_repository::insert_api_key;
+    use crate::services::auth_service::{generate_random_hex, hash_token};
+    use crate::tests::helpers::setup::{
+        bearer_token_for, clean_data_test, insert_test_user, setup_test_db,
+    };
+
+    #[web::get("/protected")]
+    async fn protected_controller() -> HttpResponse {
+        HttpResponse::Ok().finish()
+    }
+
+    fn expired_token() -> String {
+        dotenv::from_filename(".env.test").ok();
+
+        let claims = ClaimsDTO {
+            sub: String::from("1"),
+            exp: chrono::Utc::now()
+                .checked_sub_signed(chrono::Duration::hours(1))
+                .expect("Failed to compute token expiration")
+                .timestamp() as usize,
+            roles: vec![],
+            permissions: vec![],
+        };
+
+        let token = jwt_keys()
+            .encode(&claims)
+            .expect("Failed to encode test token");
+
+        format!("Bearer {}", token)
+    }
+
+    async fn call_protected(authorization: Option<String>) -> web::WebResponse {
+        let pool = setup_test_db().await;
+        let app = test::init_service(App::new().state(pool).service(
+            web::scope("/").wrap(JwtAuth).service(protected_controller),
+        ))
+        .await;
+
+        let mut req = test::TestRequest::get().uri("/protected");
+        if let Some(authorization) = authorization {
+            req = req.header(header::AUTHORIZATION, authorization);
+        }
+
+        test::call_service(&app, req.to_request()).await
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_missing_token() {
+        // Act
+        let resp = call_protected(None).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            resp.headers().get(header::WWW_AUTHENTICATE).unwrap(),
+            "Bearer realm=\"api\""
+        );
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_expired_token() {
+        // Act
+        let resp = call_protected(Some(expired_token())).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+        let challenge = resp
+            .headers()
+            .get(header::WWW_AUTHENTICATE)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(challenge.contains("Token has expired"));
+        let body = test::read_body(resp).await;
+        let error: serde_json::Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(error["message"], "Token has expired");
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_malformed_token() {
+        // Act
+        let resp = call_protected(Some(String::from("Bearer not.a.jwt"))).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+        let body = test::read_body(resp).await;
+        let error: serde_json::Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(error["message"], "Invalid token");
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_success() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id =
+            insert_test_user(&pool, "jwt-auth@test.com", "password").await;
+
+        // Act
+        let resp = call_protected(Some(bearer_token_for(user_id, &[]))).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "jwt-auth@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_inactive_user() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id =
+            insert_test_user(&pool, "jwt-auth-inactive@test.com", "password")
+                .await;
+        sqlx::query("UPDATE users SET active = FALSE WHERE id = $1")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to deactivate test user");
+
+        // Act
+        let resp = call_protected(Some(bearer_token_for(user_id, &[]))).await;
+
+        // Assert
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+        let body = test::read_body(resp).await;
+        let error: serde_json::Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(error["message"], "User account is inactive");
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "jwt-auth-inactive@test.com")
+            .await
+            .expect("Failed to clean up test data");
+    }
+
+    #[ntex::test]
+    async fn test_jwt_auth_api_key() {
+        // Arrange
+        let pool = setup_test_db().await;
+        let user_id =
+            insert_test_user(&pool, "api-key-auth@test.com", "password").await;
+        let app = test::init_service(App::new().state(pool.clone()).service(
+            web::scope("/").wrap(JwtAuth).service(protected_controller),
+        ))
+        .await;
+        let mut keys = vec![];
+        for expires_in in
+            [chrono::Duration::hours(1), -chrono::Duration::hours(1)]
+        {
+            let prefix = generate_random_hex(6);
+            let secret = generate_random_hex(32);
+            insert_api_key(
+                &pool,
+                user_id,
+                "test",
+                &prefix,
+                &hash_token(&secret),
+                &[],
+                Some(chrono::Utc::now().naive_utc() + expires_in),
+            )
+            .await
+            .expect("Failed to insert test API key");
+            keys.push((prefix, secret));
         }
-        false
+        let call = |key: String| {
+            let req = test::TestRequest::get()
+                .uri("/protected")
+                .header(header::AUTHORIZATION, format!("Bearer {}", key))
+                .to_request();
+            test::call_service(&app, req)
+        };
+
+        // Act
+        let valid = call(format!("rcms_{}_{}", keys[0].0, keys[0].1)).await;
+        let expired = call(format!("rcms_{}_{}", keys[1].0, keys[1].1)).await;
+        let wrong_secret =
+            call(format!("rcms_{}_{}", keys[0].0, keys[1].1)).await;
+
+        // Assert
+        assert_eq!(valid.status(), http::StatusCode::OK);
+        assert_eq!(expired.status(), http::StatusCode::UNAUTHORIZED);
+        let body = test::read_body(expired).await;
+        let error: serde_json::Value =
+            serde_json::from_slice(&body).expect("Failed to parse JSON");
+        assert_eq!(error["message"], "Token has expired");
+        assert_eq!(wrong_secret.status(), http::StatusCode::UNAUTHORIZED);
+
+        // Clean up test data
+        clean_data_test(&pool, "users", "email", "api-key-auth@test.com")
+            .await
+            .expect("Failed to clean up test data");
     }
 }
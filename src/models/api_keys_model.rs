@@ -0,0 +1,16 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(FromRow)]
+pub struct ApiKeyModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub name: String,
+    pub prefix: String,
+    pub secret_hash: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<NaiveDateTime>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub date_created: NaiveDateTime,
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use validator::{Validate, ValidationError};
 
+use crate::models::sort_model::sort_fields;
 use crate::validators::slug_validator::validate_slug;
 
 #[derive(Validate, Serialize, Deserialize, FromRow, Clone)]
@@ -42,6 +43,26 @@ pub struct CategoryModel {
 
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
     pub date_created: Option<NaiveDateTime>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+}
+
+/// The conditions a list of categories is restricted to, each one optional.
+#[derive(Default)]
+pub struct CategoryFilters {
+    pub parent_id: Option<i32>,
+}
+
+sort_fields! {
+    /// The fields a list of categories can be sorted by.
+    pub enum CategorySortField {
+        Id("id") => "id",
+        ParentId("parent_id") => "parent_id",
+        Name("name") => "name",
+        Slug("slug") => "slug",
+        DateCreated("date_created") => "date_created",
+    }
 }
 
 fn validate_slug_category(slug: &str) -> Result<(), ValidationError> {
@@ -1,5 +1,18 @@
+pub mod api_keys_model;
 pub mod categories_model;
+pub mod permissions_model;
+pub mod post_blocks_model;
+pub mod post_drafts_model;
+pub mod post_revisions_model;
 pub mod posts_categories_model;
 pub mod posts_model;
+pub mod redirects_model;
+pub mod refresh_tokens_model;
+pub mod roles_model;
+pub mod roles_permissions_model;
+pub mod slugs_model;
+pub mod sort_model;
 pub mod tags_model;
+pub mod user_totp_model;
 pub mod users_models;
+pub mod users_roles_model;
@@ -0,0 +1,61 @@
+use chrono::NaiveDateTime;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::{Validate, ValidationError};
+
+use crate::models::sort_model::sort_fields;
+
+#[derive(Validate, Serialize, Deserialize, FromRow)]
+pub struct PermissionModel {
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "ID must be between 1 and 2,147,483,647"
+    ))]
+    pub id: Option<i32>,
+
+    #[validate(
+        length(
+            min = 1,
+            max = 100,
+            message = "Name must be between 1 and 100 characters"
+        ),
+        custom(function = "validate_permission_name")
+    )]
+    pub name: String,
+
+    #[validate(length(
+        max = 1000,
+        message = "Description maximum 1000 characters"
+    ))]
+    pub description: Option<String>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+sort_fields! {
+    /// The fields a list of permissions can be sorted by.
+    pub enum PermissionSortField {
+        Id("id") => "id",
+        Name("name") => "name",
+        DateCreated("date_created") => "date_created",
+    }
+}
+
+/// Permissions are named `resource:action`, e.g. `posts:publish`.
+fn validate_permission_name(name: &str) -> Result<(), ValidationError> {
+    let re = Regex::new(r"^[a-z_]+:[a-z_]+$").unwrap();
+    if !re.is_match(name) {
+        let mut error = ValidationError::new("invalid_format");
+        error.message = Some(
+            "Permission name must follow the 'resource:action' format".into(),
+        );
+        return Err(error);
+    }
+
+    Ok(())
+}
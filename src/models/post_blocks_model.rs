@@ -0,0 +1,312 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+/// The version of the block schema, bumped on incompatible changes.
+pub const POST_BLOCKS_VERSION: u16 = 1;
+
+const MAX_BLOCKS: usize = 1000;
+const MAX_TEXT_LENGTH: usize = 10_000;
+const MAX_SHORT_TEXT_LENGTH: usize = 500;
+
+/// The body of a post as typed blocks, stored as JSON in its `content` when
+/// the content format is `blocks`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PostBlocks {
+    /// The version of the block schema, currently 1
+    pub version: u16,
+    pub blocks: Vec<PostBlock>,
+}
+
+/// A block of a post, tagged with its `type`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum PostBlock {
+    Paragraph {
+        text: String,
+    },
+    Heading {
+        /// From 1 to 6
+        level: u8,
+        text: String,
+    },
+    Image {
+        /// An `http` or `https` URL
+        url: String,
+        alt: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+    Quote {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        citation: Option<String>,
+    },
+    Code {
+        code: String,
+        /// Lowercase letters, digits, `+`, `#` or `-`, e.g. `rust`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+    },
+    Embed {
+        /// An `https` URL of the embedded page
+        url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+}
+
+impl PostBlocks {
+    /// Reads and validates blocks stored in the `content` of a post.
+    pub fn parse(content: &str) -> Result<Self, ValidationErrors> {
+        let blocks: PostBlocks = serde_json::from_str(content)
+            .map_err(|e| content_errors(format!("Invalid blocks: {}", e)))?;
+
+        blocks.validate()?;
+        Ok(blocks)
+    }
+
+    /// The JSON stored in the `content` of a post.
+    pub fn to_content(&self) -> String {
+        serde_json::to_string(self).expect("Blocks are serializable")
+    }
+}
+
+impl Validate for PostBlocks {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        if self.version != POST_BLOCKS_VERSION {
+            return Err(content_errors(format!(
+                "Unsupported block schema version {}, expected {}",
+                self.version, POST_BLOCKS_VERSION
+            )));
+        }
+        if self.blocks.len() > MAX_BLOCKS {
+            return Err(content_errors(format!(
+                "A post cannot have more than {} blocks",
+                MAX_BLOCKS
+            )));
+        }
+
+        let mut errors = ValidationErrors::new();
+        for (index, block) in self.blocks.iter().enumerate() {
+            if let Err(message) = block.check() {
+                let mut error = ValidationError::new("invalid_block");
+                error.message =
+                    Some(format!("Block {}: {}", index + 1, message).into());
+                errors.add("content", error);
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+}
+
+impl PostBlock {
+    /// Checks the fields of the block against the rules of its type.
+    fn check(&self) -> Result<(), String> {
+        match self {
+            PostBlock::Paragraph { text } => {
+                check_text("text", text, MAX_TEXT_LENGTH)
+            }
+            PostBlock::Heading { level, text } => {
+                if !(1..=6).contains(level) {
+                    return Err(String::from(
+                        "Heading level must be between 1 and 6",
+                    ));
+                }
+                check_text("text", text, MAX_SHORT_TEXT_LENGTH)
+            }
+            PostBlock::Image { url, alt, caption } => {
+                check_url(url, &["http", "https"])?;
+                check_optional_text("alt", alt, MAX_SHORT_TEXT_LENGTH)?;
+                check_caption(caption)
+            }
+            PostBlock::Quote { text, citation } => {
+                check_text("text", text, MAX_TEXT_LENGTH)?;
+                match citation {
+                    Some(citation) => check_optional_text(
+                        "citation",
+                        citation,
+                        MAX_SHORT_TEXT_LENGTH,
+                    ),
+                    None => Ok(()),
+                }
+            }
+            PostBlock::Code { code, language } => {
+                check_text("code", code, MAX_TEXT_LENGTH)?;
+                match language {
+                    Some(language) if !is_valid_language(language) => {
+                        Err(String::from(
+                            "Language can only contain lowercase letters, \
+                             digits, '+', '#' and '-', up to 30 characters",
+                        ))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            PostBlock::Embed { url, caption } => {
+                check_url(url, &["https"])?;
+                check_caption(caption)
+            }
+        }
+    }
+}
+
+fn check_text(
+    field: &str,
+    text: &str,
+    max_length: usize,
+) -> Result<(), String> {
+    if text.trim().is_empty() {
+        return Err(format!("{} cannot be empty", capitalize(field)));
+    }
+
+    check_optional_text(field, text, max_length)
+}
+
+fn check_optional_text(
+    field: &str,
+    text: &str,
+    max_length: usize,
+) -> Result<(), String> {
+    if text.chars().count() > max_length {
+        return Err(format!(
+            "{} cannot exceed {} characters",
+            capitalize(field),
+            max_length
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_caption(caption: &Option<String>) -> Result<(), String> {
+    match caption {
+        Some(caption) => {
+            check_optional_text("caption", caption, MAX_SHORT_TEXT_LENGTH)
+        }
+        None => Ok(()),
+    }
+}
+
+fn check_url(url: &str, schemes: &[&str]) -> Result<(), String> {
+    let valid = url.len() <= 2000
+        && url.split_once("://").is_some_and(|(scheme, rest)| {
+            schemes.contains(&scheme)
+                && !rest.is_empty()
+                && !rest.starts_with('/')
+                && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+        });
+    if !valid {
+        return Err(format!(
+            "URL must be an absolute {} URL",
+            schemes.join(" or ")
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_valid_language(language: &str) -> bool {
+    !language.is_empty()
+        && language.len() <= 30
+        && language.chars().all(|c| {
+            c.is_ascii_lowercase()
+                || c.is_ascii_digit()
+                || matches!(c, '+' | '#' | '-')
+        })
+}
+
+fn capitalize(field: &str) -> String {
+    let mut chars = field.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn content_errors(message: String) -> ValidationErrors {
+    let mut error = ValidationError::new("invalid_blocks");
+    error.message = Some(message.into());
+
+    let mut errors = ValidationErrors::new();
+    errors.add("content", error);
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_post_blocks() {
+        // Arrange
+        let content = r#"{
+            "version": 1,
+            "blocks": [
+                {"type": "heading", "level": 2, "text": "Title"},
+                {"type": "code", "code": "fn main() {}", "language": "rust"}
+            ]
+        }"#;
+
+        // Act
+        let blocks = PostBlocks::parse(content).expect("Valid blocks");
+
+        // Assert
+        assert_eq!(
+            blocks.blocks,
+            vec![
+                PostBlock::Heading {
+                    level: 2,
+                    text: String::from("Title"),
+                },
+                PostBlock::Code {
+                    code: String::from("fn main() {}"),
+                    language: Some(String::from("rust")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_post_blocks_invalid() {
+        // Arrange
+        let unknown_type = r#"{"version": 1, "blocks": [{"type": "video"}]}"#;
+        let wrong_version = r#"{"version": 2, "blocks": []}"#;
+        let invalid_blocks = r#"{
+            "version": 1,
+            "blocks": [
+                {"type": "heading", "level": 7, "text": "Title"},
+                {"type": "paragraph", "text": "Fine"},
+                {"type": "image", "url": "javascript:alert(1)", "alt": ""},
+                {"type": "embed", "url": "http://example.com"}
+            ]
+        }"#;
+
+        // Act
+        let unknown_type = PostBlocks::parse(unknown_type);
+        let wrong_version = PostBlocks::parse(wrong_version);
+        let invalid_blocks =
+            PostBlocks::parse(invalid_blocks).expect_err("Invalid blocks");
+
+        // Assert
+        assert!(unknown_type.is_err());
+        assert!(wrong_version.is_err());
+        let messages: Vec<String> = invalid_blocks.field_errors()["content"]
+            .iter()
+            .map(|error| error.message.clone().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Block 1: Heading level must be between 1 and 6",
+                "Block 3: URL must be an absolute http or https URL",
+                "Block 4: URL must be an absolute https URL",
+            ]
+        );
+    }
+}
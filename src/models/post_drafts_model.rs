@@ -0,0 +1,47 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+use validator::{Validate, ValidationError};
+
+use crate::validators::slug_validator::validate_slug;
+
+/// The working copy of a post, saved without affecting the live post until
+/// it is published.
+#[derive(Validate, FromRow)]
+pub struct PostDraftModel {
+    pub post_id: i32,
+
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Title must be between 1 and 500 characters"
+    ))]
+    pub title: String,
+
+    #[validate(length(
+        max = 100_000,
+        message = "Content cannot exceed 100,000 characters"
+    ))]
+    pub content: String,
+
+    #[validate(custom(function = "validate_slug_draft"))]
+    pub slug: String,
+
+    /// Whether `slug` was generated from the title, and so is allocated
+    /// again when the draft is published
+    pub slug_generated: bool,
+
+    pub editor_id: Option<i32>,
+
+    /// The version of the post the draft was started from
+    pub base_version: i32,
+
+    #[sqlx(default)]
+    pub date_created: Option<NaiveDateTime>,
+
+    #[sqlx(default)]
+    pub date_updated: Option<NaiveDateTime>,
+}
+
+fn validate_slug_draft(slug: &str) -> Result<(), ValidationError> {
+    validate_slug(slug, 1, 200)
+}
@@ -0,0 +1,18 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+use super::posts_model::{ContentFormat, PostsStatus};
+
+/// An immutable snapshot of a post, taken each time it is saved.
+#[derive(FromRow)]
+pub struct PostRevisionModel {
+    pub post_id: i32,
+    pub revision_number: i32,
+    pub title: String,
+    pub content: String,
+    pub content_format: ContentFormat,
+    pub slug: String,
+    pub status: PostsStatus,
+    pub editor_id: Option<i32>,
+    pub date_created: NaiveDateTime,
+}
@@ -1,9 +1,12 @@
+use std::fmt;
+
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Type};
 use utoipa::ToSchema;
 use validator::{Validate, ValidationError};
 
+use crate::models::sort_model::sort_fields;
 use crate::validators::slug_validator::validate_slug;
 
 /// Represents a blog post with associated metadata and categories.
@@ -26,11 +29,17 @@ pub struct PostModel {
     pub title: String,
 
     #[validate(length(
-        max = 5000,
-        message = "Content cannot exceed 5000 characters"
+        max = 100_000,
+        message = "Content cannot exceed 100,000 characters"
     ))]
     pub content: String,
 
+    pub content_format: ContentFormat,
+
+    /// The sanitized HTML rendering of `content`, `None` until it is rendered
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub content_html: Option<String>,
+
     #[validate(custom(function = "validate_slug_post"))]
     pub slug: Option<String>,
 
@@ -42,7 +51,6 @@ pub struct PostModel {
     ))]
     pub author_id: i32,
 
-    #[validate(custom(function = "validate_post_status"))]
     pub status: PostsStatus,
 
     pub date_published: Option<NaiveDateTime>,
@@ -51,7 +59,80 @@ pub struct PostModel {
     pub date_created: Option<NaiveDateTime>,
 
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<i32>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub date_updated: Option<NaiveDateTime>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<NaiveDateTime>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
     pub categories: Option<serde_json::Value>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
+    pub tags: Option<serde_json::Value>,
+}
+
+/// The conditions a list of posts is restricted to, each one optional.
+#[derive(Default)]
+pub struct PostFilters {
+    pub status: Option<PostsStatus>,
+    pub author_id: Option<i32>,
+    pub category_id: Option<i32>,
+    /// The slug of a tag of the posts
+    pub tag: Option<String>,
+    pub date_published_gte: Option<NaiveDateTime>,
+    pub date_published_lte: Option<NaiveDateTime>,
+    /// Words searched for in the title and content
+    pub q: Option<String>,
+}
+
+sort_fields! {
+    /// The fields a list of posts can be sorted by.
+    pub enum PostSortField {
+        Id("id") => "p.id",
+        Title("title") => "p.title",
+        Slug("slug") => "p.slug",
+        AuthorId("author_id") => "p.author_id",
+        Status("status") => "p.status",
+        DatePublished("date_published") => "p.date_published",
+        DateCreated("date_created") => "p.date_created",
+        DateUpdated("date_updated") => "p.date_updated",
+    }
+}
+
+/// The title, content and slug a post takes back from its draft or one of
+/// its revisions.
+pub struct PostContent {
+    pub title: String,
+    pub content: String,
+    pub content_format: ContentFormat,
+    /// The sanitized HTML rendering of `content`
+    pub content_html: String,
+    pub slug: String,
+}
+
+/// A published post as served to anonymous readers, with the display name
+/// of its author instead of the account.
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct PublicPostModel {
+    pub id: i32,
+    pub title: String,
+    pub slug: Option<String>,
+    pub content: String,
+    pub content_format: ContentFormat,
+    pub content_html: Option<String>,
+    pub author_name: String,
+    pub date_published: Option<NaiveDateTime>,
+    pub date_updated: Option<NaiveDateTime>,
+    pub categories: serde_json::Value,
+    pub tags: serde_json::Value,
 }
 
 #[derive(Debug, Serialize, Deserialize, Type, Clone, PartialEq, ToSchema)]
@@ -64,20 +145,99 @@ pub enum PostsStatus {
     Published,
 }
 
-fn validate_post_status(status: &PostsStatus) -> Result<(), ValidationError> {
-    match status {
-        PostsStatus::Draft
-        | PostsStatus::Pending
-        | PostsStatus::Published
-        | PostsStatus::Private
-        | PostsStatus::Scheduled => Ok(()),
-        _ => Err(ValidationError::new("Invalid post status")),
+/// How the `content` of a post is written, and so how it is rendered to HTML.
+#[derive(
+    Debug, Default, Serialize, Deserialize, Type, Clone, PartialEq, ToSchema,
+)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "posts_content_format", rename_all = "lowercase")]
+pub enum ContentFormat {
+    Markdown,
+    #[default]
+    Html,
+    Plain,
+    /// Typed blocks, see `PostBlocks`
+    Blocks,
+}
+
+impl PostsStatus {
+    /// Statuses that expose a post to readers, now or at `date_published`.
+    pub fn is_published(&self) -> bool {
+        matches!(self, PostsStatus::Published | PostsStatus::Scheduled)
+    }
+
+    /// Statuses a post can be created with: it then goes through the
+    /// workflow like any draft.
+    pub fn is_initial(&self) -> bool {
+        matches!(self, PostsStatus::Draft | PostsStatus::Pending)
+    }
+
+    /// The editorial workflow: a draft is submitted for review, then
+    /// published or scheduled, so it never goes live without going through
+    /// `Pending`. Published posts can be made private or taken back to a
+    /// draft, and a private post is submitted again to go back live.
+    pub fn can_transition_to(&self, to: &PostsStatus) -> bool {
+        use PostsStatus::*;
+
+        matches!(
+            (self, to),
+            (Draft, Pending | Private)
+                | (Pending, Draft | Scheduled | Published)
+                | (Scheduled, Draft | Scheduled | Published)
+                | (Published, Draft | Private)
+                | (Private, Draft | Pending)
+        )
+    }
+}
+
+/// A status change that the workflow does not allow.
+#[derive(Debug)]
+pub struct IllegalTransitionError {
+    pub from: PostsStatus,
+    pub to: PostsStatus,
+}
+
+impl fmt::Display for IllegalTransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A post cannot go from {:?} to {:?}", self.from, self.to)
     }
 }
 
+impl std::error::Error for IllegalTransitionError {}
+
 fn validate_slug_post(slug: &str) -> Result<(), ValidationError> {
     let min_length = 1;
     let max_length = 500;
 
     validate_slug(&slug, min_length, max_length)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_live_statuses_require_review() {
+        // Arrange
+        use PostsStatus::*;
+        let all = [Draft, Pending, Private, Scheduled, Published];
+
+        // Act
+        let mut reachable = vec![Draft];
+        let mut index = 0;
+        while index < reachable.len() {
+            let from = reachable[index].clone();
+            for to in all.iter().filter(|to| from.can_transition_to(to)) {
+                if *to != Pending && !reachable.contains(to) {
+                    reachable.push(to.clone());
+                }
+            }
+            index += 1;
+        }
+
+        // Assert
+        assert!(!reachable.iter().any(PostsStatus::is_published));
+        assert!(Pending.can_transition_to(&Published));
+        assert!(Private.can_transition_to(&Pending));
+    }
+}
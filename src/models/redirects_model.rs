@@ -0,0 +1,55 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+use crate::models::sort_model::sort_fields;
+use crate::validators::redirect_validator::{
+    validate_redirect_source, validate_redirect_target,
+};
+
+#[derive(Validate, Serialize, Deserialize, FromRow)]
+pub struct RedirectModel {
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "ID must be between 1 and 2,147,483,647"
+    ))]
+    pub id: Option<i32>,
+
+    #[validate(custom(function = "validate_redirect_source"))]
+    pub source_path: String,
+
+    #[validate(custom(function = "validate_redirect_target"))]
+    pub target: String,
+
+    #[validate(range(
+        min = 301,
+        max = 302,
+        message = "Status code must be 301 or 302"
+    ))]
+    pub status_code: i32,
+
+    #[serde(skip_deserializing)]
+    pub hits: i64,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub last_hit_at: Option<NaiveDateTime>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+sort_fields! {
+    /// The fields a list of redirects can be sorted by.
+    pub enum RedirectSortField {
+        Id("id") => "id",
+        SourcePath("source_path") => "source_path",
+        StatusCode("status_code") => "status_code",
+        Hits("hits") => "hits",
+        LastHitAt("last_hit_at") => "last_hit_at",
+        DateCreated("date_created") => "date_created",
+    }
+}
@@ -0,0 +1,12 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(FromRow)]
+pub struct RefreshTokenModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub family_id: String,
+    pub replaced_by: Option<i32>,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+}
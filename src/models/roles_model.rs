@@ -0,0 +1,43 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use validator::Validate;
+
+use crate::models::sort_model::sort_fields;
+
+#[derive(Validate, Serialize, Deserialize, FromRow)]
+pub struct RoleModel {
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    // https://www.postgresql.org/docs/8.1/datatype.html#DATATYPE-NUMERIC
+    #[validate(range(
+        min = 1,
+        max = 2_147_483_647,
+        message = "ID must be between 1 and 2,147,483,647"
+    ))]
+    pub id: Option<i32>,
+
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Name must be between 1 and 50 characters"
+    ))]
+    pub name: String,
+
+    #[validate(length(
+        max = 1000,
+        message = "Description maximum 1000 characters"
+    ))]
+    pub description: Option<String>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<NaiveDateTime>,
+}
+
+sort_fields! {
+    /// The fields a list of roles can be sorted by.
+    pub enum RoleSortField {
+        Id("id") => "id",
+        Name("name") => "name",
+        DateCreated("date_created") => "date_created",
+    }
+}
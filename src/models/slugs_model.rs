@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+
+/// The content types having slugs, each unique within its own type.
+#[derive(
+    Debug, Serialize, Deserialize, Type, Clone, Copy, PartialEq, ToSchema,
+)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "slug_type", rename_all = "lowercase")]
+pub enum SlugType {
+    Post,
+    Category,
+    Tag,
+}
+
+impl SlugType {
+    /// The table holding the slugs of the content type.
+    pub fn table(&self) -> &'static str {
+        match self {
+            SlugType::Post => "posts",
+            SlugType::Category => "categories",
+            SlugType::Tag => "tags",
+        }
+    }
+
+    /// The condition on the rows of `table` whose slug resolves to them,
+    /// trashed posts having no public URL.
+    pub fn visible_condition(&self) -> &'static str {
+        match self {
+            SlugType::Post => "deleted_at IS NULL",
+            SlugType::Category | SlugType::Tag => "TRUE",
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use utoipa::openapi::{ObjectBuilder, RefOr, Schema, SchemaType};
+use validator::{ValidationError, ValidationErrors};
+
+/// A field a list can be sorted by, mapped to the column it orders on.
+///
+/// Only these columns ever reach the `ORDER BY` clause, so a `sort` query
+/// parameter cannot inject SQL. Implemented through `sort_fields!`.
+pub trait SortField: Sized + Copy + PartialEq + 'static {
+    /// The fields accepted, in the order they are documented.
+    const ALL: &'static [Self];
+
+    /// The name of the field in the `sort` parameter.
+    fn name(self) -> &'static str;
+
+    /// The SQL expression the field orders the rows on.
+    fn column(self) -> &'static str;
+}
+
+/// Declares the fields a list can be sorted by as an enum implementing
+/// `SortField`, documented in OpenAPI as the accepted `sort` values.
+macro_rules! sort_fields {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($field:ident($param:literal) => $column:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum $name {
+            $($field),*
+        }
+
+        impl $crate::models::sort_model::SortField for $name {
+            const ALL: &'static [Self] = &[$($name::$field),*];
+
+            fn name(self) -> &'static str {
+                match self {
+                    $($name::$field => $param),*
+                }
+            }
+
+            fn column(self) -> &'static str {
+                match self {
+                    $($name::$field => $column),*
+                }
+            }
+        }
+
+        impl<'s> utoipa::ToSchema<'s> for $name {
+            fn schema() -> (
+                &'s str,
+                utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>,
+            ) {
+                (
+                    stringify!($name),
+                    $crate::models::sort_model::sort_field_schema::<$name>(),
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use sort_fields;
+
+/// The direction a field is sorted in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// How a list is sorted: fields by order of precedence, each ascending or
+/// descending.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sort<F: SortField>(Vec<(F, SortDirection)>);
+
+impl<F: SortField> Sort<F> {
+    /// Parses a `sort` parameter, a comma-separated list of fields each
+    /// sorted in descending order if prefixed with `-`, e.g.
+    /// `-date_published,title`.
+    ///
+    /// Unknown, empty or repeated fields are rejected.
+    pub fn parse(value: &str) -> Result<Self, ValidationErrors> {
+        let mut keys: Vec<(F, SortDirection)> = Vec::new();
+
+        for part in value.split(',').map(str::trim) {
+            let (name, direction) = match part.strip_prefix('-') {
+                Some(name) => (name, SortDirection::Desc),
+                None => (part, SortDirection::Asc),
+            };
+            if name.is_empty() {
+                return Err(sort_errors(String::from(
+                    "Sort cannot contain an empty field",
+                )));
+            }
+
+            let field = F::ALL
+                .iter()
+                .copied()
+                .find(|field| field.name() == name)
+                .ok_or_else(|| {
+                    sort_errors(format!(
+                        "Unknown sort field '{}', expected one of: {}",
+                        name,
+                        field_names::<F>().join(", ")
+                    ))
+                })?;
+            if keys.iter().any(|(key, _)| *key == field) {
+                return Err(sort_errors(format!(
+                    "Sort field '{}' is given more than once",
+                    name
+                )));
+            }
+
+            keys.push((field, direction));
+        }
+
+        Ok(Sort(keys))
+    }
+
+    /// The expressions of the `ORDER BY` clause, with empty values last.
+    ///
+    /// Rows with equal values are ordered by `id` in the direction of the
+    /// last field, so that pagination is stable.
+    pub fn order_by(&self) -> String {
+        let mut keys = self.0.clone();
+        let id = F::ALL.iter().copied().find(|field| field.name() == "id");
+        if let Some(id) = id {
+            if !keys.iter().any(|(key, _)| *key == id) {
+                let direction = keys
+                    .last()
+                    .map_or(SortDirection::Asc, |(_, direction)| *direction);
+                keys.push((id, direction));
+            }
+        }
+
+        keys.iter()
+            .map(|(field, direction)| {
+                let direction = match direction {
+                    SortDirection::Asc => "ASC",
+                    SortDirection::Desc => "DESC",
+                };
+                format!("{} {} NULLS LAST", field.column(), direction)
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+/// The OpenAPI schema of a sort field: its names, ascending or prefixed
+/// with `-` for descending.
+pub fn sort_field_schema<F: SortField>() -> RefOr<Schema> {
+    let values: Vec<String> = field_names::<F>()
+        .into_iter()
+        .flat_map(|name| [name.to_string(), format!("-{}", name)])
+        .collect();
+
+    ObjectBuilder::new()
+        .schema_type(SchemaType::String)
+        .description(Some(
+            "A field to sort by, in descending order if prefixed with `-`",
+        ))
+        .enum_values(Some(values))
+        .into()
+}
+
+fn field_names<F: SortField>() -> Vec<&'static str> {
+    F::ALL.iter().map(|field| field.name()).collect()
+}
+
+fn sort_errors(message: String) -> ValidationErrors {
+    let mut error = ValidationError::new("invalid_sort");
+    error.message = Some(message.into());
+
+    let mut errors = ValidationErrors::new();
+    errors.add("sort", error);
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    sort_fields! {
+        pub enum TestSortField {
+            Id("id") => "t.id",
+            DatePublished("date_published") => "t.date_published",
+        }
+    }
+
+    #[test]
+    fn test_parse_sort() {
+        // Act
+        let sort = Sort::<TestSortField>::parse("-date_published, id").unwrap();
+
+        // Assert
+        assert_eq!(
+            sort,
+            Sort(vec![
+                (TestSortField::DatePublished, SortDirection::Desc),
+                (TestSortField::Id, SortDirection::Asc),
+            ])
+        );
+        assert_eq!(
+            sort.order_by(),
+            "t.date_published DESC NULLS LAST, t.id ASC NULLS LAST"
+        );
+    }
+
+    #[test]
+    fn test_order_by_tie_breaker() {
+        // Act
+        let sort = Sort::<TestSortField>::parse("-date_published").unwrap();
+        let by_id = Sort::<TestSortField>::parse("-id").unwrap();
+
+        // Assert
+        assert_eq!(
+            sort.order_by(),
+            "t.date_published DESC NULLS LAST, t.id DESC NULLS LAST"
+        );
+        assert_eq!(by_id.order_by(), "t.id DESC NULLS LAST");
+    }
+
+    #[test]
+    fn test_parse_sort_invalid() {
+        // Arrange
+        let messages = |value: &str| {
+            let errors =
+                Sort::<TestSortField>::parse(value).expect_err("Invalid sort");
+            errors.field_errors()["sort"][0]
+                .message
+                .clone()
+                .unwrap()
+                .to_string()
+        };
+
+        // Act & Assert
+        assert_eq!(
+            messages("id; DROP TABLE posts"),
+            "Unknown sort field 'id; DROP TABLE posts', expected one of: \
+             id, date_published"
+        );
+        assert_eq!(messages("id,"), "Sort cannot contain an empty field");
+        assert_eq!(messages("-"), "Sort cannot contain an empty field");
+        assert_eq!(
+            messages("id,-id"),
+            "Sort field 'id' is given more than once"
+        );
+    }
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use validator::Validate;
 
+use crate::models::sort_model::sort_fields;
+
 #[derive(Validate, Serialize, Deserialize, FromRow)]
 pub struct TagModel {
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
@@ -27,4 +29,17 @@ pub struct TagModel {
 
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
     pub date_created: Option<NaiveDateTime>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+}
+
+sort_fields! {
+    /// The fields a list of tags can be sorted by.
+    pub enum TagSortField {
+        Id("id") => "id",
+        Name("name") => "name",
+        Slug("slug") => "slug",
+        DateCreated("date_created") => "date_created",
+    }
 }
@@ -0,0 +1,9 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(FromRow)]
+pub struct UserTotpModel {
+    pub user_id: i32,
+    pub secret: String,
+    pub confirmed_at: Option<NaiveDateTime>,
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use validator::Validate;
 
+use crate::models::sort_model::sort_fields;
+
 #[derive(Validate, Serialize, Deserialize, FromRow, Clone)]
 pub struct UserModel {
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
@@ -59,6 +61,31 @@ pub struct UserModel {
 
     pub active: bool,
 
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub email_verified_at: Option<NaiveDateTime>,
+
     #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
     pub date_created: Option<NaiveDateTime>,
+
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+}
+
+/// The conditions a list of users is restricted to, each one optional.
+#[derive(Default)]
+pub struct UserFilters {
+    pub active: Option<bool>,
+}
+
+sort_fields! {
+    /// The fields a list of users can be sorted by.
+    pub enum UserSortField {
+        Id("id") => "id",
+        Username("username") => "username",
+        Email("email") => "email",
+        Firstname("firstname") => "firstname",
+        Lastname("lastname") => "lastname",
+        Active("active") => "active",
+        DateCreated("date_created") => "date_created",
+    }
 }
@@ -0,0 +1,156 @@
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::models::api_keys_model::ApiKeyModel;
+
+/// Stores a new API key of a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user owning the key.
+/// * `name` - A label for the key, e.g. the client using it.
+/// * `prefix` - The public part of the key, used to look it up.
+/// * `secret_hash` - The SHA-256 hash of the secret part of the key.
+/// * `scopes` - The permissions the key is limited to.
+/// * `expires_at` - When the key stops being accepted, if ever.
+///
+/// # Returns
+///
+/// * `Result<ApiKeyModel>` - The stored key.
+pub async fn insert_api_key(
+    pool: &PgPool,
+    user_id: i32,
+    name: &str,
+    prefix: &str,
+    secret_hash: &str,
+    scopes: &[String],
+    expires_at: Option<NaiveDateTime>,
+) -> Result<ApiKeyModel> {
+    let result = sqlx::query_as::<_, ApiKeyModel>(
+        r#"
+        INSERT INTO api_keys
+            (user_id, name, prefix, secret_hash, scopes, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING *
+        "#,
+    )
+    .bind(user_id)
+    .bind(name)
+    .bind(prefix)
+    .bind(secret_hash)
+    .bind(scopes)
+    .bind(expires_at)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves every API key of a user, newest first.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+///
+/// # Returns
+///
+/// * `Result<Vec<ApiKeyModel>>` - The keys, including revoked and expired ones.
+pub async fn select_api_keys_by_user_id(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Vec<ApiKeyModel>> {
+    let result = sqlx::query_as::<_, ApiKeyModel>(
+        "SELECT * FROM api_keys WHERE user_id = $1 ORDER BY id DESC",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves an API key by its public prefix.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `prefix` - The public part of the key.
+///
+/// # Returns
+///
+/// * `Result<Option<ApiKeyModel>>` - The key, or `None` if the prefix is
+///   unknown.
+pub async fn select_api_key_by_prefix(
+    pool: &PgPool,
+    prefix: &str,
+) -> Result<Option<ApiKeyModel>> {
+    let result = sqlx::query_as::<_, ApiKeyModel>(
+        "SELECT * FROM api_keys WHERE prefix = $1",
+    )
+    .bind(prefix)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Records that an API key was used. The timestamp is only written once a
+/// minute, so that busy clients do not cause a write on every request.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the key.
+pub async fn update_api_key_last_used(pool: &PgPool, id: i32) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE api_keys
+        SET last_used_at = CURRENT_TIMESTAMP
+        WHERE id = $1
+            AND (
+                last_used_at IS NULL
+                OR last_used_at < CURRENT_TIMESTAMP - INTERVAL '1 minute'
+            )
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Revokes an API key of a user. Revoking a key twice keeps the first
+/// revocation date.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the key.
+/// * `user_id` - The ID of the user who must own the key.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the user has no such key.
+pub async fn revoke_api_key(
+    pool: &PgPool,
+    id: i32,
+    user_id: i32,
+) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE api_keys
+        SET revoked_at = COALESCE(revoked_at, CURRENT_TIMESTAMP)
+        WHERE id = $1 AND user_id = $2
+        "#,
+    )
+    .bind(id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
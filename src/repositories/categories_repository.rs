@@ -1,9 +1,12 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
-use crate::models::categories_model::CategoryModel;
+use crate::models::categories_model::{
+    CategoryFilters, CategoryModel, CategorySortField,
+};
+use crate::models::sort_model::Sort;
 
-use super::{Bind, QueryBuilder};
+use super::{Bind, Filters, QueryBuilder};
 
 /// Inserts a new category into the database.
 ///
@@ -44,7 +47,8 @@ pub async fn insert_category(
 ///
 /// # Returns
 ///
-/// * `Result<CategoryModel>` - The updated `CategoryModel` record.
+/// * `Result<CategoryModel>` - The updated `CategoryModel` record, or a
+///   `RowNotFound` error if the row is no longer at the version of the model.
 pub async fn update_category(
     pool: &PgPool,
     id: i32,
@@ -59,34 +63,36 @@ pub async fn update_category(
             category_model.slug.map_or(Bind::Null, Bind::Text),
             category_model.description.map_or(Bind::Null, Bind::Text),
         ])
+        .version(category_model.version)
         .update("id", Bind::Int(id))
         .await?;
 
     Ok(result)
 }
 
-/// Retrieves all categories from the database.
+/// Retrieves the categories matching the filters from the database.
 ///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The conditions the categories must match.
 ///
 /// # Returns
 ///
 /// * `Result<Vec<CategoryModel>>` - A vector containing the retrieved `CategoryModel` records.
 pub async fn select_categories(
     pool: &PgPool,
+    filters: &CategoryFilters,
     limit: i64,
     offset: i64,
-    sort_column: &str,
-    sort_order: &str,
+    sort: &Sort<CategorySortField>,
 ) -> Result<Vec<CategoryModel>> {
     let result = QueryBuilder::<CategoryModel>::new(pool)
         .table("categories")
+        .filters(category_filters(filters))
         .limit(limit)
         .offset(offset)
-        .sort_column(sort_column)
-        .sort_order(sort_order)
+        .sort(sort)
         .fields(&[
             "id",
             "parent_id",
@@ -94,6 +100,7 @@ pub async fn select_categories(
             "slug",
             "description",
             "date_created",
+            "version",
         ])
         .select(None, None)
         .await?;
@@ -124,6 +131,7 @@ pub async fn select_category_by_id(
             "slug",
             "description",
             "date_created",
+            "version",
         ])
         .select_one("id", Bind::Int(id))
         .await?;
@@ -137,6 +145,8 @@ pub async fn select_category_by_id(
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
 /// * `ids` - A vector containing the IDs of the categories to delete.
+/// * `version` - The version of the category if a single one is deleted
+///   provided it is unchanged, `None` otherwise.
 ///
 /// # Returns
 ///
@@ -144,20 +154,58 @@ pub async fn select_category_by_id(
 pub async fn delete_category_by_id(
     pool: &PgPool,
     ids: Vec<i32>,
+    version: Option<i32>,
 ) -> Result<Vec<i32>> {
     let result = QueryBuilder::<CategoryModel>::new(pool)
         .table("categories")
+        .version(version)
         .delete("id", ids)
         .await?;
 
     Ok(result)
 }
 
-pub async fn count_categories(pool: &PgPool) -> Result<i64> {
+pub async fn count_categories(
+    pool: &PgPool,
+    filters: &CategoryFilters,
+) -> Result<i64> {
     let result = QueryBuilder::<CategoryModel>::new(pool)
         .table("categories")
+        .filters(category_filters(filters))
         .count()
         .await?;
 
     Ok(result)
 }
+
+/// Builds the conditions of a list of categories.
+fn category_filters(category_filters: &CategoryFilters) -> Filters {
+    let mut filters = Filters::default();
+    filters.push_some("parent_id = {}", category_filters.parent_id, Bind::Int);
+
+    filters
+}
+
+/// Keeps the IDs of existing categories.
+///
+/// # Arguments
+///
+/// * `conn` - A connection to the PostgreSQL database.
+/// * `ids` - The category IDs to look up.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs among `ids` that belong to a category.
+pub async fn select_existing_category_ids(
+    conn: &mut PgConnection,
+    ids: &[i32],
+) -> Result<Vec<i32>> {
+    let result = sqlx::query_scalar::<_, i32>(
+        "SELECT id FROM categories WHERE id = ANY($1)",
+    )
+    .bind(ids)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+/// Returns the number of seconds left before either the account or the IP
+/// address may attempt to log in again.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `account` - The normalized email the login is attempted for.
+/// * `ip` - The IP address the login is attempted from.
+///
+/// # Returns
+///
+/// * `Result<Option<i64>>` - The remaining lock time, or `None` if neither is locked.
+pub async fn select_login_retry_after(
+    pool: &PgPool,
+    account: &str,
+    ip: &str,
+) -> Result<Option<i64>> {
+    let result = sqlx::query_scalar::<_, Option<i64>>(
+        r#"
+        SELECT CEIL(EXTRACT(EPOCH FROM MAX(locked_until) - CURRENT_TIMESTAMP))::BIGINT
+        FROM login_throttles
+        WHERE ((scope = 'account' AND identifier = $1)
+                OR (scope = 'ip' AND identifier = $2))
+            AND locked_until > CURRENT_TIMESTAMP
+        "#,
+    )
+    .bind(account)
+    .bind(ip)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Records a failed login attempt.
+///
+/// Failures older than `window` seconds are forgotten, so the counter starts
+/// over after a quiet period.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `scope` - Either `account` or `ip`.
+/// * `identifier` - The normalized email or the IP address.
+/// * `window` - The number of seconds a failure is remembered for.
+///
+/// # Returns
+///
+/// * `Result<i32>` - The number of consecutive failures, this one included.
+pub async fn record_login_failure(
+    pool: &PgPool,
+    scope: &str,
+    identifier: &str,
+    window: i64,
+) -> Result<i32> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        INSERT INTO login_throttles (scope, identifier, failed_attempts, last_failed_at)
+        VALUES ($1, $2, 1, CURRENT_TIMESTAMP)
+        ON CONFLICT (scope, identifier) DO UPDATE
+        SET failed_attempts = CASE
+                WHEN login_throttles.last_failed_at
+                    < CURRENT_TIMESTAMP - make_interval(secs => $3) THEN 1
+                ELSE login_throttles.failed_attempts + 1
+            END,
+            last_failed_at = CURRENT_TIMESTAMP
+        RETURNING failed_attempts
+        "#,
+    )
+    .bind(scope)
+    .bind(identifier)
+    .bind(window as f64)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Prevents logins for the given account or IP address for a while.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `scope` - Either `account` or `ip`.
+/// * `identifier` - The normalized email or the IP address.
+/// * `duration` - The lock duration in seconds.
+pub async fn lock_login(
+    pool: &PgPool,
+    scope: &str,
+    identifier: &str,
+    duration: i64,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE login_throttles
+        SET locked_until = CURRENT_TIMESTAMP + make_interval(secs => $3)
+        WHERE scope = $1 AND identifier = $2
+        "#,
+    )
+    .bind(scope)
+    .bind(identifier)
+    .bind(duration as f64)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Forgets the failed attempts and any lock of an account or IP address.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `scope` - Either `account` or `ip`.
+/// * `identifier` - The normalized email or the IP address.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of cleared entries.
+pub async fn delete_login_throttle(
+    pool: &PgPool,
+    scope: &str,
+    identifier: &str,
+) -> Result<u64> {
+    let result = sqlx::query(
+        "DELETE FROM login_throttles WHERE scope = $1 AND identifier = $2",
+    )
+    .bind(scope)
+    .bind(identifier)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
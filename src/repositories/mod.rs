@@ -1,14 +1,34 @@
+use chrono::NaiveDateTime;
 use sqlx::{
     postgres::PgRow,
     query::{Query, QueryAs},
     query_as, Database, Encode, Error, FromRow, PgPool, Type,
 };
 
+use crate::models::sort_model::{Sort, SortField};
+
+pub mod api_keys_repository;
 pub mod categories_repository;
+pub mod login_throttles_repository;
+pub mod permissions_repository;
+pub mod post_drafts_repository;
+pub mod post_revisions_repository;
+pub mod post_status_transitions_repository;
 pub mod posts_categories_repository;
 pub mod posts_repository;
+pub mod posts_tags_repository;
+pub mod public_posts_repository;
+pub mod redirects_repository;
+pub mod refresh_tokens_repository;
+pub mod roles_permissions_repository;
+pub mod roles_repository;
+pub mod slugs_repository;
 pub mod tags_repository;
+pub mod user_recovery_codes_repository;
+pub mod user_tokens_repository;
+pub mod user_totp_repository;
 pub mod users_repository;
+pub mod users_roles_repository;
 
 /// Enum to represent different types of bindable values for SQL queries
 #[derive(Clone, Debug)]
@@ -16,6 +36,7 @@ enum Bind {
     Int(i32),
     Text(String),
     Bool(bool),
+    Timestamp(NaiveDateTime),
     Null,
 }
 
@@ -28,17 +49,90 @@ impl Bind {
         i32: Encode<'q, DB> + Type<DB>,
         String: Encode<'q, DB> + Type<DB>,
         bool: Encode<'q, DB> + Type<DB>,
+        NaiveDateTime: Encode<'q, DB> + Type<DB>,
         Option<i32>: Encode<'q, DB> + Type<DB>,
     {
         match self {
             Bind::Int(val) => query.bind_value(val),
             Bind::Text(val) => query.bind_value(val),
             Bind::Bool(val) => query.bind_value(val),
+            Bind::Timestamp(val) => query.bind_value(val),
             Bind::Null => query.bind_value(None::<i32>),
         }
     }
 }
 
+/// The conditions of a WHERE clause, joined with AND, and the values bound
+/// to their placeholders, numbered from `$1`.
+#[derive(Default)]
+struct Filters {
+    conditions: Vec<String>,
+    values: Vec<Bind>,
+}
+
+impl Filters {
+    /// Adds a condition without a bound value.
+    fn push_condition(&mut self, condition: &str) {
+        self.conditions.push(condition.to_string());
+    }
+
+    /// Adds a condition in which each `{}` stands for the placeholder of
+    /// `value`.
+    fn push(&mut self, condition: &str, value: Bind) {
+        let placeholder = format!("${}", self.values.len() + 1);
+        self.conditions.push(condition.replace("{}", &placeholder));
+        self.values.push(value);
+    }
+
+    /// Adds a condition if the value is set.
+    fn push_some<V>(
+        &mut self,
+        condition: &str,
+        value: Option<V>,
+        bind: impl FnOnce(V) -> Bind,
+    ) {
+        if let Some(value) = value {
+            self.push(condition, bind(value));
+        }
+    }
+
+    /// The WHERE clause with a leading space, empty without conditions.
+    fn where_clause(&self) -> String {
+        if self.conditions.is_empty() {
+            return String::new();
+        }
+
+        format!(" WHERE {}", self.conditions.join(" AND "))
+    }
+
+    /// The number of the first placeholder after those of the conditions.
+    fn next_placeholder(&self) -> usize {
+        self.values.len() + 1
+    }
+
+    /// Binds the values of the conditions, before any other parameter.
+    fn bind_to_query<'q, Q>(&self, mut query: Q) -> Q
+    where
+        Q: BindableQuery<'q, sqlx::Postgres>,
+    {
+        for value in &self.values {
+            query = value.clone().bind_to_query(query);
+        }
+
+        query
+    }
+}
+
+/// Wraps a search term in `%` for `ILIKE`, matching its wildcards literally.
+fn contains_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{}%", escaped)
+}
+
 /// A helper trait to generalize binding for both `Query` and `QueryAs`.
 pub trait BindableQuery<'q, DB: Database>: Sized {
     fn bind_value<T>(self, value: T) -> Self
@@ -82,8 +176,9 @@ struct QueryBuilder<'a, T> {
     values: Vec<Bind>,
     limit: Option<i64>,
     offset: Option<i64>,
-    sort_column: Option<String>,
-    sort_order: Option<String>,
+    order_by: Option<String>,
+    version: Option<i32>,
+    filters: Filters,
     query_type: QueryType,
     _marker: std::marker::PhantomData<T>,
 }
@@ -116,8 +211,9 @@ where
             values: vec![],
             limit: None,
             offset: None,
-            sort_column: None,
-            sort_order: None,
+            order_by: None,
+            version: None,
+            filters: Filters::default(),
             query_type: QueryType::Select,
             _marker: std::marker::PhantomData,
         }
@@ -183,14 +279,42 @@ where
         self
     }
 
-    fn sort_column(mut self, column: &str) -> Self {
-        self.sort_column = Some(column.to_string());
+    /// Sets the order of the rows returned by a SELECT query.
+    ///
+    /// # Arguments
+    /// * `sort` - The whitelisted fields to sort by, with their direction.
+    ///
+    /// # Returns
+    /// Returns the `QueryBuilder` with the order set.
+    fn sort<F: SortField>(mut self, sort: &Sort<F>) -> Self {
+        self.order_by = Some(sort.order_by());
         self
     }
 
-    // Méthode pour définir l'ordre du tri
-    fn sort_order(mut self, order: &str) -> Self {
-        self.sort_order = Some(order.to_string());
+    /// Restricts a SELECT without an ID filter, or a COUNT, to the rows
+    /// matching the conditions.
+    ///
+    /// # Arguments
+    /// * `filters` - The conditions and their bound values.
+    ///
+    /// # Returns
+    /// Returns the `QueryBuilder` with the filters set.
+    fn filters(mut self, filters: Filters) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Restricts an UPDATE or DELETE query to the row at the given version,
+    /// an UPDATE then increments it. A row that has changed since it was
+    /// read is reported as not found.
+    ///
+    /// # Arguments
+    /// * `version` - The version the row was read at, `None` to skip the check.
+    ///
+    /// # Returns
+    /// Returns the `QueryBuilder` with the version set.
+    fn version(mut self, version: Option<i32>) -> Self {
+        self.version = version;
         self
     }
 
@@ -212,16 +336,16 @@ where
         let mut query =
             format!("SELECT {} FROM {}", self.fields.join(", "), self.table);
 
-        // Add WHERE clause if an ID filter is provided
+        // Add WHERE clause if an ID filter is provided, or the filters
         if let Some(id_field) = id_field {
             query.push_str(&format!(" WHERE {} = $1", id_field));
+        } else {
+            query.push_str(&self.filters.where_clause());
         }
 
         // Add ORDER if defined
-        if let Some(ref column) = self.sort_column {
-            let order =
-                self.sort_order.clone().unwrap_or_else(|| "ASC".to_string());
-            query.push_str(&format!(" ORDER BY {} {}", column, order));
+        if let Some(ref order_by) = self.order_by {
+            query.push_str(&format!(" ORDER BY {}", order_by));
         }
 
         // Add LIMIT if defined
@@ -238,6 +362,8 @@ where
 
         if let Some(bind_value) = id_value {
             sql_query = bind_value.clone().bind_to_query(sql_query);
+        } else {
+            sql_query = self.filters.bind_to_query(sql_query);
         }
 
         // Execute the query and fetch all results
@@ -313,7 +439,7 @@ where
         self.query_type = QueryType::Update;
         let mut tx = self.pool.begin().await?;
 
-        let update_fields_str = self
+        let mut update_fields_str = self
             .fields
             .iter()
             .enumerate()
@@ -321,18 +447,29 @@ where
             .collect::<Vec<String>>()
             .join(", ");
 
+        // Only a row still at the version it was read at is updated
+        let mut version_condition = String::new();
+        if let Some(version) = self.version {
+            update_fields_str.push_str(", version = version + 1");
+            version_condition = format!(" AND version = {}", version);
+        }
+
         let query = match value {
             Bind::Int(val) => format!(
-                "UPDATE {} SET {} WHERE {} = {} RETURNING *;",
-                self.table, update_fields_str, field, val
+                "UPDATE {} SET {} WHERE {} = {}{} RETURNING *;",
+                self.table, update_fields_str, field, val, version_condition
             ),
             Bind::Text(ref val) => format!(
-                "UPDATE {} SET {} WHERE {} = '{}' RETURNING *;",
-                self.table, update_fields_str, field, val
+                "UPDATE {} SET {} WHERE {} = '{}'{} RETURNING *;",
+                self.table, update_fields_str, field, val, version_condition
             ),
             Bind::Bool(val) => format!(
-                "UPDATE {} SET {} WHERE {} = {} RETURNING *;",
-                self.table, update_fields_str, field, val
+                "UPDATE {} SET {} WHERE {} = {}{} RETURNING *;",
+                self.table, update_fields_str, field, val, version_condition
+            ),
+            Bind::Timestamp(val) => format!(
+                "UPDATE {} SET {} WHERE {} = '{}'{} RETURNING *;",
+                self.table, update_fields_str, field, val, version_condition
             ),
             Bind::Null => {
                 return Err(Error::RowNotFound);
@@ -369,10 +506,14 @@ where
         self.query_type = QueryType::Delete;
         let mut tx = self.pool.begin().await?;
 
-        let query = format!(
-            "DELETE FROM {} WHERE {} = ANY($1::int[]) RETURNING {}",
-            self.table, column, column
+        let mut query = format!(
+            "DELETE FROM {} WHERE {} = ANY($1::int[])",
+            self.table, column
         );
+        if let Some(version) = self.version {
+            query.push_str(&format!(" AND version = {}", version));
+        }
+        query.push_str(&format!(" RETURNING {}", column));
 
         let sql_query = query_as::<_, (i32,)>(&query);
 
@@ -384,14 +525,23 @@ where
         Ok(deleted_ids)
     }
 
-    /// Builds and executes a COUNT query to count the number of rows.
+    /// Builds and executes a COUNT query to count the number of rows matching
+    /// the filters.
     ///
     /// # Returns
     /// Returns a `Result` containing the count of rows.
     async fn count(self) -> Result<i64, Error> {
-        let query = format!("SELECT COUNT(*) FROM {}", self.table);
+        let query = format!(
+            "SELECT COUNT(*) FROM {}{}",
+            self.table,
+            self.filters.where_clause()
+        );
 
-        let row: (i64,) = sqlx::query_as(&query).fetch_one(self.pool).await?;
+        let row: (i64,) = self
+            .filters
+            .bind_to_query(sqlx::query_as(&query))
+            .fetch_one(self.pool)
+            .await?;
 
         Ok(row.0)
     }
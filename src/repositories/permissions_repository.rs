@@ -0,0 +1,156 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::permissions_model::{PermissionModel, PermissionSortField};
+use crate::models::sort_model::Sort;
+
+use super::{Bind, QueryBuilder};
+
+/// Inserts a new permission into the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `permission_model` - The `PermissionModel` instance containing the permission data to insert.
+///
+/// # Returns
+///
+/// * `Result<PermissionModel>` - The newly inserted `PermissionModel` record.
+pub async fn insert_permission(
+    pool: &PgPool,
+    permission_model: PermissionModel,
+) -> Result<PermissionModel> {
+    let result = QueryBuilder::<PermissionModel>::new(pool)
+        .table("permissions")
+        .fields(&["name", "description"])
+        .values(vec![
+            Bind::Text(permission_model.name),
+            permission_model.description.map_or(Bind::Null, Bind::Text),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Updates an existing permission in the database by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the permission to update.
+/// * `permission_model` - The `PermissionModel` instance containing the updated permission data.
+///
+/// # Returns
+///
+/// * `Result<PermissionModel>` - The updated `PermissionModel` record.
+pub async fn update_permission(
+    pool: &PgPool,
+    id: i32,
+    permission_model: PermissionModel,
+) -> Result<PermissionModel> {
+    let result = QueryBuilder::<PermissionModel>::new(pool)
+        .table("permissions")
+        .fields(&["name", "description"])
+        .values(vec![
+            Bind::Text(permission_model.name),
+            permission_model.description.map_or(Bind::Null, Bind::Text),
+        ])
+        .update("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a paginated list of permissions from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of permissions to retrieve.
+/// * `offset` - The number of permissions to skip.
+/// * `sort` - The fields to sort by.
+///
+/// # Returns
+///
+/// * `Result<Vec<PermissionModel>>` - A vector containing the retrieved `PermissionModel` records.
+pub async fn select_permissions(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+    sort: &Sort<PermissionSortField>,
+) -> Result<Vec<PermissionModel>> {
+    let result = QueryBuilder::<PermissionModel>::new(pool)
+        .table("permissions")
+        .limit(limit)
+        .offset(offset)
+        .sort(sort)
+        .fields(&["id", "name", "description", "date_created"])
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a permission by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the permission to retrieve.
+///
+/// # Returns
+///
+/// * `Result<PermissionModel>` - The `PermissionModel` record for the specified ID.
+pub async fn select_permission_by_id(
+    pool: &PgPool,
+    id: i32,
+) -> Result<PermissionModel> {
+    let result = QueryBuilder::<PermissionModel>::new(pool)
+        .table("permissions")
+        .fields(&["id", "name", "description", "date_created"])
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Deletes permissions by their IDs from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the permissions to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted permissions.
+pub async fn delete_permission_by_id(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<PermissionModel>::new(pool)
+        .table("permissions")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts the total number of permissions in the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The total number of permissions.
+pub async fn count_permissions(pool: &PgPool) -> Result<i64> {
+    let result = QueryBuilder::<PermissionModel>::new(pool)
+        .table("permissions")
+        .count()
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,122 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::post_drafts_model::PostDraftModel;
+
+/// Creates or replaces the working draft of a post. A replaced draft keeps
+/// the version of the post it was started from unless it is rebased.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `draft` - The draft to save.
+/// * `rebase` - Whether a replaced draft takes the `base_version` of `draft`.
+///
+/// # Returns
+///
+/// * `Result<PostDraftModel>` - The saved draft.
+pub async fn upsert_post_draft(
+    pool: &PgPool,
+    draft: &PostDraftModel,
+    rebase: bool,
+) -> Result<PostDraftModel> {
+    let result = sqlx::query_as::<_, PostDraftModel>(
+        r#"
+        INSERT INTO post_drafts (
+            post_id, title, content, slug, slug_generated, editor_id,
+            base_version
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (post_id) DO UPDATE
+        SET title = EXCLUDED.title,
+            content = EXCLUDED.content,
+            slug = EXCLUDED.slug,
+            slug_generated = EXCLUDED.slug_generated,
+            editor_id = EXCLUDED.editor_id,
+            base_version = CASE WHEN $8 THEN EXCLUDED.base_version
+                ELSE post_drafts.base_version END,
+            date_updated = CURRENT_TIMESTAMP
+        RETURNING *
+        "#,
+    )
+    .bind(draft.post_id)
+    .bind(&draft.title)
+    .bind(&draft.content)
+    .bind(&draft.slug)
+    .bind(draft.slug_generated)
+    .bind(draft.editor_id)
+    .bind(draft.base_version)
+    .bind(rebase)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the working draft of a post.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `post_id` - The ID of the post.
+///
+/// # Returns
+///
+/// * `Result<PostDraftModel>` - The draft, or a `RowNotFound` error.
+pub async fn select_post_draft(
+    pool: &PgPool,
+    post_id: i32,
+) -> Result<PostDraftModel> {
+    let result = sqlx::query_as::<_, PostDraftModel>(
+        "SELECT * FROM post_drafts WHERE post_id = $1",
+    )
+    .bind(post_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Removes the working draft of a post and returns it.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the draft is published in.
+/// * `post_id` - The ID of the post.
+///
+/// # Returns
+///
+/// * `Result<Option<PostDraftModel>>` - The draft, or `None` if the post
+///   has none.
+pub async fn take_post_draft(
+    conn: &mut PgConnection,
+    post_id: i32,
+) -> Result<Option<PostDraftModel>> {
+    let result = sqlx::query_as::<_, PostDraftModel>(
+        "DELETE FROM post_drafts WHERE post_id = $1 RETURNING *",
+    )
+    .bind(post_id)
+    .fetch_optional(conn)
+    .await?;
+
+    Ok(result)
+}
+
+/// Discards the working draft of a post.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `post_id` - The ID of the post.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of deleted drafts.
+pub async fn delete_post_draft(pool: &PgPool, post_id: i32) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM post_drafts WHERE post_id = $1")
+        .bind(post_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,107 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::post_revisions_model::PostRevisionModel;
+
+/// Records the current state of a post as its next revision.
+///
+/// The post must be locked by the transaction, so that two revisions cannot
+/// get the same number.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is saved in.
+/// * `post_id` - The ID of the post.
+/// * `editor_id` - The ID of the user who saved the post.
+///
+/// # Returns
+///
+/// * `Result<i32>` - The number of the new revision.
+pub async fn insert_post_revision(
+    conn: &mut PgConnection,
+    post_id: i32,
+    editor_id: i32,
+) -> Result<i32> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        INSERT INTO post_revisions (
+            post_id, revision_number, title, content, content_format, slug,
+            status, editor_id
+        )
+        SELECT id,
+            COALESCE(
+                (SELECT MAX(revision_number) FROM post_revisions
+                WHERE post_id = $1),
+                0
+            ) + 1,
+            title, content, content_format, slug, status, $2
+        FROM posts
+        WHERE id = $1
+        RETURNING revision_number
+        "#,
+    )
+    .bind(post_id)
+    .bind(editor_id)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the revisions of a post, the latest first.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `post_id` - The ID of the post.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostRevisionModel>>` - The revisions of the post.
+pub async fn select_post_revisions(
+    pool: &PgPool,
+    post_id: i32,
+) -> Result<Vec<PostRevisionModel>> {
+    let result = sqlx::query_as::<_, PostRevisionModel>(
+        r#"
+        SELECT * FROM post_revisions
+        WHERE post_id = $1
+        ORDER BY revision_number DESC
+        "#,
+    )
+    .bind(post_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a revision of a post by its number.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `post_id` - The ID of the post.
+/// * `revision_number` - The number of the revision.
+///
+/// # Returns
+///
+/// * `Result<PostRevisionModel>` - The revision, or a `RowNotFound` error.
+pub async fn select_post_revision(
+    pool: &PgPool,
+    post_id: i32,
+    revision_number: i32,
+) -> Result<PostRevisionModel> {
+    let result = sqlx::query_as::<_, PostRevisionModel>(
+        r#"
+        SELECT * FROM post_revisions
+        WHERE post_id = $1 AND revision_number = $2
+        "#,
+    )
+    .bind(post_id)
+    .bind(revision_number)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use sqlx::PgConnection;
+
+use crate::models::posts_model::PostsStatus;
+
+/// Records a change of status of a post.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is changed in.
+/// * `post_id` - The ID of the post.
+/// * `from` - The previous status.
+/// * `to` - The new status.
+/// * `changed_by` - The ID of the user changing the status, `None` for the
+///   scheduled publishing worker.
+pub async fn insert_post_status_transition(
+    conn: &mut PgConnection,
+    post_id: i32,
+    from: &PostsStatus,
+    to: &PostsStatus,
+    changed_by: Option<i32>,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO post_status_transitions (
+            post_id, from_status, to_status, changed_by
+        )
+        VALUES ($1, $2::posts_status, $3::posts_status, $4)
+        "#,
+    )
+    .bind(post_id)
+    .bind(from)
+    .bind(to)
+    .bind(changed_by)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
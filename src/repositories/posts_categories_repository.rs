@@ -1,5 +1,5 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
 use crate::models::posts_categories_model::PostsCategoriesModel;
 
@@ -82,3 +82,47 @@ pub async fn delete_post_category_by_category_id(
 
     Ok(result)
 }
+
+/// Sets the categories of a post. Only the categories that are no longer
+/// wanted are removed, and only the missing ones are added.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is saved in.
+/// * `post_id` - The ID of the post.
+/// * `category_ids` - The IDs of every category the post should have.
+pub async fn replace_post_categories(
+    conn: &mut PgConnection,
+    post_id: i32,
+    category_ids: &[i32],
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        DELETE FROM posts_categories
+        WHERE post_id = $1 AND category_id <> ALL($2)
+        "#,
+    )
+    .bind(post_id)
+    .bind(category_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO posts_categories (post_id, category_id)
+        SELECT $1, new_categories.category_id
+        FROM UNNEST($2::INTEGER[]) AS new_categories (category_id)
+        WHERE NOT EXISTS (
+            SELECT 1 FROM posts_categories
+            WHERE post_id = $1
+                AND category_id = new_categories.category_id
+        )
+        "#,
+    )
+    .bind(post_id)
+    .bind(category_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
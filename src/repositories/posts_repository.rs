@@ -1,42 +1,49 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use chrono::NaiveDateTime;
+use sqlx::{PgConnection, PgPool};
 
-use crate::models::posts_model::PostModel;
+use crate::models::posts_model::{
+    ContentFormat, PostContent, PostFilters, PostModel, PostSortField,
+    PostsStatus,
+};
+use crate::models::sort_model::Sort;
 
-use super::{Bind, QueryBuilder};
+use super::{contains_pattern, Bind, Filters};
 
 /// Inserts a new post into the database.
 ///
 /// # Arguments
 ///
-/// * `pool` - A reference to the PostgreSQL connection pool.
-/// * `model` - The `PostModel` instance containing the post data to insert.
+/// * `conn` - The connection of the transaction the post is created in.
+/// * `post_model` - The `PostModel` instance containing the post data to insert.
 ///
 /// # Returns
 ///
-/// * `Result<PostModel>` - The newly inserted `PostModel` record.
+/// * `Result<i32>` - The ID of the new post.
 pub async fn insert_post(
-    pool: &PgPool,
-    post_model: PostModel,
-) -> Result<PostModel> {
-    let result = QueryBuilder::<PostModel>::new(&pool)
-        .table("posts")
-        .fields(&[
-            "title",
-            "content",
-            "slug",
-            "author_id",
-            "status",
-            "date_published",
-        ])
-        .values(vec![
-            Bind::Text(post_model.title),
-            Bind::Text(post_model.content),
-            post_model.slug.map_or(Bind::Null, Bind::Text),
-            Bind::Int(post_model.author_id),
-        ])
-        .insert()
-        .await?;
+    conn: &mut PgConnection,
+    post_model: &PostModel,
+) -> Result<i32> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        INSERT INTO posts (
+            title, content, slug, author_id, status, date_published,
+            content_format, content_html
+        )
+        VALUES ($1, $2, $3, $4, $5::posts_status, $6, $7, $8)
+        RETURNING id
+        "#,
+    )
+    .bind(&post_model.title)
+    .bind(&post_model.content)
+    .bind(&post_model.slug)
+    .bind(post_model.author_id)
+    .bind(&post_model.status)
+    .bind(post_model.date_published)
+    .bind(&post_model.content_format)
+    .bind(&post_model.content_html)
+    .fetch_one(conn)
+    .await?;
 
     Ok(result)
 }
@@ -45,82 +52,246 @@ pub async fn insert_post(
 ///
 /// # Arguments
 ///
-/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `conn` - The connection of the transaction the post is updated in.
 /// * `id` - The ID of the post to update.
-/// * `model` - The `PostModel` instance containing the updated post data.
+/// * `post_model` - The `PostModel` instance containing the updated post data.
 ///
 /// # Returns
 ///
-/// * `Result<PostModel>` - The updated `PostModel` record.
+/// * `Result<()>` - A `RowNotFound` error if there is no such post.
 pub async fn update_post(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     id: i32,
-    post_model: PostModel,
-) -> Result<PostModel> {
-    let result = QueryBuilder::<PostModel>::new(&pool)
-        .table("posts")
-        .fields(&[
-            "title",
-            "content",
-            "slug",
-            "author_id",
-            "status",
-            "date_published",
-        ])
-        .values(vec![
-            Bind::Text(post_model.title),
-            Bind::Text(post_model.content),
-            post_model.slug.map_or(Bind::Null, Bind::Text),
-            Bind::Int(post_model.author_id),
-        ])
-        .update("id", Bind::Int(id))
+    post_model: &PostModel,
+) -> Result<()> {
+    let result = sqlx::query(
+        r#"
+        UPDATE posts
+        SET title = $2,
+            content = $3,
+            slug = $4,
+            status = $5::posts_status,
+            date_published = $6,
+            updated_by = $7,
+            date_updated = $8,
+            content_format = $9,
+            content_html = $10,
+            version = version + 1
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(&post_model.title)
+    .bind(&post_model.content)
+    .bind(&post_model.slug)
+    .bind(&post_model.status)
+    .bind(post_model.date_published)
+    .bind(post_model.updated_by)
+    .bind(post_model.date_updated)
+    .bind(&post_model.content_format)
+    .bind(&post_model.content_html)
+    .execute(conn)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound.into());
+    }
+
+    Ok(())
+}
+
+/// Selects posts along with their categories and tags, aggregated as JSON
+/// arrays.
+const SELECT_POSTS_WITH_TERMS: &str = r#"
+    SELECT p.id,
+        p.title,
+        p.content,
+        p.content_format,
+        p.content_html,
+        p.slug,
+        p.author_id,
+        p.status,
+        p.date_published,
+        p.date_created,
+        p.updated_by,
+        p.date_updated,
+        p.version,
+        p.deleted_at,
+        COALESCE(
+            (
+                SELECT json_agg(
+                        json_build_object(
+                            'id',
+                            c.id,
+                            'name',
+                            c.name,
+                            'description',
+                            c.description
+                        )
+                        ORDER BY c.name
+                    )
+                FROM posts_categories pc
+                    INNER JOIN categories c ON c.id = pc.category_id
+                WHERE pc.post_id = p.id
+            ),
+            '[]'
+        ) AS categories,
+        COALESCE(
+            (
+                SELECT json_agg(
+                        json_build_object(
+                            'id',
+                            t.id,
+                            'name',
+                            t.name,
+                            'slug',
+                            t.slug
+                        )
+                        ORDER BY t.name
+                    )
+                FROM posts_tags pt
+                    INNER JOIN tags t ON t.id = pt.tag_id
+                WHERE pt.post_id = p.id
+            ),
+            '[]'
+        ) AS tags
+    FROM posts p
+"#;
+
+/// Retrieves a paginated list of posts from the database, leaving out the
+/// trashed ones.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The conditions the posts must match.
+/// * `limit` - The maximum number of posts to retrieve.
+/// * `offset` - The number of posts to skip before starting to retrieve the records.
+/// * `sort` - The fields to sort by.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostModel>>` - A vector containing the retrieved `PostModel` records.
+pub async fn select_posts(
+    pool: &PgPool,
+    filters: &PostFilters,
+    limit: i64,
+    offset: i64,
+    sort: &Sort<PostSortField>,
+) -> Result<Vec<PostModel>> {
+    let filters = post_filters(filters);
+    let limit_placeholder = filters.next_placeholder();
+    let query = format!(
+        "{}{} ORDER BY {} LIMIT ${} OFFSET ${}",
+        SELECT_POSTS_WITH_TERMS,
+        filters.where_clause(),
+        sort.order_by(),
+        limit_placeholder,
+        limit_placeholder + 1
+    );
+
+    let result = filters
+        .bind_to_query(sqlx::query_as::<_, PostModel>(&query))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
         .await?;
 
     Ok(result)
 }
 
-/// Retrieves a paginated list of posts from the database.
+/// Builds the conditions of a list of posts, leaving out the trashed ones.
+fn post_filters(post_filters: &PostFilters) -> Filters {
+    let mut filters = Filters::default();
+    filters.push_condition("p.deleted_at IS NULL");
+    filters.push_some(
+        "p.status = {}::posts_status",
+        post_filters.status.as_ref(),
+        |status| Bind::Text(format!("{:?}", status)),
+    );
+    filters.push_some("p.author_id = {}", post_filters.author_id, Bind::Int);
+    filters.push_some(
+        r#"EXISTS (
+            SELECT 1 FROM posts_categories pc
+            WHERE pc.post_id = p.id AND pc.category_id = {}
+        )"#,
+        post_filters.category_id,
+        Bind::Int,
+    );
+    filters.push_some(
+        r#"EXISTS (
+            SELECT 1 FROM posts_tags pt
+                INNER JOIN tags t ON t.id = pt.tag_id
+            WHERE pt.post_id = p.id AND t.slug = {}
+        )"#,
+        post_filters.tag.clone(),
+        Bind::Text,
+    );
+    filters.push_some(
+        "p.date_published >= {}",
+        post_filters.date_published_gte,
+        Bind::Timestamp,
+    );
+    filters.push_some(
+        "p.date_published <= {}",
+        post_filters.date_published_lte,
+        Bind::Timestamp,
+    );
+    filters.push_some(
+        "(p.title ILIKE {} OR p.content ILIKE {})",
+        post_filters.q.as_deref(),
+        |q| Bind::Text(contains_pattern(q)),
+    );
+
+    filters
+}
+
+/// Retrieves a paginated list of the posts having a tag, leaving out the
+/// trashed ones.
 ///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `tag_id` - The ID of the tag.
 /// * `limit` - The maximum number of posts to retrieve.
 /// * `offset` - The number of posts to skip before starting to retrieve the records.
+/// * `sort` - The fields to sort by.
 ///
 /// # Returns
 ///
 /// * `Result<Vec<PostModel>>` - A vector containing the retrieved `PostModel` records.
-pub async fn select_posts(
+pub async fn select_posts_by_tag_id(
     pool: &PgPool,
+    tag_id: i32,
     limit: i64,
     offset: i64,
-    sort_column: &str,
-    sort_order: &str,
+    sort: &Sort<PostSortField>,
 ) -> Result<Vec<PostModel>> {
-    let result = QueryBuilder::<PostModel>::new(pool)
-        .table("posts")
-        .limit(limit)
-        .offset(offset)
-        .sort_column(sort_column)
-        .sort_order(sort_order)
-        .fields(&[
-            "id",
-            "title",
-            "content",
-            "slug",
-            "author_id",
-            "status",
-            "date_published",
-            "date_created",
-            "categories",
-        ])
-        .select(None, None)
+    let query = format!(
+        r#"
+        {}
+        WHERE p.deleted_at IS NULL
+            AND EXISTS (
+                SELECT 1 FROM posts_tags
+                WHERE posts_tags.post_id = p.id AND posts_tags.tag_id = $1
+            )
+        ORDER BY {} LIMIT $2 OFFSET $3
+        "#,
+        SELECT_POSTS_WITH_TERMS,
+        sort.order_by()
+    );
+
+    let result = sqlx::query_as::<_, PostModel>(&query)
+        .bind(tag_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
         .await?;
 
     Ok(result)
 }
 
-/// Retrieves a post by its ID from the database.
+/// Retrieves a post by its ID from the database, unless it is trashed.
 ///
 /// # Arguments
 ///
@@ -131,59 +302,508 @@ pub async fn select_posts(
 ///
 /// * `Result<PostModel>` - The `PostModel` record for the specified ID.
 pub async fn select_post_by_id(pool: &PgPool, id: i32) -> Result<PostModel> {
-    let result = QueryBuilder::<PostModel>::new(pool)
-        .table("posts")
-        .fields(&[
-            "id",
-            "title",
-            "content",
-            "slug",
-            "author_id",
-            "status",
-            "date_published",
-        ])
-        .select_one("id", Bind::Int(id))
+    let query = format!(
+        "{} WHERE p.id = $1 AND p.deleted_at IS NULL",
+        SELECT_POSTS_WITH_TERMS
+    );
+
+    let result = sqlx::query_as::<_, PostModel>(&query)
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(result)
+}
+
+/// Moves posts to the trash, they can be restored until they are purged.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the posts to trash.
+/// * `version` - The version of the post if a single one is trashed
+///   provided it is unchanged, `None` otherwise.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the trashed posts, leaving out the
+///   unknown and already trashed ones.
+pub async fn trash_posts_by_id(
+    pool: &PgPool,
+    ids: Vec<i32>,
+    version: Option<i32>,
+) -> Result<Vec<i32>> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        UPDATE posts
+        SET deleted_at = CURRENT_TIMESTAMP, version = version + 1
+        WHERE id = ANY($1)
+            AND deleted_at IS NULL
+            AND ($2::INTEGER IS NULL OR version = $2)
+        RETURNING id
+        "#,
+    )
+    .bind(ids)
+    .bind(version)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a page of the trashed posts, the last trashed first.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of posts to retrieve.
+/// * `offset` - The number of posts to skip.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostModel>>` - The trashed posts, with their terms.
+pub async fn select_trashed_posts(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<PostModel>> {
+    let query = format!(
+        r#"
+        {}
+        WHERE p.deleted_at IS NOT NULL
+        ORDER BY p.deleted_at DESC, p.id DESC
+        LIMIT $1 OFFSET $2
+        "#,
+        SELECT_POSTS_WITH_TERMS
+    );
+
+    let result = sqlx::query_as::<_, PostModel>(&query)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
         .await?;
 
     Ok(result)
 }
 
-/// Deletes posts by their IDs from the database.
+/// Counts the trashed posts.
 ///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
-/// * `ids` - A vector containing the IDs of the posts to delete.
 ///
 /// # Returns
 ///
-/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted posts.
-pub async fn delete_post_by_id(
+/// * `Result<i64>` - The number of trashed posts.
+pub async fn count_trashed_posts(pool: &PgPool) -> Result<i64> {
+    let result = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM posts WHERE deleted_at IS NOT NULL",
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Takes posts out of the trash.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - The IDs of the posts to restore.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the restored posts, leaving out the
+///   ones that are not in the trash.
+pub async fn restore_trashed_posts(
     pool: &PgPool,
     ids: Vec<i32>,
 ) -> Result<Vec<i32>> {
-    let result = QueryBuilder::<PostModel>::new(pool)
-        .table("posts")
-        .delete("id", ids)
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        UPDATE posts
+        SET deleted_at = NULL, version = version + 1
+        WHERE id = ANY($1) AND deleted_at IS NOT NULL
+        RETURNING id
+        "#,
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Permanently deletes trashed posts, along with their terms, revisions
+/// and drafts.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - The IDs of the posts to purge.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the purged posts, leaving out the
+///   ones that are not in the trash.
+pub async fn purge_trashed_posts(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = sqlx::query_scalar::<_, i32>(
+        "DELETE FROM posts WHERE id = ANY($1) AND deleted_at IS NOT NULL RETURNING id",
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Permanently deletes the posts trashed for longer than a number of days,
+/// measured with the database clock `deleted_at` is stamped with.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `retention_days` - The number of days a post stays in the trash.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the purged posts.
+pub async fn purge_expired_trashed_posts(
+    pool: &PgPool,
+    retention_days: i32,
+) -> Result<Vec<i32>> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        DELETE FROM posts
+        WHERE deleted_at < CURRENT_TIMESTAMP - make_interval(days => $1)
+        RETURNING id
+        "#,
+    )
+    .bind(retention_days)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Counts the posts in the database matching the filters, leaving out the
+/// trashed ones.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `filters` - The conditions the posts must match.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The number of matching posts.
+pub async fn count_posts(pool: &PgPool, filters: &PostFilters) -> Result<i64> {
+    let filters = post_filters(filters);
+    let query =
+        format!("SELECT COUNT(*) FROM posts p{}", filters.where_clause());
+
+    let (result,) = filters
+        .bind_to_query(sqlx::query_as::<_, (i64,)>(&query))
+        .fetch_one(pool)
         .await?;
 
     Ok(result)
 }
 
-/// Counts the total number of posts in the database.
+/// Counts the posts having a tag, leaving out the trashed ones.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `tag_id` - The ID of the tag.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The number of posts.
+pub async fn count_posts_by_tag_id(pool: &PgPool, tag_id: i32) -> Result<i64> {
+    let result = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM posts_tags
+            INNER JOIN posts ON posts.id = posts_tags.post_id
+        WHERE posts_tags.tag_id = $1 AND posts.deleted_at IS NULL
+        "#,
+    )
+    .bind(tag_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Publishes the scheduled posts whose publication date has passed, and
+/// records the transitions.
+///
+/// Posts being published by another instance are skipped rather than
+/// waited for, so that concurrent runs never publish a post twice.
 ///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `now` - The current time, in UTC like the publication dates.
+/// * `limit` - The maximum number of posts to publish.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the published posts.
+pub async fn publish_due_posts(
+    pool: &PgPool,
+    now: NaiveDateTime,
+    limit: i64,
+) -> Result<Vec<i32>> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        WITH due AS (
+            SELECT id FROM posts
+            WHERE status = 'Scheduled'
+                AND date_published <= $1
+                AND deleted_at IS NULL
+            ORDER BY date_published
+            LIMIT $2
+            FOR UPDATE SKIP LOCKED
+        ),
+        published AS (
+            UPDATE posts SET status = 'Published', version = version + 1
+            FROM due
+            WHERE posts.id = due.id
+            RETURNING posts.id
+        )
+        INSERT INTO post_status_transitions (post_id, from_status, to_status)
+        SELECT id, 'Scheduled', 'Published' FROM published
+        RETURNING post_id
+        "#,
+    )
+    .bind(now)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the scheduled posts, the next to be published first.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of posts to retrieve.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostModel>>` - The scheduled posts, without their terms.
+pub async fn select_scheduled_posts(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<PostModel>> {
+    let result = sqlx::query_as::<_, PostModel>(
+        r#"
+        SELECT * FROM posts
+        WHERE status = 'Scheduled' AND deleted_at IS NULL
+        ORDER BY date_published, id
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the status, publication date and version of a post that is not
+/// trashed, locking the post until the end of the transaction.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is changed in.
+/// * `id` - The ID of the post.
 ///
 /// # Returns
 ///
-/// * `Result<i64>` - The total number of posts.
-pub async fn count_posts(pool: &PgPool) -> Result<i64> {
-    let result = QueryBuilder::<PostModel>::new(pool)
-        .table("posts")
-        .count()
+/// * `Result<(PostsStatus, Option<NaiveDateTime>, i32)>` - The status, the
+///   publication date and the version of the post.
+pub async fn select_post_status_for_update(
+    conn: &mut PgConnection,
+    id: i32,
+) -> Result<(PostsStatus, Option<NaiveDateTime>, i32)> {
+    let result =
+        sqlx::query_as::<_, (PostsStatus, Option<NaiveDateTime>, i32)>(
+            r#"
+            SELECT status, date_published, version FROM posts
+            WHERE id = $1 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+        )
+        .bind(id)
+        .fetch_one(conn)
         .await?;
 
     Ok(result)
 }
+
+/// Changes the status and publication date of a post.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is changed in.
+/// * `id` - The ID of the post.
+/// * `status` - The new status.
+/// * `date_published` - The new publication date.
+/// * `updated_by` - The ID of the user changing the status.
+pub async fn update_post_status(
+    conn: &mut PgConnection,
+    id: i32,
+    status: &PostsStatus,
+    date_published: Option<NaiveDateTime>,
+    updated_by: i32,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE posts
+        SET status = $2::posts_status,
+            date_published = $3,
+            updated_by = $4,
+            date_updated = $5,
+            version = version + 1
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(status)
+    .bind(date_published)
+    .bind(updated_by)
+    .bind(chrono::Utc::now().naive_utc())
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Replaces the title, content and slug of a post, leaving its status
+/// untouched.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is changed in.
+/// * `id` - The ID of the post.
+/// * `post_content` - The new title, content and slug.
+/// * `updated_by` - The ID of the user changing the post.
+pub async fn update_post_content(
+    conn: &mut PgConnection,
+    id: i32,
+    post_content: &PostContent,
+    updated_by: i32,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE posts
+        SET title = $2,
+            content = $3,
+            content_format = $4,
+            content_html = $5,
+            slug = $6,
+            updated_by = $7,
+            date_updated = $8,
+            version = version + 1
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(&post_content.title)
+    .bind(&post_content.content)
+    .bind(&post_content.content_format)
+    .bind(&post_content.content_html)
+    .bind(&post_content.slug)
+    .bind(updated_by)
+    .bind(chrono::Utc::now().naive_utc())
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Retrieves the content format of a post.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is changed in.
+/// * `id` - The ID of the post.
+///
+/// # Returns
+///
+/// * `Result<ContentFormat>` - The format, or a `RowNotFound` error if there
+///   is no such post.
+pub async fn select_post_content_format(
+    conn: &mut PgConnection,
+    id: i32,
+) -> Result<ContentFormat> {
+    let result = sqlx::query_scalar::<_, ContentFormat>(
+        "SELECT content_format FROM posts WHERE id = $1",
+    )
+    .bind(id)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves posts whose content has not been rendered to HTML yet, trashed
+/// ones included.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of posts to retrieve.
+///
+/// # Returns
+///
+/// * `Result<Vec<(i32, ContentFormat, String)>>` - The ID, content format
+///   and content of each post.
+pub async fn select_posts_without_content_html(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<(i32, ContentFormat, String)>> {
+    let result = sqlx::query_as::<_, (i32, ContentFormat, String)>(
+        r#"
+        SELECT id, content_format, content FROM posts
+        WHERE content_html IS NULL
+        ORDER BY id
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Stores the HTML rendering of the content of a post, without changing its
+/// version: it is derived from the content.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the post.
+/// * `content_html` - The content, rendered to HTML.
+pub async fn update_post_content_html(
+    pool: &PgPool,
+    id: i32,
+    content_html: &str,
+) -> Result<()> {
+    sqlx::query("UPDATE posts SET content_html = $2 WHERE id = $1")
+        .bind(id)
+        .bind(content_html)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
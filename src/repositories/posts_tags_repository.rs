@@ -0,0 +1,37 @@
+use anyhow::Result;
+use sqlx::PgConnection;
+
+/// Sets the tags of a post, removing the tags that are not in `tag_ids`.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is saved in.
+/// * `post_id` - The ID of the post.
+/// * `tag_ids` - The IDs of every tag the post should have.
+pub async fn replace_post_tags(
+    conn: &mut PgConnection,
+    post_id: i32,
+    tag_ids: &[i32],
+) -> Result<()> {
+    sqlx::query(
+        "DELETE FROM posts_tags WHERE post_id = $1 AND tag_id <> ALL($2)",
+    )
+    .bind(post_id)
+    .bind(tag_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO posts_tags (post_id, tag_id)
+        SELECT $1, UNNEST($2::INTEGER[])
+        ON CONFLICT (post_id, tag_id) DO NOTHING
+        "#,
+    )
+    .bind(post_id)
+    .bind(tag_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::posts_model::PublicPostModel;
+
+/// Selects the published posts that are not trashed, with the display name
+/// of their author and their categories and tags aggregated as JSON arrays.
+const SELECT_PUBLIC_POSTS: &str = r#"
+    SELECT p.id,
+        p.title,
+        p.slug,
+        p.content,
+        p.content_format,
+        p.content_html,
+        COALESCE(
+            NULLIF(TRIM(CONCAT_WS(' ', u.firstname, u.lastname)), ''),
+            u.username
+        ) AS author_name,
+        p.date_published,
+        p.date_updated,
+        COALESCE(
+            (
+                SELECT json_agg(
+                        json_build_object(
+                            'id',
+                            c.id,
+                            'name',
+                            c.name,
+                            'slug',
+                            c.slug
+                        )
+                        ORDER BY c.name
+                    )
+                FROM posts_categories pc
+                    INNER JOIN categories c ON c.id = pc.category_id
+                WHERE pc.post_id = p.id
+            ),
+            '[]'
+        ) AS categories,
+        COALESCE(
+            (
+                SELECT json_agg(
+                        json_build_object(
+                            'id',
+                            t.id,
+                            'name',
+                            t.name,
+                            'slug',
+                            t.slug
+                        )
+                        ORDER BY t.name
+                    )
+                FROM posts_tags pt
+                    INNER JOIN tags t ON t.id = pt.tag_id
+                WHERE pt.post_id = p.id
+            ),
+            '[]'
+        ) AS tags
+    FROM posts p
+        INNER JOIN users u ON u.id = p.author_id
+    WHERE p.status = 'Published'
+        AND p.deleted_at IS NULL
+"#;
+
+/// Retrieves a page of published posts, the most recent first.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of posts to retrieve.
+/// * `offset` - The number of posts to skip before starting to retrieve the records.
+///
+/// # Returns
+///
+/// * `Result<Vec<PublicPostModel>>` - A vector containing the retrieved `PublicPostModel` records.
+pub async fn select_public_posts(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<PublicPostModel>> {
+    let query = format!(
+        "{} ORDER BY p.date_published DESC, p.id DESC LIMIT $1 OFFSET $2",
+        SELECT_PUBLIC_POSTS
+    );
+
+    let result = sqlx::query_as::<_, PublicPostModel>(&query)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts the published posts that are not trashed.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The number of published posts.
+pub async fn count_public_posts(pool: &PgPool) -> Result<i64> {
+    let result = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM posts
+        WHERE status = 'Published' AND deleted_at IS NULL
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a published post by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the post to retrieve.
+///
+/// # Returns
+///
+/// * `Result<PublicPostModel>` - The post, or a `RowNotFound` error if it
+///   is not published.
+pub async fn select_public_post_by_id(
+    pool: &PgPool,
+    id: i32,
+) -> Result<PublicPostModel> {
+    let query = format!("{} AND p.id = $1", SELECT_PUBLIC_POSTS);
+
+    let result = sqlx::query_as::<_, PublicPostModel>(&query)
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a published post by its slug.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `slug` - The slug of the post to retrieve.
+///
+/// # Returns
+///
+/// * `Result<PublicPostModel>` - The post, or a `RowNotFound` error if it
+///   is not published.
+pub async fn select_public_post_by_slug(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<PublicPostModel> {
+    let query = format!("{} AND p.slug = $1", SELECT_PUBLIC_POSTS);
+
+    let result = sqlx::query_as::<_, PublicPostModel>(&query)
+        .bind(slug)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,199 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::redirects_model::{RedirectModel, RedirectSortField};
+use crate::models::sort_model::Sort;
+
+use super::{Bind, QueryBuilder};
+
+const REDIRECT_FIELDS: &[&str] = &[
+    "id",
+    "source_path",
+    "target",
+    "status_code",
+    "hits",
+    "last_hit_at",
+    "date_created",
+];
+
+/// Inserts a new redirect into the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `redirect_model` - The `RedirectModel` instance containing the redirect data to insert.
+///
+/// # Returns
+///
+/// * `Result<RedirectModel>` - The newly inserted `RedirectModel` record.
+pub async fn insert_redirect(
+    pool: &PgPool,
+    redirect_model: RedirectModel,
+) -> Result<RedirectModel> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .fields(&["source_path", "target", "status_code"])
+        .values(vec![
+            Bind::Text(redirect_model.source_path),
+            Bind::Text(redirect_model.target),
+            Bind::Int(redirect_model.status_code),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Updates an existing redirect in the database by its ID, keeping its hit
+/// counter.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the redirect to update.
+/// * `redirect_model` - The `RedirectModel` instance containing the updated redirect data.
+///
+/// # Returns
+///
+/// * `Result<RedirectModel>` - The updated `RedirectModel` record.
+pub async fn update_redirect(
+    pool: &PgPool,
+    id: i32,
+    redirect_model: RedirectModel,
+) -> Result<RedirectModel> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .fields(&["source_path", "target", "status_code"])
+        .values(vec![
+            Bind::Text(redirect_model.source_path),
+            Bind::Text(redirect_model.target),
+            Bind::Int(redirect_model.status_code),
+        ])
+        .update("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a paginated list of redirects from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of redirects to retrieve.
+/// * `offset` - The number of redirects to skip.
+/// * `sort` - The fields to sort by.
+///
+/// # Returns
+///
+/// * `Result<Vec<RedirectModel>>` - A vector containing the retrieved `RedirectModel` records.
+pub async fn select_redirects(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+    sort: &Sort<RedirectSortField>,
+) -> Result<Vec<RedirectModel>> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .limit(limit)
+        .offset(offset)
+        .sort(sort)
+        .fields(REDIRECT_FIELDS)
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a redirect by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the redirect to retrieve.
+///
+/// # Returns
+///
+/// * `Result<RedirectModel>` - The `RedirectModel` record for the specified ID.
+pub async fn select_redirect_by_id(
+    pool: &PgPool,
+    id: i32,
+) -> Result<RedirectModel> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .fields(REDIRECT_FIELDS)
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts a hit on the redirect from the given path.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `source_path` - The requested path.
+///
+/// # Returns
+///
+/// * `Result<Option<RedirectModel>>` - The followed redirect, or `None` if
+///   no redirect starts from the path.
+pub async fn hit_redirect(
+    pool: &PgPool,
+    source_path: &str,
+) -> Result<Option<RedirectModel>> {
+    let result = sqlx::query_as::<_, RedirectModel>(
+        r#"
+        UPDATE redirects
+        SET hits = hits + 1, last_hit_at = CURRENT_TIMESTAMP
+        WHERE source_path = $1
+        RETURNING *
+        "#,
+    )
+    .bind(source_path)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Deletes redirects by their IDs from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the redirects to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted redirects.
+pub async fn delete_redirect_by_id(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts the total number of redirects in the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The total number of redirects.
+pub async fn count_redirects(pool: &PgPool) -> Result<i64> {
+    let result = QueryBuilder::<RedirectModel>::new(pool)
+        .table("redirects")
+        .count()
+        .await?;
+
+    Ok(result)
+}
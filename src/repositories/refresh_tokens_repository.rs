@@ -0,0 +1,190 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::refresh_tokens_model::RefreshTokenModel;
+
+/// Stores a new refresh token hash.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user owning the token.
+/// * `family_id` - The family the token belongs to (one family per login).
+/// * `token_hash` - The SHA-256 hash of the opaque token.
+/// * `ttl` - The lifetime of the token in seconds.
+///
+/// # Returns
+///
+/// * `Result<RefreshTokenModel>` - The newly inserted token.
+pub async fn insert_refresh_token(
+    pool: &PgPool,
+    user_id: i32,
+    family_id: &str,
+    token_hash: &str,
+    ttl: i64,
+) -> Result<RefreshTokenModel> {
+    let result = sqlx::query_as::<_, RefreshTokenModel>(
+        r#"
+        INSERT INTO refresh_tokens (user_id, family_id, token_hash, expires_at)
+        VALUES ($1, $2, $3, CURRENT_TIMESTAMP + make_interval(secs => $4))
+        RETURNING *
+        "#,
+    )
+    .bind(user_id)
+    .bind(family_id)
+    .bind(token_hash)
+    .bind(ttl as f64)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a refresh token by its hash.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `token_hash` - The SHA-256 hash of the opaque token.
+///
+/// # Returns
+///
+/// * `Result<Option<RefreshTokenModel>>` - The token, or `None` if unknown.
+pub async fn select_refresh_token_by_hash(
+    pool: &PgPool,
+    token_hash: &str,
+) -> Result<Option<RefreshTokenModel>> {
+    let result = sqlx::query_as::<_, RefreshTokenModel>(
+        "SELECT * FROM refresh_tokens WHERE token_hash = $1",
+    )
+    .bind(token_hash)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Atomically revokes a live refresh token and issues its successor in the
+/// same family.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the token being rotated.
+/// * `token_hash` - The SHA-256 hash of the new opaque token.
+/// * `ttl` - The lifetime of the new token in seconds.
+///
+/// # Returns
+///
+/// * `Result<Option<RefreshTokenModel>>` - The new token, or `None` if the
+///   current token was already revoked or has expired.
+pub async fn rotate_refresh_token(
+    pool: &PgPool,
+    id: i32,
+    token_hash: &str,
+    ttl: i64,
+) -> Result<Option<RefreshTokenModel>> {
+    let mut tx = pool.begin().await?;
+
+    let current = sqlx::query_as::<_, RefreshTokenModel>(
+        r#"
+        UPDATE refresh_tokens
+        SET revoked_at = CURRENT_TIMESTAMP
+        WHERE id = $1
+            AND revoked_at IS NULL
+            AND expires_at > CURRENT_TIMESTAMP
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let current = match current {
+        Some(current) => current,
+        None => {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+    };
+
+    let next = sqlx::query_as::<_, RefreshTokenModel>(
+        r#"
+        INSERT INTO refresh_tokens (user_id, family_id, token_hash, expires_at)
+        VALUES ($1, $2, $3, CURRENT_TIMESTAMP + make_interval(secs => $4))
+        RETURNING *
+        "#,
+    )
+    .bind(current.user_id)
+    .bind(&current.family_id)
+    .bind(token_hash)
+    .bind(ttl as f64)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query("UPDATE refresh_tokens SET replaced_by = $1 WHERE id = $2")
+        .bind(next.id)
+        .bind(current.id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(Some(next))
+}
+
+/// Revokes every live token of a family.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `family_id` - The family to revoke.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of tokens revoked.
+pub async fn revoke_refresh_token_family(
+    pool: &PgPool,
+    family_id: &str,
+) -> Result<u64> {
+    let result = sqlx::query(
+        r#"
+        UPDATE refresh_tokens
+        SET revoked_at = CURRENT_TIMESTAMP
+        WHERE family_id = $1 AND revoked_at IS NULL
+        "#,
+    )
+    .bind(family_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Revokes every live token of a user, across all families.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of tokens revoked.
+pub async fn revoke_refresh_tokens_by_user_id(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<u64> {
+    let result = sqlx::query(
+        r#"
+        UPDATE refresh_tokens
+        SET revoked_at = CURRENT_TIMESTAMP
+        WHERE user_id = $1 AND revoked_at IS NULL
+        "#,
+    )
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::roles_permissions_model::RolesPermissionsModel;
+
+use super::{Bind, QueryBuilder};
+
+/// Grants a permission to a role.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `model` - The `RolesPermissionsModel` holding the role and permission IDs.
+///
+/// # Returns
+///
+/// * `Result<RolesPermissionsModel>` - The newly inserted relationship.
+pub async fn insert_role_permission(
+    pool: &PgPool,
+    model: RolesPermissionsModel,
+) -> Result<RolesPermissionsModel> {
+    let result = QueryBuilder::<RolesPermissionsModel>::new(pool)
+        .table("roles_permissions")
+        .fields(&["role_id", "permission_id"])
+        .values(vec![
+            Bind::Int(model.role_id),
+            Bind::Int(model.permission_id),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Revokes role-permission relationships by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the relationships to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted relationships.
+pub async fn delete_role_permission_by_id(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<RolesPermissionsModel>::new(pool)
+        .table("roles_permissions")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
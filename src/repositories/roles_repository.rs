@@ -0,0 +1,153 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::roles_model::{RoleModel, RoleSortField};
+use crate::models::sort_model::Sort;
+
+use super::{Bind, QueryBuilder};
+
+/// Inserts a new role into the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `role_model` - The `RoleModel` instance containing the role data to insert.
+///
+/// # Returns
+///
+/// * `Result<RoleModel>` - The newly inserted `RoleModel` record.
+pub async fn insert_role(
+    pool: &PgPool,
+    role_model: RoleModel,
+) -> Result<RoleModel> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .fields(&["name", "description"])
+        .values(vec![
+            Bind::Text(role_model.name),
+            role_model.description.map_or(Bind::Null, Bind::Text),
+        ])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Updates an existing role in the database by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the role to update.
+/// * `role_model` - The `RoleModel` instance containing the updated role data.
+///
+/// # Returns
+///
+/// * `Result<RoleModel>` - The updated `RoleModel` record.
+pub async fn update_role(
+    pool: &PgPool,
+    id: i32,
+    role_model: RoleModel,
+) -> Result<RoleModel> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .fields(&["name", "description"])
+        .values(vec![
+            Bind::Text(role_model.name),
+            role_model.description.map_or(Bind::Null, Bind::Text),
+        ])
+        .update("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a paginated list of roles from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `limit` - The maximum number of roles to retrieve.
+/// * `offset` - The number of roles to skip.
+/// * `sort` - The fields to sort by.
+///
+/// # Returns
+///
+/// * `Result<Vec<RoleModel>>` - A vector containing the retrieved `RoleModel` records.
+pub async fn select_roles(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+    sort: &Sort<RoleSortField>,
+) -> Result<Vec<RoleModel>> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .limit(limit)
+        .offset(offset)
+        .sort(sort)
+        .fields(&["id", "name", "description", "date_created"])
+        .select(None, None)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves a role by its ID from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the role to retrieve.
+///
+/// # Returns
+///
+/// * `Result<RoleModel>` - The `RoleModel` record for the specified ID.
+pub async fn select_role_by_id(pool: &PgPool, id: i32) -> Result<RoleModel> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .fields(&["id", "name", "description", "date_created"])
+        .select_one("id", Bind::Int(id))
+        .await?;
+
+    Ok(result)
+}
+
+/// Deletes roles by their IDs from the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the roles to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted roles.
+pub async fn delete_role_by_id(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
+
+/// Counts the total number of roles in the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+///
+/// # Returns
+///
+/// * `Result<i64>` - The total number of roles.
+pub async fn count_roles(pool: &PgPool) -> Result<i64> {
+    let result = QueryBuilder::<RoleModel>::new(pool)
+        .table("roles")
+        .count()
+        .await?;
+
+    Ok(result)
+}
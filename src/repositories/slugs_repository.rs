@@ -0,0 +1,175 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool};
+
+use crate::models::slugs_model::SlugType;
+
+/// The length of the `slug` columns.
+const MAX_SLUG_LENGTH: usize = 200;
+
+/// The number of candidate slugs looked up at once.
+const SLUGS_PER_QUERY: usize = 20;
+
+/// Serializes the allocation of slugs of a content type until the end of
+/// the transaction, so that two allocations cannot pick the same slug.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the slug is allocated in.
+/// * `slug_type` - The content type of the slug.
+pub async fn lock_slugs(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+) -> Result<()> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+        .bind(format!("{}.slug", slug_type.table()))
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Finds the first slug among `base`, `base-2`, `base-3`… that no other
+/// row of the content type uses, shortening `base` to fit the suffix.
+///
+/// # Arguments
+///
+/// * `conn` - The connection to query.
+/// * `slug_type` - The content type of the slug.
+/// * `base` - The wanted slug.
+/// * `exclude_id` - The ID of a row whose slug is free, the one being
+///   updated.
+///
+/// # Returns
+///
+/// * `Result<String>` - The available slug.
+pub async fn select_available_slug(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+    base: &str,
+    exclude_id: Option<i32>,
+) -> Result<String> {
+    let query = format!(
+        r#"
+        SELECT slug FROM {}
+        WHERE slug = ANY($1) AND ($2::INTEGER IS NULL OR id <> $2)
+        "#,
+        slug_type.table()
+    );
+
+    let mut first = 1;
+    loop {
+        let candidates: Vec<String> = (first..first + SLUGS_PER_QUERY)
+            .map(|position| candidate_slug(base, position))
+            .collect();
+        let taken = sqlx::query_scalar::<_, String>(&query)
+            .bind(&candidates)
+            .bind(exclude_id)
+            .fetch_all(&mut *conn)
+            .await?;
+
+        if let Some(slug) =
+            candidates.into_iter().find(|slug| !taken.contains(slug))
+        {
+            return Ok(slug);
+        }
+        first += SLUGS_PER_QUERY;
+    }
+}
+
+/// The slug at the given position, `base` then `base-2`, `base-3`…
+fn candidate_slug(base: &str, position: usize) -> String {
+    if position == 1 {
+        return String::from(base);
+    }
+
+    let suffix = format!("-{}", position);
+    let mut end = base.len().min(MAX_SLUG_LENGTH - suffix.len());
+    while !base.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!("{}{}", &base[..end], suffix)
+}
+
+/// Records the current slug of a content as a previous one if it is about
+/// to change, to be saved in the same transaction as the new slug.
+///
+/// The new slug stops redirecting elsewhere, the live slug of a content
+/// taking precedence over the history of the others.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the content is saved in.
+/// * `slug_type` - The content type of the slug.
+/// * `id` - The ID of the content being updated.
+/// * `new_slug` - The slug the content is saved with.
+pub async fn insert_previous_slug(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+    id: i32,
+    new_slug: &str,
+) -> Result<()> {
+    let query = format!(
+        r#"
+        WITH revived AS (
+            DELETE FROM slug_redirects WHERE slug_type = $1 AND slug = $3
+        )
+        INSERT INTO slug_redirects (slug_type, slug, target_id)
+        SELECT $1, slug, id FROM {} WHERE id = $2 AND slug <> $3
+        ON CONFLICT (slug_type, slug) DO UPDATE
+        SET target_id = EXCLUDED.target_id, date_created = CURRENT_TIMESTAMP
+        "#,
+        slug_type.table()
+    );
+
+    sqlx::query(&query)
+        .bind(slug_type)
+        .bind(id)
+        .bind(new_slug)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Finds the content a current or previous slug points to.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `slug_type` - The content type of the slug.
+/// * `slug` - The slug to resolve.
+///
+/// # Returns
+///
+/// * `Result<Option<(i32, String, bool)>>` - The ID and current slug of the
+///   content, and whether `slug` is a previous one.
+pub async fn select_slug_target(
+    pool: &PgPool,
+    slug_type: SlugType,
+    slug: &str,
+) -> Result<Option<(i32, String, bool)>> {
+    let query = format!(
+        r#"
+        SELECT id, slug, FALSE AS redirected FROM {table}
+        WHERE slug = $2 AND {visible}
+        UNION ALL
+        SELECT t.id, t.slug, TRUE AS redirected
+        FROM slug_redirects r
+        JOIN {table} t ON t.id = r.target_id
+        WHERE r.slug_type = $1 AND r.slug = $2 AND {visible}
+        ORDER BY redirected
+        LIMIT 1
+        "#,
+        table = slug_type.table(),
+        visible = slug_type.visible_condition()
+    );
+
+    let target = sqlx::query_as::<_, (i32, String, bool)>(&query)
+        .bind(slug_type)
+        .bind(slug)
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(target)
+}
@@ -1,7 +1,8 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
-use crate::models::tags_model::TagModel;
+use crate::models::sort_model::Sort;
+use crate::models::tags_model::{TagModel, TagSortField};
 
 use super::{Bind, QueryBuilder};
 
@@ -43,7 +44,8 @@ pub async fn insert_tag(
 ///
 /// # Returns
 ///
-/// * `Result<TagModel>` - The updated `TagModel` record.
+/// * `Result<TagModel>` - The updated `TagModel` record, or a `RowNotFound` error
+///   if the row is no longer at the version of the model.
 pub async fn update_tag(
     pool: &PgPool,
     id: i32,
@@ -57,6 +59,7 @@ pub async fn update_tag(
             tag_model.slug.map_or(Bind::Null, Bind::Text),
             tag_model.description.map_or(Bind::Null, Bind::Text),
         ])
+        .version(tag_model.version)
         .update("id", Bind::Int(id))
         .await?;
 
@@ -76,16 +79,21 @@ pub async fn select_tags(
     pool: &PgPool,
     limit: i64,
     offset: i64,
-    sort_column: &str,
-    sort_order: &str,
+    sort: &Sort<TagSortField>,
 ) -> Result<Vec<TagModel>> {
     let result = QueryBuilder::<TagModel>::new(pool)
         .table("tags")
         .limit(limit)
         .offset(offset)
-        .sort_column(sort_column)
-        .sort_order(sort_order)
-        .fields(&["id", "name", "slug", "description", "date_created"])
+        .sort(sort)
+        .fields(&[
+            "id",
+            "name",
+            "slug",
+            "description",
+            "date_created",
+            "version",
+        ])
         .select(None, None)
         .await?;
 
@@ -105,7 +113,14 @@ pub async fn select_tags(
 pub async fn select_tag_by_id(pool: &PgPool, id: i32) -> Result<TagModel> {
     let result = QueryBuilder::<TagModel>::new(pool)
         .table("tags")
-        .fields(&["id", "name", "slug", "description", "date_created"])
+        .fields(&[
+            "id",
+            "name",
+            "slug",
+            "description",
+            "date_created",
+            "version",
+        ])
         .select_one("id", Bind::Int(id))
         .await?;
 
@@ -118,6 +133,8 @@ pub async fn select_tag_by_id(pool: &PgPool, id: i32) -> Result<TagModel> {
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
 /// * `ids` - A vector containing the IDs of the tags to delete.
+/// * `version` - The version of the tag if a single one is deleted
+///   provided it is unchanged, `None` otherwise.
 ///
 /// # Returns
 ///
@@ -125,9 +142,11 @@ pub async fn select_tag_by_id(pool: &PgPool, id: i32) -> Result<TagModel> {
 pub async fn delete_tag_by_id(
     pool: &PgPool,
     ids: Vec<i32>,
+    version: Option<i32>,
 ) -> Result<Vec<i32>> {
     let result = QueryBuilder::<TagModel>::new(pool)
         .table("tags")
+        .version(version)
         .delete("id", ids)
         .await?;
 
@@ -151,3 +170,67 @@ pub async fn count_tags(pool: &PgPool) -> Result<i64> {
 
     Ok(result)
 }
+
+/// Keeps the IDs of existing tags.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is saved in.
+/// * `ids` - The tag IDs to look up.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs among `ids` that belong to a tag.
+pub async fn select_existing_tag_ids(
+    conn: &mut PgConnection,
+    ids: &[i32],
+) -> Result<Vec<i32>> {
+    let result =
+        sqlx::query_scalar::<_, i32>("SELECT id FROM tags WHERE id = ANY($1)")
+            .bind(ids)
+            .fetch_all(conn)
+            .await?;
+
+    Ok(result)
+}
+
+/// Finds the tags with the given slugs, creating the missing ones.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the post is saved in.
+/// * `names` - The names of the tags.
+/// * `slugs` - The slugs of the tags, in the same order as `names`.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the tags, one per distinct slug.
+pub async fn insert_missing_tags(
+    conn: &mut PgConnection,
+    names: &[String],
+    slugs: &[String],
+) -> Result<Vec<i32>> {
+    sqlx::query(
+        r#"
+        INSERT INTO tags (name, slug)
+        SELECT DISTINCT ON (new_tags.slug) new_tags.name, new_tags.slug
+        FROM UNNEST($1::TEXT[], $2::TEXT[]) AS new_tags (name, slug)
+        ON CONFLICT (slug) DO NOTHING
+        "#,
+    )
+    .bind(names)
+    .bind(slugs)
+    .execute(&mut *conn)
+    .await?;
+
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        SELECT id FROM tags WHERE slug = ANY($1) ORDER BY slug
+        "#,
+    )
+    .bind(slugs)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,96 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+/// Replaces the recovery codes of a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+/// * `code_hashes` - The SHA-256 hashes of the new codes.
+pub async fn replace_user_recovery_codes(
+    pool: &PgPool,
+    user_id: i32,
+    code_hashes: &[String],
+) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO user_recovery_codes (user_id, code_hash)
+        SELECT $1, UNNEST($2::CHAR(64)[])
+        "#,
+    )
+    .bind(user_id)
+    .bind(code_hashes)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Marks an unused recovery code of a user as used.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+/// * `code_hash` - The SHA-256 hash of the code.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the code is unknown or already used.
+pub async fn consume_user_recovery_code(
+    pool: &PgPool,
+    user_id: i32,
+    code_hash: &str,
+) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE user_recovery_codes
+        SET used_at = CURRENT_TIMESTAMP
+        WHERE id = (
+            SELECT id FROM user_recovery_codes
+            WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+        )
+        "#,
+    )
+    .bind(user_id)
+    .bind(code_hash)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Deletes every recovery code of a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of deleted codes.
+pub async fn delete_user_recovery_codes(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<u64> {
+    let result =
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(pool)
+            .await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,135 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+/// Stores the hash of a new single-use token.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user the token is issued to.
+/// * `purpose` - What the token may be used for, e.g. `password_reset`.
+/// * `token_hash` - The SHA-256 hash of the opaque token.
+/// * `ttl` - The lifetime of the token in seconds.
+pub async fn insert_user_token(
+    pool: &PgPool,
+    user_id: i32,
+    purpose: &str,
+    token_hash: &str,
+    ttl: i64,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO user_tokens (user_id, purpose, token_hash, expires_at)
+        VALUES ($1, $2, $3, CURRENT_TIMESTAMP + make_interval(secs => $4))
+        "#,
+    )
+    .bind(user_id)
+    .bind(purpose)
+    .bind(token_hash)
+    .bind(ttl as f64)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Looks up a token without using it up.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `purpose` - The purpose the token must have been issued for.
+/// * `token_hash` - The SHA-256 hash of the opaque token.
+///
+/// # Returns
+///
+/// * `Result<Option<i32>>` - The ID of the token's user, or `None` if the
+///   token is unknown, used or expired.
+pub async fn select_user_token_user_id(
+    pool: &PgPool,
+    purpose: &str,
+    token_hash: &str,
+) -> Result<Option<i32>> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        SELECT user_id FROM user_tokens
+        WHERE purpose = $1
+            AND token_hash = $2
+            AND used_at IS NULL
+            AND expires_at > CURRENT_TIMESTAMP
+        "#,
+    )
+    .bind(purpose)
+    .bind(token_hash)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Marks a token as used, provided it has the given purpose, has not been
+/// used yet and has not expired.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `purpose` - The purpose the token must have been issued for.
+/// * `token_hash` - The SHA-256 hash of the opaque token.
+///
+/// # Returns
+///
+/// * `Result<Option<i32>>` - The ID of the token's user, or `None` if the
+///   token cannot be used.
+pub async fn consume_user_token(
+    pool: &PgPool,
+    purpose: &str,
+    token_hash: &str,
+) -> Result<Option<i32>> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        UPDATE user_tokens
+        SET used_at = CURRENT_TIMESTAMP
+        WHERE purpose = $1
+            AND token_hash = $2
+            AND used_at IS NULL
+            AND expires_at > CURRENT_TIMESTAMP
+        RETURNING user_id
+        "#,
+    )
+    .bind(purpose)
+    .bind(token_hash)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Deletes the unused tokens of a user for the given purpose.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+/// * `purpose` - The purpose of the tokens to delete.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of deleted tokens.
+pub async fn delete_unused_user_tokens(
+    pool: &PgPool,
+    user_id: i32,
+    purpose: &str,
+) -> Result<u64> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM user_tokens
+        WHERE user_id = $1 AND purpose = $2 AND used_at IS NULL
+        "#,
+    )
+    .bind(user_id)
+    .bind(purpose)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::user_totp_model::UserTotpModel;
+
+/// Retrieves the TOTP enrolment of a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+///
+/// # Returns
+///
+/// * `Result<Option<UserTotpModel>>` - The enrolment, confirmed or not, or
+///   `None` if the user never started one.
+pub async fn select_user_totp(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Option<UserTotpModel>> {
+    let result = sqlx::query_as::<_, UserTotpModel>(
+        "SELECT * FROM user_totp WHERE user_id = $1",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Starts a TOTP enrolment, replacing a previous unconfirmed one.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+/// * `secret` - The base32 encoded shared secret.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the user already has a confirmed enrolment,
+///   which is left untouched.
+pub async fn upsert_pending_user_totp(
+    pool: &PgPool,
+    user_id: i32,
+    secret: &str,
+) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO user_totp (user_id, secret)
+        VALUES ($1, $2)
+        ON CONFLICT (user_id) DO UPDATE
+        SET secret = EXCLUDED.secret,
+            last_used_step = NULL,
+            date_created = CURRENT_TIMESTAMP
+        WHERE user_totp.confirmed_at IS NULL
+        "#,
+    )
+    .bind(user_id)
+    .bind(secret)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Confirms the enrolment of a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+pub async fn confirm_user_totp(pool: &PgPool, user_id: i32) -> Result<()> {
+    sqlx::query(
+        "UPDATE user_totp SET confirmed_at = CURRENT_TIMESTAMP WHERE user_id = $1",
+    )
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Records the time step of an accepted code, unless a code of the same or a
+/// later step was already accepted.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+/// * `step` - The time step the accepted code belongs to.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the code is a replay.
+pub async fn update_user_totp_last_used_step(
+    pool: &PgPool,
+    user_id: i32,
+    step: i64,
+) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE user_totp
+        SET last_used_step = $2
+        WHERE user_id = $1
+            AND (last_used_step IS NULL OR last_used_step < $2)
+        "#,
+    )
+    .bind(user_id)
+    .bind(step)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Removes the TOTP enrolment of a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+///
+/// # Returns
+///
+/// * `Result<u64>` - The number of deleted enrolments.
+pub async fn delete_user_totp(pool: &PgPool, user_id: i32) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM user_totp WHERE user_id = $1")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
@@ -1,9 +1,10 @@
 use anyhow::Result;
 use sqlx::PgPool;
 
-use crate::models::users_models::UserModel;
+use crate::models::sort_model::Sort;
+use crate::models::users_models::{UserFilters, UserModel, UserSortField};
 
-use super::{Bind, QueryBuilder};
+use super::{Bind, Filters, QueryBuilder};
 
 /// Inserts a user into the database.
 ///
@@ -43,7 +44,8 @@ pub async fn insert_user(
     Ok(result)
 }
 
-/// Updates a user in the database.
+/// Updates a user in the database. The password is left untouched, see
+/// `update_user_password`.
 ///
 /// # Arguments
 /// * `pool` - Reference to the PgPool pool.
@@ -51,7 +53,8 @@ pub async fn insert_user(
 /// * `user_model` - The user model with the new data.
 ///
 /// # Returns
-/// A `Result` containing the updated user model or an error.
+/// A `Result` containing the updated user model or an error, `RowNotFound`
+/// if the user changed since `user_model` was read.
 pub async fn update_user(
     pool: &PgPool,
     id: i32,
@@ -61,7 +64,6 @@ pub async fn update_user(
         .table("users")
         .fields(&[
             "username",
-            "password",
             "email",
             "firstname",
             "lastname",
@@ -70,43 +72,43 @@ pub async fn update_user(
         ])
         .values(vec![
             Bind::Text(user_model.username),
-            Bind::Text(user_model.password),
             Bind::Text(user_model.email),
             Bind::Text(user_model.firstname),
             Bind::Text(user_model.lastname),
             user_model.url.map_or(Bind::Null, Bind::Text),
-            Bind::Bool(true),
+            Bind::Bool(user_model.active),
         ])
+        .version(user_model.version)
         .update("id", Bind::Int(id))
         .await?;
 
     Ok(result)
 }
 
-/// Selects a list of users with pagination and sorting options.
+/// Selects a list of users matching the filters, with pagination and sorting options.
 ///
 /// # Arguments
 /// * `pool` - Reference to the PgPool pool.
+/// * `filters` - The conditions the users must match.
 /// * `limit` - The number of users to return.
 /// * `offset` - The pagination offset.
-/// * `sort_column` - The column to sort by.
-/// * `sort_order` - The sort order (ASC or DESC).
+/// * `sort` - The fields to sort by.
 ///
 /// # Returns
 /// A `Result` containing a vector of `UserModel` or an error.
 pub async fn select_users(
     pool: &PgPool,
+    filters: &UserFilters,
     limit: i64,
     offset: i64,
-    sort_column: &str,
-    sort_order: &str,
+    sort: &Sort<UserSortField>,
 ) -> Result<Vec<UserModel>> {
     let result = QueryBuilder::<UserModel>::new(pool)
         .table("users")
+        .filters(user_filters(filters))
         .limit(limit)
         .offset(offset)
-        .sort_column(sort_column)
-        .sort_order(sort_order)
+        .sort(sort)
         .fields(&[
             "id",
             "username",
@@ -116,7 +118,9 @@ pub async fn select_users(
             "lastname",
             "url",
             "active",
+            "email_verified_at",
             "date_created",
+            "version",
         ])
         .select(None, None)
         .await?;
@@ -144,7 +148,9 @@ pub async fn select_user_by_id(pool: &PgPool, id: i32) -> Result<UserModel> {
             "lastname",
             "url",
             "active",
+            "email_verified_at",
             "date_created",
+            "version",
         ])
         .select_one("id", Bind::Int(id))
         .await?;
@@ -152,7 +158,7 @@ pub async fn select_user_by_id(pool: &PgPool, id: i32) -> Result<UserModel> {
     Ok(result)
 }
 
-/// Selects a user by email address.
+/// Selects a user by email address, ignoring case.
 ///
 /// # Arguments
 /// * `pool` - Reference to the PgPool pool.
@@ -175,9 +181,11 @@ pub async fn select_user_by_email(
             "lastname",
             "url",
             "active",
+            "email_verified_at",
             "date_created",
+            "version",
         ])
-        .select_one("email", Bind::Text(email.to_string()))
+        .select_one("LOWER(email)", Bind::Text(email.to_lowercase()))
         .await?;
 
     Ok(result)
@@ -188,33 +196,101 @@ pub async fn select_user_by_email(
 /// # Arguments
 /// * `pool` - Reference to the PgPool pool.
 /// * `ids` - A vector containing the IDs of the users to delete.
+/// * `version` - The version of the user if a single one is deleted provided
+///   it is unchanged, `None` otherwise.
 ///
 /// # Returns
 /// A `Result` containing a vector of deleted IDs or an error.
 pub async fn delete_user_by_id(
     pool: &PgPool,
     ids: Vec<i32>,
+    version: Option<i32>,
 ) -> Result<Vec<i32>> {
     let result = QueryBuilder::<UserModel>::new(pool)
         .table("users")
+        .version(version)
         .delete("id", ids)
         .await?;
 
     Ok(result)
 }
 
-/// Counts the total number of users.
+/// Counts the users matching the filters.
 ///
 /// # Arguments
 /// * `pool` - Reference to the PgPool pool.
+/// * `filters` - The conditions the users must match.
 ///
 /// # Returns
-/// A `Result` containing the total user count or an error.
-pub async fn count_users(pool: &PgPool) -> Result<i64> {
+/// A `Result` containing the user count or an error.
+pub async fn count_users(pool: &PgPool, filters: &UserFilters) -> Result<i64> {
     let result = QueryBuilder::<UserModel>::new(pool)
         .table("users")
+        .filters(user_filters(filters))
         .count()
         .await?;
 
     Ok(result)
 }
+
+/// Builds the conditions of a list of users.
+fn user_filters(user_filters: &UserFilters) -> Filters {
+    let mut filters = Filters::default();
+    filters.push_some("active = {}", user_filters.active, Bind::Bool);
+
+    filters
+}
+
+/// Replaces the password hash of a user.
+///
+/// # Arguments
+/// * `pool` - Reference to the PgPool pool.
+/// * `id` - The ID of the user.
+/// * `password_hash` - The new argon2 password hash.
+///
+/// # Returns
+/// A `Result` indicating success or failure.
+pub async fn update_user_password(
+    pool: &PgPool,
+    id: i32,
+    password_hash: &str,
+) -> Result<()> {
+    sqlx::query("UPDATE users SET password = $1 WHERE id = $2")
+        .bind(password_hash)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Sets or clears the date a user proved ownership of their email address.
+///
+/// # Arguments
+/// * `pool` - Reference to the PgPool pool.
+/// * `id` - The ID of the user.
+/// * `verified` - Whether the current email address is verified.
+///
+/// # Returns
+/// A `Result` containing the new version of the user or an error.
+pub async fn update_user_email_verified(
+    pool: &PgPool,
+    id: i32,
+    verified: bool,
+) -> Result<i32> {
+    let result = sqlx::query_scalar::<_, i32>(
+        r#"
+        UPDATE users
+        SET email_verified_at = CASE WHEN $1 THEN CURRENT_TIMESTAMP END,
+            version = version + 1
+        WHERE id = $2
+        RETURNING version
+        "#,
+    )
+    .bind(verified)
+    .bind(id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(result)
+}
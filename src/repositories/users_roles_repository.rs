@@ -0,0 +1,113 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::models::users_roles_model::UsersRolesModel;
+
+use super::{Bind, QueryBuilder};
+
+/// Assigns a role to a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `model` - The `UsersRolesModel` holding the user and role IDs.
+///
+/// # Returns
+///
+/// * `Result<UsersRolesModel>` - The newly inserted relationship.
+pub async fn insert_user_role(
+    pool: &PgPool,
+    model: UsersRolesModel,
+) -> Result<UsersRolesModel> {
+    let result = QueryBuilder::<UsersRolesModel>::new(pool)
+        .table("users_roles")
+        .fields(&["user_id", "role_id"])
+        .values(vec![Bind::Int(model.user_id), Bind::Int(model.role_id)])
+        .insert()
+        .await?;
+
+    Ok(result)
+}
+
+/// Removes user-role relationships by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `ids` - A vector containing the IDs of the relationships to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted relationships.
+pub async fn delete_user_role_by_id(
+    pool: &PgPool,
+    ids: Vec<i32>,
+) -> Result<Vec<i32>> {
+    let result = QueryBuilder::<UsersRolesModel>::new(pool)
+        .table("users_roles")
+        .delete("id", ids)
+        .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the names of the roles assigned to a user.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+///
+/// # Returns
+///
+/// * `Result<Vec<String>>` - The role names, sorted alphabetically.
+pub async fn select_role_names_by_user_id(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Vec<String>> {
+    let result = sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT r.name
+        FROM users_roles ur
+            INNER JOIN roles r ON r.id = ur.role_id
+        WHERE ur.user_id = $1
+        ORDER BY r.name
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
+
+/// Retrieves the names of every permission granted to a user through their roles.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `user_id` - The ID of the user.
+///
+/// # Returns
+///
+/// * `Result<Vec<String>>` - The distinct permission names, sorted alphabetically.
+pub async fn select_permission_names_by_user_id(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Vec<String>> {
+    let result = sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT DISTINCT p.name
+        FROM users_roles ur
+            INNER JOIN roles_permissions rp ON rp.role_id = ur.role_id
+            INNER JOIN permissions p ON p.id = rp.permission_id
+        WHERE ur.user_id = $1
+        ORDER BY p.name
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(result)
+}
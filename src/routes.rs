@@ -2,7 +2,22 @@ use ntex::web;
 
 use crate::{
     controllers::{
-        auth::login_controller::login_controller,
+        api_keys::{
+            create_api_key_controller::create_api_key_controller,
+            get_api_keys_controller::get_api_keys_controller,
+            revoke_api_key_controller::revoke_api_key_controller,
+        },
+        auth::{
+            forgot_password_controller::forgot_password_controller,
+            jwks_controller::jwks_controller,
+            login_controller::login_controller,
+            login_two_factor_controller::login_two_factor_controller,
+            logout_all_controller::logout_all_controller,
+            logout_controller::logout_controller,
+            refresh_controller::refresh_controller,
+            reset_password_controller::reset_password_controller,
+            verify_email_controller::verify_email_controller,
+        },
         categories::{
             create_category_controller::create_category_controller,
             delete_category_controller::delete_category_controller,
@@ -10,44 +25,137 @@ use crate::{
             get_category_by_id_controller::get_category_by_id_controller,
             update_category_controller::update_category_controller,
         },
+        permissions::{
+            create_permission_controller::create_permission_controller,
+            delete_permission_controller::delete_permission_controller,
+            get_all_permissions_controller::get_all_permissions_controller,
+            get_permission_by_id_controller::get_permission_by_id_controller,
+            update_permission_controller::update_permission_controller,
+        },
+        post_drafts::{
+            delete_post_draft_controller::delete_post_draft_controller,
+            get_post_draft_controller::get_post_draft_controller,
+            publish_post_draft_controller::publish_post_draft_controller,
+            save_post_draft_controller::save_post_draft_controller,
+        },
+        post_revisions::{
+            get_post_revision_controller::get_post_revision_controller,
+            get_post_revision_diff_controller::get_post_revision_diff_controller,
+            get_post_revisions_controller::get_post_revisions_controller,
+            restore_post_revision_controller::restore_post_revision_controller,
+        },
+        post_trash::{
+            get_trashed_posts_controller::get_trashed_posts_controller,
+            purge_trashed_posts_controller::purge_trashed_posts_controller,
+            restore_trashed_posts_controller::restore_trashed_posts_controller,
+        },
         posts::{
             create_post_controller::create_post_controller,
             delete_post_controller::delete_post_controller,
             get_all_posts_controller::get_all_posts_controller,
             get_post_by_id_controller::get_post_by_id_controller,
+            publish_post_controller::publish_post_controller,
+            schedule_post_controller::schedule_post_controller,
+            submit_post_controller::submit_post_controller,
+            unpublish_post_controller::unpublish_post_controller,
             update_post_controller::update_post_controller,
         },
         posts_categories::create_posts_categories_controller::create_posts_categories_controller,
+        profile::{
+            change_password_controller::change_password_controller,
+            get_profile_controller::get_profile_controller,
+            update_profile_controller::update_profile_controller,
+        },
+        public_posts::{
+            get_public_post_by_id_controller::get_public_post_by_id_controller,
+            get_public_post_by_slug_controller::get_public_post_by_slug_controller,
+            get_public_posts_controller::get_public_posts_controller,
+        },
+        redirects::{
+            create_redirect_controller::create_redirect_controller,
+            delete_redirect_controller::delete_redirect_controller,
+            follow_redirect_controller::follow_redirect_controller,
+            get_all_redirects_controller::get_all_redirects_controller,
+            get_redirect_by_id_controller::get_redirect_by_id_controller,
+            update_redirect_controller::update_redirect_controller,
+        },
+        roles::{
+            create_role_controller::create_role_controller,
+            delete_role_controller::delete_role_controller,
+            get_all_roles_controller::get_all_roles_controller,
+            get_role_by_id_controller::get_role_by_id_controller,
+            update_role_controller::update_role_controller,
+        },
+        roles_permissions::{
+            create_roles_permissions_controller::create_roles_permissions_controller,
+            delete_roles_permissions_controller::delete_roles_permissions_controller,
+        },
+        scheduler::get_next_runs_controller::get_next_runs_controller,
+        slugs::{
+            check_slug_controller::check_slug_controller,
+            resolve_slug_controller::resolve_slug_controller,
+        },
         tags::{
             create_tag_controller::create_tag_controller,
             delete_tag_controller::delete_tag_controller,
             get_all_tags_controller::get_all_tags_controller,
             get_tag_by_id_controller::get_tag_by_id_controller,
+            get_tag_posts_controller::get_tag_posts_controller,
             update_tag_controller::update_tag_controller,
         },
+        two_factor::{
+            confirm_totp_controller::confirm_totp_controller,
+            disable_totp_controller::disable_totp_controller,
+            enroll_totp_controller::enroll_totp_controller,
+        },
         users::{
             create_user_controller::create_user_controller,
             delete_user_controller::delete_user_controller,
+            get_all_users_controller::get_all_users_controller,
             get_user_by_id_controller::get_user_by_id_controller,
+            unlock_user_controller::unlock_user_controller,
             update_user_controller::update_user_controller,
         },
+        users_roles::{
+            create_users_roles_controller::create_users_roles_controller,
+            delete_users_roles_controller::delete_users_roles_controller,
+        },
     },
-    middlewares::auth_middleware::JwtGuard,
+    middlewares::auth_middleware::JwtAuth,
 };
 
 pub fn init(cfg: &mut web::ServiceConfig) {
+    // JSON Web Key Set, outside of the versioned API
+    cfg.service(jwks_controller);
+
     cfg.service(
         web::scope("/api/v1")
             // Public routes
             .service(login_controller)
-            // JWT routes
+            .service(login_two_factor_controller)
+            .service(refresh_controller)
+            .service(logout_controller)
+            .service(logout_all_controller)
+            .service(forgot_password_controller)
+            .service(reset_password_controller)
+            .service(verify_email_controller)
+            .service(follow_redirect_controller)
+            // Read-only content for anonymous readers
+            .service(
+                web::scope("/public")
+                    .service(get_public_posts_controller)
+                    .service(get_public_post_by_id_controller)
+                    .service(get_public_post_by_slug_controller),
+            )
+            // Routes requiring a JWT or an API key
             .service(
                 web::scope("/")
-                    .guard(JwtGuard)
+                    .wrap(JwtAuth)
                     // Tag Controllers
                     .service(create_tag_controller)
                     .service(get_all_tags_controller)
                     .service(get_tag_by_id_controller)
+                    .service(get_tag_posts_controller)
                     .service(update_tag_controller)
                     .service(delete_tag_controller)
                     // Category Controllers
@@ -56,19 +164,80 @@ pub fn init(cfg: &mut web::ServiceConfig) {
                     .service(get_category_by_id_controller)
                     .service(update_category_controller)
                     .service(delete_category_controller)
+                    // Post Trash Controllers, ahead of `/posts/{id}`
+                    .service(get_trashed_posts_controller)
+                    .service(restore_trashed_posts_controller)
+                    .service(purge_trashed_posts_controller)
                     // Post Controllers
                     .service(create_post_controller)
                     .service(get_all_posts_controller)
                     .service(get_post_by_id_controller)
                     .service(update_post_controller)
                     .service(delete_post_controller)
+                    .service(submit_post_controller)
+                    .service(publish_post_controller)
+                    .service(schedule_post_controller)
+                    .service(unpublish_post_controller)
+                    // Post Draft Controllers
+                    .service(get_post_draft_controller)
+                    .service(save_post_draft_controller)
+                    .service(delete_post_draft_controller)
+                    .service(publish_post_draft_controller)
+                    // Post Revision Controllers
+                    .service(get_post_revisions_controller)
+                    .service(get_post_revision_controller)
+                    .service(get_post_revision_diff_controller)
+                    .service(restore_post_revision_controller)
+                    // Scheduler Controllers
+                    .service(get_next_runs_controller)
+                    // Slug Controllers
+                    .service(check_slug_controller)
+                    .service(resolve_slug_controller)
+                    // Redirect Controllers
+                    .service(create_redirect_controller)
+                    .service(get_all_redirects_controller)
+                    .service(get_redirect_by_id_controller)
+                    .service(update_redirect_controller)
+                    .service(delete_redirect_controller)
                     // User Controllers
                     .service(create_user_controller)
+                    .service(get_all_users_controller)
                     .service(get_user_by_id_controller)
                     .service(update_user_controller)
                     .service(delete_user_controller)
+                    .service(unlock_user_controller)
+                    // Profile Controllers
+                    .service(get_profile_controller)
+                    .service(update_profile_controller)
+                    .service(change_password_controller)
+                    // Two-Factor Authentication Controllers
+                    .service(enroll_totp_controller)
+                    .service(confirm_totp_controller)
+                    .service(disable_totp_controller)
+                    // API Key Controllers
+                    .service(create_api_key_controller)
+                    .service(get_api_keys_controller)
+                    .service(revoke_api_key_controller)
                     // Post-Category Relationship Controller
-                    .service(create_posts_categories_controller),
+                    .service(create_posts_categories_controller)
+                    // Role Controllers
+                    .service(create_role_controller)
+                    .service(get_all_roles_controller)
+                    .service(get_role_by_id_controller)
+                    .service(update_role_controller)
+                    .service(delete_role_controller)
+                    // Permission Controllers
+                    .service(create_permission_controller)
+                    .service(get_all_permissions_controller)
+                    .service(get_permission_by_id_controller)
+                    .service(update_permission_controller)
+                    .service(delete_permission_controller)
+                    // User-Role Relationship Controllers
+                    .service(create_users_roles_controller)
+                    .service(delete_users_roles_controller)
+                    // Role-Permission Relationship Controllers
+                    .service(create_roles_permissions_controller)
+                    .service(delete_roles_permissions_controller),
             ),
     );
 }
@@ -0,0 +1,179 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::{
+    dtos::{
+        api_key_dtos::{ApiKeyDTO, CreateApiKeyDTO, CreatedApiKeyDTO},
+        auth_dtos::ClaimsDTO,
+    },
+    repositories::{
+        api_keys_repository::{
+            insert_api_key, revoke_api_key, select_api_key_by_prefix,
+            select_api_keys_by_user_id, update_api_key_last_used,
+        },
+        users_repository::select_user_by_id,
+        users_roles_repository::select_permission_names_by_user_id,
+    },
+};
+
+use super::auth_service::{generate_random_hex, hash_token};
+
+/// Prefix telling API keys apart from JWTs in the `Authorization` header.
+pub const API_KEY_PREFIX: &str = "rcms_";
+const API_KEY_PREFIX_BYTES: usize = 6;
+const API_KEY_SECRET_BYTES: usize = 32;
+
+/// Outcome of the authentication of a request with an API key.
+pub enum ApiKeyAuthentication {
+    Valid { api_key_id: i32, claims: ClaimsDTO },
+    Expired,
+    Invalid,
+    InactiveUser,
+}
+
+/// Creates an API key for the user.
+///
+/// The key is made of a public prefix, used to look it up, and a secret of
+/// which only the hash is stored.
+///
+/// # Returns
+///
+/// * `Result<CreatedApiKeyDTO>` - The key, whose secret cannot be retrieved
+///   again.
+pub async fn create_api_key_service(
+    pool: &PgPool,
+    user_id: i32,
+    request: CreateApiKeyDTO,
+) -> Result<CreatedApiKeyDTO> {
+    request.validate()?;
+
+    let mut scopes = request.scopes;
+    scopes.sort();
+    scopes.dedup();
+
+    let mut errors = ValidationErrors::new();
+    let permissions = select_permission_names_by_user_id(pool, user_id).await?;
+    if let Some(scope) =
+        scopes.iter().find(|scope| !permissions.contains(scope))
+    {
+        let mut error = ValidationError::new("unknown_scope");
+        error.message = Some(
+            format!("Scope {} is not a permission of the user", scope).into(),
+        );
+        errors.add("scopes", error);
+    }
+    if request
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= chrono::Utc::now().naive_utc())
+    {
+        let mut error = ValidationError::new("expired");
+        error.message = Some("Expiration date must be in the future".into());
+        errors.add("expires_at", error);
+    }
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    let prefix = generate_random_hex(API_KEY_PREFIX_BYTES);
+    let secret = generate_random_hex(API_KEY_SECRET_BYTES);
+    let api_key = insert_api_key(
+        pool,
+        user_id,
+        request.name.trim(),
+        &prefix,
+        &hash_token(&secret),
+        &scopes,
+        request.expires_at,
+    )
+    .await?;
+
+    Ok(CreatedApiKeyDTO {
+        key: format!("{}{}_{}", API_KEY_PREFIX, prefix, secret),
+        api_key: api_key.into(),
+    })
+}
+
+/// Lists the API keys of the user, including revoked and expired ones.
+pub async fn get_api_keys_service(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Vec<ApiKeyDTO>> {
+    let api_keys = select_api_keys_by_user_id(pool, user_id).await?;
+
+    Ok(api_keys.into_iter().map(ApiKeyDTO::from).collect())
+}
+
+/// Revokes an API key of the user.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the user has no such key.
+pub async fn revoke_api_key_service(
+    pool: &PgPool,
+    user_id: i32,
+    id: i32,
+) -> Result<bool> {
+    revoke_api_key(pool, id, user_id).await
+}
+
+/// Authenticates a request with an API key.
+///
+/// The key grants the permissions of its scopes that its user still holds,
+/// so removing a role from the user also narrows their keys.
+pub async fn authenticate_api_key_service(
+    pool: &PgPool,
+    key: &str,
+) -> Result<ApiKeyAuthentication> {
+    let (prefix, secret) = match key
+        .strip_prefix(API_KEY_PREFIX)
+        .and_then(|key| key.split_once('_'))
+    {
+        Some(parts) => parts,
+        None => return Ok(ApiKeyAuthentication::Invalid),
+    };
+
+    let api_key = match select_api_key_by_prefix(pool, prefix).await? {
+        Some(api_key)
+            if api_key.revoked_at.is_none()
+                && api_key.secret_hash == hash_token(secret) =>
+        {
+            api_key
+        }
+        _ => return Ok(ApiKeyAuthentication::Invalid),
+    };
+
+    let now = chrono::Utc::now().naive_utc();
+    if api_key
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= now)
+    {
+        return Ok(ApiKeyAuthentication::Expired);
+    }
+
+    let user = select_user_by_id(pool, api_key.user_id).await?;
+    if !user.active {
+        return Ok(ApiKeyAuthentication::InactiveUser);
+    }
+
+    let permissions = select_permission_names_by_user_id(pool, api_key.user_id)
+        .await?
+        .into_iter()
+        .filter(|permission| api_key.scopes.contains(permission))
+        .collect();
+
+    update_api_key_last_used(pool, api_key.id).await?;
+
+    Ok(ApiKeyAuthentication::Valid {
+        api_key_id: api_key.id,
+        claims: ClaimsDTO {
+            sub: api_key.user_id.to_string(),
+            // Keys without an expiration date never expire
+            exp: api_key.expires_at.map_or(usize::MAX, |expires_at| {
+                expires_at.and_utc().timestamp() as usize
+            }),
+            roles: vec![],
+            permissions,
+        },
+    })
+}
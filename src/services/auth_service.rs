@@ -1,66 +1,654 @@
+use std::sync::OnceLock;
+
 use anyhow::{anyhow, Result};
-use argon2::{self, Argon2, PasswordHash, PasswordVerifier};
-use jsonwebtoken::{encode, EncodingKey, Header};
+use argon2::{
+    self,
+    password_hash::{PasswordHasher, SaltString},
+    Argon2, PasswordHash, PasswordVerifier,
+};
+use log::warn;
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
 use sqlx::PgPool;
+use validator::Validate;
 
 use crate::{
-    config::config::get_secret_key,
-    dtos::auth_dtos::{ClaimsDTO, TokenDTO},
-    repositories::users_repository::select_user_by_email,
+    config::{
+        config::{get_access_token_ttl, get_app_url, get_refresh_token_ttl},
+        jwt_keys::jwt_keys,
+    },
+    dtos::auth_dtos::{
+        ChangePasswordRequestDTO, ClaimsDTO, ForgotPasswordRequestDTO,
+        ResetPasswordRequestDTO, TokenDTO, TwoFactorLoginRequestDTO,
+        VerifyEmailRequestDTO,
+    },
+    mailers::{Email, Mailer},
+    repositories::{
+        login_throttles_repository::{
+            delete_login_throttle, lock_login, record_login_failure,
+            select_login_retry_after,
+        },
+        refresh_tokens_repository::{
+            insert_refresh_token, revoke_refresh_token_family,
+            revoke_refresh_tokens_by_user_id, rotate_refresh_token,
+            select_refresh_token_by_hash,
+        },
+        user_tokens_repository::{
+            consume_user_token, delete_unused_user_tokens, insert_user_token,
+            select_user_token_user_id,
+        },
+        users_repository::{
+            select_user_by_email, select_user_by_id,
+            update_user_email_verified, update_user_password,
+        },
+        users_roles_repository::{
+            select_permission_names_by_user_id, select_role_names_by_user_id,
+        },
+    },
 };
 
+use super::two_factor_service::{is_two_factor_enabled, verify_second_factor};
+
+const ACCOUNT_SCOPE: &str = "account";
+const IP_SCOPE: &str = "ip";
+const ACCOUNT_FREE_ATTEMPTS: i32 = 5;
+const IP_FREE_ATTEMPTS: i32 = 20;
+const LOCKOUT_BASE_SECONDS: i64 = 30;
+const LOCKOUT_MAX_SECONDS: i64 = 900;
+const FAILURE_WINDOW_SECONDS: i64 = 3600;
+const PASSWORD_RESET_PURPOSE: &str = "password_reset";
+const EMAIL_VERIFICATION_PURPOSE: &str = "email_verification";
+const PASSWORD_RESET_TTL_SECONDS: i64 = 3600;
+const EMAIL_VERIFICATION_TTL_SECONDS: i64 = 172800;
+const LOGIN_CHALLENGE_PURPOSE: &str = "login_challenge";
+const LOGIN_CHALLENGE_TTL_SECONDS: i64 = 300;
+
+static DUMMY_PASSWORD_HASH: OnceLock<String> = OnceLock::new();
+
+/// Outcome of a login attempt.
+pub enum LoginResult {
+    Success(TokenDTO),
+    /// The password is right, the login must be completed with a second
+    /// factor through `login_two_factor_service`.
+    TwoFactorRequired {
+        challenge_token: String,
+        expires_in: i64,
+    },
+    InvalidCredentials,
+    Locked {
+        retry_after: i64,
+    },
+}
+
+/// The form of an email that accounts are looked up by and that their failed
+/// logins are counted under, whatever the case the user typed it in.
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Authenticates a user by email and password.
+///
+/// Failed attempts are counted per account and per IP address. Once the free
+/// attempts are used up, further logins are refused for an exponentially
+/// growing delay. Unknown emails, wrong passwords and inactive accounts all
+/// yield `InvalidCredentials` after the same amount of hashing work, so the
+/// response does not reveal whether an account exists.
+///
+/// For accounts with two-factor authentication, the failed attempts are only
+/// cleared once the second step succeeds, so that knowing the password does
+/// not allow unlimited guesses of the code.
 pub async fn login_service(
     pool: &PgPool,
     email: &str,
     password: &str,
+    ip: &str,
+) -> Result<LoginResult> {
+    let account = normalize_email(email);
+
+    if let Some(retry_after) =
+        select_login_retry_after(pool, &account, ip).await?
+    {
+        return Ok(LoginResult::Locked { retry_after });
+    }
+
+    let user = match select_user_by_email(pool, &account).await {
+        Ok(user) => Some(user),
+        Err(e) => match e.downcast_ref::<sqlx::Error>() {
+            Some(sqlx::Error::RowNotFound) => None,
+            _ => return Err(e),
+        },
+    };
+
+    let password_hash = match &user {
+        Some(user) => user.password.as_str(),
+        None => dummy_password_hash(),
+    };
+    let parsed_hash = PasswordHash::new(password_hash)
+        .map_err(|e| anyhow!("Failed to parse password hash: {}", e))?;
+    let verified = Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .is_ok();
+
+    let user = match user {
+        Some(user) if verified && user.active => user,
+        _ => {
+            record_failure(
+                pool,
+                ACCOUNT_SCOPE,
+                &account,
+                ACCOUNT_FREE_ATTEMPTS,
+            )
+            .await?;
+            record_failure(pool, IP_SCOPE, ip, IP_FREE_ATTEMPTS).await?;
+            return Ok(LoginResult::InvalidCredentials);
+        }
+    };
+
+    let user_id = user.id.expect("L'utilisateur doit avoir un ID");
+
+    if is_two_factor_enabled(pool, user_id).await? {
+        let challenge_token = issue_user_token(
+            pool,
+            user_id,
+            LOGIN_CHALLENGE_PURPOSE,
+            LOGIN_CHALLENGE_TTL_SECONDS,
+        )
+        .await?;
+
+        return Ok(LoginResult::TwoFactorRequired {
+            challenge_token,
+            expires_in: LOGIN_CHALLENGE_TTL_SECONDS,
+        });
+    }
+
+    delete_login_throttle(pool, ACCOUNT_SCOPE, &account).await?;
+
+    Ok(LoginResult::Success(start_session(pool, user_id).await?))
+}
+
+/// Completes a login started by `login_service` with a code from the
+/// authenticator app or a recovery code.
+///
+/// Wrong codes count as failed logins. The challenge token stays valid until
+/// it expires or a code is accepted, so a typo does not require entering the
+/// password again.
+pub async fn login_two_factor_service(
+    pool: &PgPool,
+    request: TwoFactorLoginRequestDTO,
+    ip: &str,
+) -> Result<LoginResult> {
+    request.validate()?;
+
+    let challenge_hash = hash_token(&request.challenge_token);
+    let user_id = match select_user_token_user_id(
+        pool,
+        LOGIN_CHALLENGE_PURPOSE,
+        &challenge_hash,
+    )
+    .await?
+    {
+        Some(user_id) => user_id,
+        None => return Ok(LoginResult::InvalidCredentials),
+    };
+
+    let user = select_user_by_id(pool, user_id).await?;
+    let account = normalize_email(&user.email);
+
+    if let Some(retry_after) =
+        select_login_retry_after(pool, &account, ip).await?
+    {
+        return Ok(LoginResult::Locked { retry_after });
+    }
+
+    if !user.active
+        || !verify_second_factor(pool, user_id, &request.code).await?
+    {
+        record_failure(pool, ACCOUNT_SCOPE, &account, ACCOUNT_FREE_ATTEMPTS)
+            .await?;
+        record_failure(pool, IP_SCOPE, ip, IP_FREE_ATTEMPTS).await?;
+        return Ok(LoginResult::InvalidCredentials);
+    }
+
+    // Guards against the same challenge being completed twice concurrently
+    if consume_user_token(pool, LOGIN_CHALLENGE_PURPOSE, &challenge_hash)
+        .await?
+        .is_none()
+    {
+        return Ok(LoginResult::InvalidCredentials);
+    }
+
+    delete_login_throttle(pool, ACCOUNT_SCOPE, &account).await?;
+
+    Ok(LoginResult::Success(start_session(pool, user_id).await?))
+}
+
+/// Clears the failed login attempts and the lock of a user account.
+pub async fn unlock_user_service(pool: &PgPool, id: i32) -> Result<()> {
+    let user = select_user_by_id(pool, id).await?;
+
+    delete_login_throttle(pool, ACCOUNT_SCOPE, &normalize_email(&user.email))
+        .await?;
+
+    Ok(())
+}
+
+/// Exchanges a refresh token for a new access token and a new refresh token.
+///
+/// The presented token is revoked and replaced by its successor. Presenting a
+/// token that was already rotated is treated as theft: the whole family is
+/// revoked and the caller must log in again.
+///
+/// # Returns
+///
+/// * `Result<Option<TokenDTO>>` - The new token pair, or `None` if the refresh
+///   token is unknown, revoked, expired or belongs to an inactive user.
+pub async fn refresh_service(
+    pool: &PgPool,
+    refresh_token: &str,
 ) -> Result<Option<TokenDTO>> {
-    let user = match select_user_by_email(pool, email).await {
-        Ok(user) => user,
-        Err(e) => {
-            return Err(anyhow!(
-                "Failed to find user with email {}: {}",
-                email,
-                e
-            ));
+    let current =
+        match select_refresh_token_by_hash(pool, &hash_token(refresh_token))
+            .await?
+        {
+            Some(current) => current,
+            None => return Ok(None),
+        };
+
+    if current.revoked_at.is_some() {
+        if current.replaced_by.is_some() {
+            warn!(
+                "Refresh token reuse detected for user {}, revoking family {}",
+                current.user_id, current.family_id
+            );
         }
+        revoke_refresh_token_family(pool, &current.family_id).await?;
+        return Ok(None);
+    }
+
+    let user = select_user_by_id(pool, current.user_id).await?;
+    if !user.active {
+        revoke_refresh_token_family(pool, &current.family_id).await?;
+        return Ok(None);
+    }
+
+    let next_token = generate_random_hex(32);
+    let rotated = rotate_refresh_token(
+        pool,
+        current.id,
+        &hash_token(&next_token),
+        get_refresh_token_ttl(),
+    )
+    .await?;
+
+    if rotated.is_none() {
+        // Expired, or rotated concurrently by another request
+        revoke_refresh_token_family(pool, &current.family_id).await?;
+        return Ok(None);
+    }
+
+    let token_dto = build_token_dto(pool, current.user_id, next_token).await?;
+
+    Ok(Some(token_dto))
+}
+
+/// Revokes the refresh token family the given token belongs to.
+///
+/// Unknown tokens are ignored so that logging out is idempotent.
+pub async fn logout_service(pool: &PgPool, refresh_token: &str) -> Result<()> {
+    if let Some(current) =
+        select_refresh_token_by_hash(pool, &hash_token(refresh_token)).await?
+    {
+        revoke_refresh_token_family(pool, &current.family_id).await?;
+    }
+
+    Ok(())
+}
+
+/// Revokes every refresh token of the user owning the given token.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the token is unknown, revoked or expired.
+pub async fn logout_all_service(
+    pool: &PgPool,
+    refresh_token: &str,
+) -> Result<bool> {
+    let current =
+        match select_refresh_token_by_hash(pool, &hash_token(refresh_token))
+            .await?
+        {
+            Some(current) => current,
+            None => return Ok(false),
+        };
+
+    if current.revoked_at.is_some()
+        || current.expires_at <= chrono::Utc::now().naive_utc()
+    {
+        return Ok(false);
+    }
+
+    revoke_refresh_tokens_by_user_id(pool, current.user_id).await?;
+
+    Ok(true)
+}
+
+/// Emails a password reset link to the owner of the given address.
+///
+/// Nothing is sent for unknown or inactive accounts, and the caller is not
+/// told either way, so that the endpoint does not reveal which emails are
+/// registered. Requesting a new link invalidates the previous ones.
+pub async fn forgot_password_service(
+    pool: &PgPool,
+    mailer: &dyn Mailer,
+    request: ForgotPasswordRequestDTO,
+) -> Result<()> {
+    request.validate()?;
+
+    let user = match select_user_by_email(
+        pool,
+        &normalize_email(&request.email),
+    )
+    .await
+    {
+        Ok(user) if user.active => user,
+        Ok(_) => return Ok(()),
+        Err(e) => match e.downcast_ref::<sqlx::Error>() {
+            Some(sqlx::Error::RowNotFound) => return Ok(()),
+            _ => return Err(e),
+        },
     };
+    let user_id = user.id.expect("L'utilisateur doit avoir un ID");
+
+    delete_unused_user_tokens(pool, user_id, PASSWORD_RESET_PURPOSE).await?;
+    let token = issue_user_token(
+        pool,
+        user_id,
+        PASSWORD_RESET_PURPOSE,
+        PASSWORD_RESET_TTL_SECONDS,
+    )
+    .await?;
+
+    mailer
+        .send(Email {
+            to: user.email,
+            subject: String::from("Reset your password"),
+            body: format!(
+                "Follow this link within the hour to choose a new password:\n\n{}/reset-password?token={}\n\nIf you did not ask for it, you can ignore this email.",
+                get_app_url(),
+                token
+            ),
+        })
+        .await
+}
+
+/// Sets a new password with a token sent by `forgot_password_service`.
+///
+/// Every session of the user is revoked and a login lock is lifted. Since the
+/// link was delivered to the user's address, the address is also marked as
+/// verified.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the token is unknown, used or expired.
+pub async fn reset_password_service(
+    pool: &PgPool,
+    request: ResetPasswordRequestDTO,
+) -> Result<bool> {
+    request.validate()?;
 
-    let argon2 = Argon2::default();
-    let parsed_hash = match PasswordHash::new(&user.password) {
-        Ok(hash) => hash,
-        Err(e) => return Err(anyhow!("Failed to parse password hash: {}", e)),
+    let user_id = match consume_user_token(
+        pool,
+        PASSWORD_RESET_PURPOSE,
+        &hash_token(&request.token),
+    )
+    .await?
+    {
+        Some(user_id) => user_id,
+        None => return Ok(false),
     };
 
-    match argon2.verify_password(password.as_bytes(), &parsed_hash) {
-        Ok(_) => {
-            let claims = ClaimsDTO {
-                sub: user
-                    .id
-                    .expect("L'utilisateur doit avoir un ID")
-                    .to_string(),
-                exp: chrono::Utc::now()
-                    .checked_add_signed(chrono::Duration::hours(24))
-                    .expect("Erreur lors de la création du token")
-                    .timestamp() as usize,
-            };
-
-            let token = encode(
-                &Header::default(),
-                &claims,
-                &EncodingKey::from_secret(get_secret_key().as_ref()),
-            )
-            .map_err(|e| {
-                anyhow!("Erreur lors de la génération du jeton: {}", e)
-            })?;
+    let user = select_user_by_id(pool, user_id).await?;
+    if !user.active {
+        return Ok(false);
+    }
+
+    update_user_password(pool, user_id, &hash_password(&request.password)?)
+        .await?;
+    if user.email_verified_at.is_none() {
+        update_user_email_verified(pool, user_id, true).await?;
+    }
+    delete_unused_user_tokens(pool, user_id, PASSWORD_RESET_PURPOSE).await?;
+    revoke_refresh_tokens_by_user_id(pool, user_id).await?;
+    delete_login_throttle(pool, ACCOUNT_SCOPE, &normalize_email(&user.email))
+        .await?;
+
+    Ok(true)
+}
+
+/// Changes the password of a user who knows the current one, and signs them
+/// out of every session.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the current password is wrong.
+pub async fn change_password_service(
+    pool: &PgPool,
+    user_id: i32,
+    request: ChangePasswordRequestDTO,
+) -> Result<bool> {
+    request.validate()?;
+
+    let user = select_user_by_id(pool, user_id).await?;
+    let parsed_hash = PasswordHash::new(&user.password)
+        .map_err(|e| anyhow!("Failed to parse password hash: {}", e))?;
+    if Argon2::default()
+        .verify_password(request.current_password.as_bytes(), &parsed_hash)
+        .is_err()
+    {
+        return Ok(false);
+    }
+
+    update_user_password(pool, user_id, &hash_password(&request.new_password)?)
+        .await?;
+    revoke_refresh_tokens_by_user_id(pool, user_id).await?;
+
+    Ok(true)
+}
 
-            let token_dto = TokenDTO {
-                token,
-                token_type: "Bearer".to_string(),
-                expires_in: 3600,
-            };
+/// Emails a link proving ownership of the user's address.
+///
+/// Previous links are invalidated, so only the latest address can be
+/// verified after an email change.
+pub async fn send_verification_email_service(
+    pool: &PgPool,
+    mailer: &dyn Mailer,
+    user_id: i32,
+    email: &str,
+) -> Result<()> {
+    delete_unused_user_tokens(pool, user_id, EMAIL_VERIFICATION_PURPOSE)
+        .await?;
+    let token = issue_user_token(
+        pool,
+        user_id,
+        EMAIL_VERIFICATION_PURPOSE,
+        EMAIL_VERIFICATION_TTL_SECONDS,
+    )
+    .await?;
+
+    mailer
+        .send(Email {
+            to: email.to_string(),
+            subject: String::from("Verify your email address"),
+            body: format!(
+                "Follow this link within 48 hours to verify your email address:\n\n{}/verify-email?token={}",
+                get_app_url(),
+                token
+            ),
+        })
+        .await
+}
 
-            Ok(Some(token_dto))
+/// Marks the address of a user as verified with a token sent by
+/// `send_verification_email_service`.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the token is unknown, used or expired.
+pub async fn verify_email_service(
+    pool: &PgPool,
+    request: VerifyEmailRequestDTO,
+) -> Result<bool> {
+    request.validate()?;
+
+    match consume_user_token(
+        pool,
+        EMAIL_VERIFICATION_PURPOSE,
+        &hash_token(&request.token),
+    )
+    .await?
+    {
+        Some(user_id) => {
+            update_user_email_verified(pool, user_id, true).await?;
+            Ok(true)
         }
-        Err(_) => Ok(None),
+        None => Ok(false),
     }
 }
+
+/// Issues the tokens of a new session. Each login starts a new refresh token
+/// family.
+async fn start_session(pool: &PgPool, user_id: i32) -> Result<TokenDTO> {
+    let family_id = generate_random_hex(16);
+    let refresh_token = generate_random_hex(32);
+    insert_refresh_token(
+        pool,
+        user_id,
+        &family_id,
+        &hash_token(&refresh_token),
+        get_refresh_token_ttl(),
+    )
+    .await?;
+
+    build_token_dto(pool, user_id, refresh_token).await
+}
+
+/// Signs a short-lived access token for the user and pairs it with the
+/// given refresh token.
+async fn build_token_dto(
+    pool: &PgPool,
+    user_id: i32,
+    refresh_token: String,
+) -> Result<TokenDTO> {
+    let roles = select_role_names_by_user_id(pool, user_id).await?;
+    let permissions = select_permission_names_by_user_id(pool, user_id).await?;
+    let access_token_ttl = get_access_token_ttl();
+
+    let claims = ClaimsDTO {
+        sub: user_id.to_string(),
+        exp: chrono::Utc::now()
+            .checked_add_signed(chrono::Duration::seconds(access_token_ttl))
+            .expect("Erreur lors de la création du token")
+            .timestamp() as usize,
+        roles,
+        permissions,
+    };
+
+    let token = jwt_keys()
+        .encode(&claims)
+        .map_err(|e| anyhow!("Erreur lors de la génération du jeton: {}", e))?;
+
+    Ok(TokenDTO {
+        token,
+        token_type: "Bearer".to_string(),
+        expires_in: access_token_ttl as usize,
+        refresh_token,
+        refresh_expires_in: get_refresh_token_ttl() as usize,
+    })
+}
+
+/// Counts a failed login and locks the account or IP address once its free
+/// attempts are used up.
+async fn record_failure(
+    pool: &PgPool,
+    scope: &str,
+    identifier: &str,
+    free_attempts: i32,
+) -> Result<()> {
+    let failures =
+        record_login_failure(pool, scope, identifier, FAILURE_WINDOW_SECONDS)
+            .await?;
+
+    if let Some(duration) = lockout_duration(failures, free_attempts) {
+        warn!(
+            "Locking login for {} {} during {}s after {} failed attempts",
+            scope, identifier, duration, failures
+        );
+        lock_login(pool, scope, identifier, duration).await?;
+    }
+
+    Ok(())
+}
+
+/// Lock duration after the given number of consecutive failures: 30 seconds
+/// once the free attempts are used up, doubling with each further failure,
+/// up to 15 minutes.
+fn lockout_duration(failures: i32, free_attempts: i32) -> Option<i64> {
+    if failures < free_attempts {
+        return None;
+    }
+
+    let exponent = (failures - free_attempts).min(16) as u32;
+    Some((LOCKOUT_BASE_SECONDS << exponent).min(LOCKOUT_MAX_SECONDS))
+}
+
+/// A valid hash of a random password, verified against when the email is
+/// unknown so that the request costs the same as a wrong password.
+fn dummy_password_hash() -> &'static str {
+    DUMMY_PASSWORD_HASH.get_or_init(|| {
+        let salt = SaltString::generate(&mut OsRng);
+        Argon2::default()
+            .hash_password(generate_random_hex(16).as_bytes(), &salt)
+            .expect("Failed to hash dummy password")
+            .to_string()
+    })
+}
+
+/// Creates a single-use token and stores its hash, returning the token.
+///
+/// The token is random rather than signed: being single-use and revoked on
+/// a new request, it needs a row in the database anyway, and the row then
+/// proves it was issued without a signing key to manage.
+async fn issue_user_token(
+    pool: &PgPool,
+    user_id: i32,
+    purpose: &str,
+    ttl: i64,
+) -> Result<String> {
+    let token = generate_random_hex(32);
+    insert_user_token(pool, user_id, purpose, &hash_token(&token), ttl).await?;
+
+    Ok(token)
+}
+
+/// Argon2 hash of a password, with a random salt.
+pub fn hash_password(password: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+
+    Ok(Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow!("Password hashing failed: {}", e))?
+        .to_string())
+}
+
+/// Hex encoded random bytes from the operating system's secure generator.
+pub fn generate_random_hex(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// SHA-256 hash under which an opaque token is stored. The tokens are random,
+/// so a fast unsalted hash is enough to keep them out of the database.
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
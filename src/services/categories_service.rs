@@ -3,20 +3,30 @@ use sqlx::PgPool;
 use validator::Validate;
 
 use crate::dtos::category_dto::{
-    CategoryDTO, CreateCategoryDTO, DeleteCategoryIdsDTO,
+    CategoryDTO, CategoryFiltersDTO, CreateCategoryDTO, DeleteCategoryIdsDTO,
 };
 use crate::dtos::pagination_dto::PaginationDTO;
+use crate::extractors::if_match_extractor::{IfMatch, PreconditionFailedError};
 use crate::handlers::generate_slug_handler::generate_slug;
-use crate::models::categories_model::CategoryModel;
+use crate::models::categories_model::{
+    CategoryFilters, CategoryModel, CategorySortField,
+};
+use crate::models::slugs_model::SlugType;
+use crate::models::sort_model::Sort;
 use crate::repositories::categories_repository::{
     count_categories, delete_category_by_id, insert_category,
     select_categories, select_category_by_id, update_category,
 };
+use crate::repositories::slugs_repository::insert_previous_slug;
 
-use super::calculate_pagination;
+use super::slugs_service::{allocate_slug, reserve_slug};
+use super::{calculate_pagination, if_match_deletion_id};
 
 /// Service to create a new category.
 ///
+/// A slug generated from the name gets a `-2`, `-3`… suffix if another
+/// category uses it.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the Postgres connection pool.
@@ -29,6 +39,7 @@ pub async fn create_category_service(
     pool: &PgPool,
     create_category_dto: CreateCategoryDTO,
 ) -> Result<CategoryDTO> {
+    let generated_slug = create_category_dto.slug.is_none();
     let mut category_model: CategoryModel = create_category_dto.try_into()?;
 
     if category_model.slug.is_none() {
@@ -36,27 +47,45 @@ pub async fn create_category_service(
     }
     category_model.validate()?;
 
+    let mut slug_lock = None;
+    if generated_slug {
+        let base = category_model.slug.take().unwrap_or_default();
+        let (lock, slug) =
+            reserve_slug(pool, SlugType::Category, &base, None).await?;
+        category_model.slug = Some(slug);
+        slug_lock = Some(lock);
+    }
+
     let create_category_model = insert_category(pool, category_model).await?;
+    if let Some(slug_lock) = slug_lock {
+        slug_lock.commit().await?;
+    }
     let result = CategoryDTO::from(create_category_model);
     Ok(result)
 }
 
 /// Service to update an existing category by its ID.
 ///
+/// A slug generated from the name is made unique as on creation.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the Postgres connection pool.
 /// * `id` - The ID of the category to update.
+/// * `if_match` - The version the client expects the category to be at.
 /// * `category_dto` - DTO object containing the updated category data.
 ///
 /// # Returns
 ///
-/// Returns a `CategoryDTO` representing the updated category.
+/// Returns a `CategoryDTO` representing the updated category, or a
+/// `PreconditionFailedError` if the category is at another version.
 pub async fn update_category_service(
     pool: &PgPool,
     id: i32,
+    if_match: &IfMatch,
     category_dto: CreateCategoryDTO,
 ) -> Result<CategoryDTO> {
+    let generated_slug = category_dto.slug.is_none();
     let mut category_model: CategoryModel = category_dto.try_into()?;
     category_model.id = Some(id);
 
@@ -65,8 +94,25 @@ pub async fn update_category_service(
     }
     category_model.validate()?;
 
-    let update_category_model =
-        update_category(pool, id, category_model).await?;
+    let current = select_category_by_id(pool, id).await?;
+    if_match.check(current.version)?;
+    category_model.version = current.version;
+
+    let mut tx = pool.begin().await?;
+    if generated_slug {
+        let base = category_model.slug.take().unwrap_or_default();
+        category_model.slug = Some(
+            allocate_slug(&mut tx, SlugType::Category, &base, Some(id)).await?,
+        );
+    }
+    if let Some(slug) = &category_model.slug {
+        insert_previous_slug(&mut tx, SlugType::Category, id, slug).await?;
+    }
+
+    let update_category_model = update_category(pool, id, category_model)
+        .await
+        .map_err(PreconditionFailedError::on_row_not_found)?;
+    tx.commit().await?;
     let result = CategoryDTO::from(update_category_model);
     Ok(result)
 }
@@ -76,32 +122,29 @@ pub async fn update_category_service(
 /// # Arguments
 ///
 /// * `pool` - A reference to the Postgres connection pool.
+/// * `filters_dto` - The conditions the categories must match.
 /// * `page` - The current page number for pagination.
 /// * `limit` - The number of items per page.
-/// * `sort_column` - The column name to sort the results by.
-/// * `sort_order` - The order of sorting (e.g., "asc" for ascending, "desc" for descending).
+/// * `sort` - The fields to sort by, e.g. `parent_id,name`.
 ///
 /// # Returns
 ///
 /// Returns a `PaginationDTO` containing paginated category data.
 pub async fn get_all_categories_service(
     pool: &PgPool,
+    filters_dto: CategoryFiltersDTO,
     page: i64,
     limit: i64,
-    sort_column: &str,
-    sort_order: &str,
+    sort: &str,
 ) -> Result<PaginationDTO<CategoryDTO>> {
-    let total_items = count_categories(pool).await?;
+    let sort = Sort::<CategorySortField>::parse(sort)?;
+    let filters = CategoryFilters::try_from(filters_dto)?;
+    let total_items = count_categories(pool, &filters).await?;
     let pagination = calculate_pagination(total_items, page, limit);
 
-    let category_model: Vec<CategoryModel> = select_categories(
-        pool,
-        limit,
-        pagination.offset,
-        sort_column,
-        sort_order,
-    )
-    .await?;
+    let category_model: Vec<CategoryModel> =
+        select_categories(pool, &filters, limit, pagination.offset, &sort)
+            .await?;
 
     let category_dto: Vec<CategoryDTO> =
         category_model.into_iter().map(CategoryDTO::from).collect();
@@ -138,6 +181,8 @@ pub async fn get_category_by_id_service(
 /// # Arguments
 ///
 /// * `pool` - A reference to the Postgres connection pool.
+/// * `if_match` - The version the client expects a single category to be at,
+///   any number of categories being deleted at any version with `*`.
 /// * `delete_category_ids_dto` - DTO containing the list of category IDs to delete.
 ///
 /// # Returns
@@ -145,9 +190,22 @@ pub async fn get_category_by_id_service(
 /// Returns a `Vec<i32>` containing the IDs of the deleted categories.
 pub async fn delete_category_service(
     pool: &PgPool,
+    if_match: &IfMatch,
     delete_category_ids_dto: DeleteCategoryIdsDTO,
 ) -> Result<Vec<i32>> {
+    let mut version = None;
+    if if_match.is_present() && !if_match.is_any() {
+        let id = if_match_deletion_id(&delete_category_ids_dto.ids)?;
+        version = select_category_by_id(pool, id).await?.version;
+        if_match.check(version)?;
+    }
+
     let deleted_ids =
-        delete_category_by_id(pool, delete_category_ids_dto.ids).await?;
+        delete_category_by_id(pool, delete_category_ids_dto.ids, version)
+            .await?;
+    if version.is_some() && deleted_ids.is_empty() {
+        return Err(PreconditionFailedError.into());
+    }
+
     Ok(deleted_ids)
 }
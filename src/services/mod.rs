@@ -1,10 +1,25 @@
+use validator::{ValidationError, ValidationErrors};
+
 use crate::dtos::pagination_dto::PaginationInfo;
 
+pub mod api_keys_service;
 pub mod auth_service;
 pub mod categories_service;
+pub mod permissions_service;
+pub mod post_drafts_service;
+pub mod post_revisions_service;
+pub mod post_trash_service;
 pub mod posts_categories_service;
 pub mod posts_services;
+pub mod public_posts_service;
+pub mod redirects_service;
+pub mod roles_permissions_service;
+pub mod roles_service;
+pub mod scheduler_service;
+pub mod slugs_service;
 pub mod tags_service;
+pub mod two_factor_service;
+pub mod users_roles_service;
 pub mod users_service;
 
 /// Calculate pagination information based on the total number of items, current page, and limit.
@@ -39,3 +54,20 @@ pub fn calculate_pagination(
         offset,
     }
 }
+
+/// The ID of the resource a deletion with an `If-Match` header targets: the
+/// header holds the `ETag` of a single resource, so a single ID is accepted.
+pub fn if_match_deletion_id(ids: &[i32]) -> Result<i32, ValidationErrors> {
+    match ids {
+        [id] => Ok(*id),
+        _ => {
+            let mut error = ValidationError::new("single_id");
+            error.message = Some(
+                "A single ID can be deleted with an If-Match header".into(),
+            );
+            let mut errors = ValidationErrors::new();
+            errors.add("ids", error);
+            Err(errors)
+        }
+    }
+}
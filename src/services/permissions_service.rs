@@ -0,0 +1,138 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::dtos::pagination_dto::PaginationDTO;
+use crate::dtos::permission_dto::{
+    CreatePermissionDTO, DeletePermissionIdsDTO, PermissionDTO,
+};
+use crate::models::permissions_model::{PermissionModel, PermissionSortField};
+use crate::models::sort_model::Sort;
+use crate::repositories::permissions_repository::{
+    count_permissions, delete_permission_by_id, insert_permission,
+    select_permission_by_id, select_permissions, update_permission,
+};
+
+use super::calculate_pagination;
+
+/// Service to create a new permission.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `permission_dto` - A `CreatePermissionDTO` object containing the permission data.
+///
+/// # Returns
+///
+/// * `Result<PermissionDTO>` - The newly created permission.
+pub async fn create_permission_service(
+    pool: &PgPool,
+    permission_dto: CreatePermissionDTO,
+) -> Result<PermissionDTO> {
+    let permission_model: PermissionModel = permission_dto.try_into()?;
+
+    let create_permission_model =
+        insert_permission(pool, permission_model).await?;
+    let result = PermissionDTO::from(create_permission_model);
+    Ok(result)
+}
+
+/// Service to update an existing permission by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the permission to update.
+/// * `permission_dto` - A `CreatePermissionDTO` object containing the updated permission data.
+///
+/// # Returns
+///
+/// * `Result<PermissionDTO>` - The updated permission.
+pub async fn update_permission_service(
+    pool: &PgPool,
+    id: i32,
+    permission_dto: CreatePermissionDTO,
+) -> Result<PermissionDTO> {
+    let mut permission_model: PermissionModel = permission_dto.try_into()?;
+    permission_model.id = Some(id);
+
+    let update_permission_model =
+        update_permission(pool, id, permission_model).await?;
+    let result = PermissionDTO::from(update_permission_model);
+    Ok(result)
+}
+
+/// Service to retrieve all permissions with pagination and sorting.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of permissions per page.
+/// * `sort` - The fields to sort by, e.g. `name,-date_created`.
+///
+/// # Returns
+///
+/// * `Result<PaginationDTO<PermissionDTO>>` - The paginated list of permissions.
+pub async fn get_all_permissions_service(
+    pool: &PgPool,
+    page: i64,
+    limit: i64,
+    sort: &str,
+) -> Result<PaginationDTO<PermissionDTO>> {
+    let sort = Sort::<PermissionSortField>::parse(sort)?;
+    let total_items = count_permissions(pool).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let permissions_model =
+        select_permissions(pool, limit, pagination.offset, &sort).await?;
+
+    let permissions_dto: Vec<PermissionDTO> = permissions_model
+        .into_iter()
+        .map(PermissionDTO::from)
+        .collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: permissions_dto,
+    })
+}
+
+/// Service to retrieve a permission by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the permission to retrieve.
+///
+/// # Returns
+///
+/// * `Result<PermissionDTO>` - The requested permission.
+pub async fn get_permission_by_id_service(
+    pool: &PgPool,
+    id: i32,
+) -> Result<PermissionDTO> {
+    let permission_model = select_permission_by_id(pool, id).await?;
+    let result = PermissionDTO::from(permission_model);
+    Ok(result)
+}
+
+/// Service to delete permissions by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `delete_permission_ids_dto` - A `DeletePermissionIdsDTO` containing the list of permission IDs to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted permissions.
+pub async fn delete_permission_by_id_service(
+    pool: &PgPool,
+    delete_permission_ids_dto: DeletePermissionIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids =
+        delete_permission_by_id(pool, delete_permission_ids_dto.ids).await?;
+    Ok(deleted_ids)
+}
@@ -0,0 +1,164 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        post_draft_dtos::{PostDraftDTO, SavePostDraftDTO},
+        post_dto::PostDTO,
+    },
+    extractors::{
+        current_user_extractor::CurrentUser,
+        if_match_extractor::{IfMatch, PreconditionFailedError},
+    },
+    handlers::{
+        generate_slug_handler::generate_slug,
+        render_content_handler::render_content,
+    },
+    models::{
+        post_blocks_model::PostBlocks,
+        post_drafts_model::PostDraftModel,
+        posts_model::{ContentFormat, PostContent},
+        slugs_model::SlugType,
+    },
+    repositories::{
+        post_drafts_repository::{
+            delete_post_draft, select_post_draft, take_post_draft,
+            upsert_post_draft,
+        },
+        post_revisions_repository::insert_post_revision,
+        posts_repository::{
+            select_post_by_id, select_post_content_format,
+            select_post_status_for_update, update_post_content,
+        },
+        slugs_repository::{insert_previous_slug, select_available_slug},
+    },
+};
+
+use super::{
+    posts_services::get_post_by_id_service, slugs_service::allocate_slug,
+};
+
+/// Autosaves the working draft of a post. The live post is left untouched.
+///
+/// The draft of a post made of blocks must hold valid blocks as JSON. A new
+/// draft records the version of the post it starts from. Once the changes
+/// made to the post since then are merged into the draft, saving it with an
+/// `If-Match` of the current version rebases it onto that version. A slug generated
+/// from the title is replaced by a free one, only a suggestion until the
+/// draft is published.
+///
+/// # Returns
+///
+/// * `Result<PostDraftDTO>` - The saved draft, a `RowNotFound` error if
+///   the post does not exist, or a `PreconditionFailedError` if `if_match`
+///   is not the current version of the post.
+pub async fn save_post_draft_service(
+    pool: &PgPool,
+    current_user: &CurrentUser,
+    post_id: i32,
+    if_match: &IfMatch,
+    save_post_draft_dto: SavePostDraftDTO,
+) -> Result<PostDraftDTO> {
+    let post = select_post_by_id(pool, post_id).await?;
+    if_match.check(post.version)?;
+    let mut draft = PostDraftModel::try_from((
+        save_post_draft_dto,
+        post_id,
+        current_user.id,
+        post.version.unwrap_or_default(),
+    ))?;
+    if post.content_format == ContentFormat::Blocks {
+        PostBlocks::parse(&draft.content)?;
+    }
+
+    if draft.slug_generated {
+        // Drafts do not hold their slug, it is allocated when published
+        let mut conn = pool.acquire().await?;
+        draft.slug = select_available_slug(
+            &mut conn,
+            SlugType::Post,
+            &draft.slug,
+            Some(post_id),
+        )
+        .await?;
+    }
+
+    let saved_draft =
+        upsert_post_draft(pool, &draft, if_match.is_present()).await?;
+
+    Ok(PostDraftDTO::from(saved_draft))
+}
+
+/// Retrieves the working draft of a post.
+pub async fn get_post_draft_service(
+    pool: &PgPool,
+    post_id: i32,
+) -> Result<PostDraftDTO> {
+    let draft = select_post_draft(pool, post_id).await?;
+
+    Ok(PostDraftDTO::from(draft))
+}
+
+/// Discards the working draft of a post.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the post has no draft.
+pub async fn delete_post_draft_service(
+    pool: &PgPool,
+    post_id: i32,
+) -> Result<bool> {
+    Ok(delete_post_draft(pool, post_id).await? > 0)
+}
+
+/// Publishes the changes of the working draft: its title, content and slug
+/// replace those of the post, are recorded as a new revision, and the draft
+/// is removed. A generated slug is allocated again, in case another post
+/// took it since the draft was saved.
+///
+/// # Returns
+///
+/// * `Result<PostDTO>` - The updated post, a `RowNotFound` error if the
+///   post has no draft, or a `PreconditionFailedError` if the post changed
+///   since the draft was started.
+pub async fn publish_post_draft_service(
+    pool: &PgPool,
+    current_user: &CurrentUser,
+    post_id: i32,
+) -> Result<PostDTO> {
+    let mut tx = pool.begin().await?;
+    let (_, _, version) =
+        select_post_status_for_update(&mut tx, post_id).await?;
+    let mut draft = take_post_draft(&mut tx, post_id)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+    // Publishing would overwrite the changes made to the post since then
+    if draft.base_version != version {
+        return Err(PreconditionFailedError.into());
+    }
+    if draft.slug_generated {
+        draft.slug = allocate_slug(
+            &mut tx,
+            SlugType::Post,
+            &generate_slug(&draft.title),
+            Some(post_id),
+        )
+        .await?;
+    }
+
+    let content_format = select_post_content_format(&mut tx, post_id).await?;
+    insert_previous_slug(&mut tx, SlugType::Post, post_id, &draft.slug).await?;
+    let post_content = PostContent {
+        content_html: render_content(&content_format, &draft.content),
+        title: draft.title,
+        content: draft.content,
+        content_format,
+        slug: draft.slug,
+    };
+    update_post_content(&mut tx, post_id, &post_content, current_user.id)
+        .await?;
+    insert_post_revision(&mut tx, post_id, current_user.id).await?;
+    tx.commit().await?;
+
+    get_post_by_id_service(pool, post_id).await
+}
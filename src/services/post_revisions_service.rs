@@ -0,0 +1,124 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::{
+        post_dto::PostDTO,
+        post_revision_dtos::{
+            PostRevisionDTO, PostRevisionDiffDTO, PostRevisionSummaryDTO,
+        },
+    },
+    extractors::current_user_extractor::CurrentUser,
+    handlers::{
+        line_diff_handler::diff_lines, render_content_handler::render_content,
+    },
+    models::{
+        post_blocks_model::PostBlocks,
+        posts_model::{ContentFormat, PostContent},
+        slugs_model::SlugType,
+    },
+    repositories::{
+        post_revisions_repository::{
+            insert_post_revision, select_post_revision, select_post_revisions,
+        },
+        posts_repository::{
+            select_post_by_id, select_post_status_for_update,
+            update_post_content,
+        },
+        slugs_repository::insert_previous_slug,
+    },
+};
+
+use super::posts_services::get_post_by_id_service;
+
+/// Lists the revisions of a post, the latest first.
+///
+/// # Returns
+///
+/// * `Result<Vec<PostRevisionSummaryDTO>>` - The revisions, or a
+///   `RowNotFound` error if the post does not exist.
+pub async fn get_post_revisions_service(
+    pool: &PgPool,
+    post_id: i32,
+) -> Result<Vec<PostRevisionSummaryDTO>> {
+    select_post_by_id(pool, post_id).await?;
+
+    let revisions = select_post_revisions(pool, post_id).await?;
+
+    Ok(revisions
+        .into_iter()
+        .map(PostRevisionSummaryDTO::from)
+        .collect())
+}
+
+/// Retrieves a revision of a post by its number.
+pub async fn get_post_revision_service(
+    pool: &PgPool,
+    post_id: i32,
+    revision_number: i32,
+) -> Result<PostRevisionDTO> {
+    let revision = select_post_revision(pool, post_id, revision_number).await?;
+
+    Ok(PostRevisionDTO::from(revision))
+}
+
+/// Compares two revisions of a post, line by line.
+pub async fn get_post_revision_diff_service(
+    pool: &PgPool,
+    post_id: i32,
+    from_revision: i32,
+    to_revision: i32,
+) -> Result<PostRevisionDiffDTO> {
+    let from = select_post_revision(pool, post_id, from_revision).await?;
+    let to = select_post_revision(pool, post_id, to_revision).await?;
+
+    Ok(PostRevisionDiffDTO {
+        from_revision,
+        to_revision,
+        title: diff_lines(&from.title, &to.title),
+        slug: diff_lines(&from.slug, &to.slug),
+        content: diff_lines(&from.content, &to.content),
+    })
+}
+
+/// Restores the title, content, content format and slug of an old revision,
+/// which are recorded as a new revision. The status of the post is left
+/// untouched.
+///
+/// # Returns
+///
+/// * `Result<PostDTO>` - The restored post, or `ValidationErrors` if the
+///   revision holds blocks that are no longer valid.
+pub async fn restore_post_revision_service(
+    pool: &PgPool,
+    current_user: &CurrentUser,
+    post_id: i32,
+    revision_number: i32,
+) -> Result<PostDTO> {
+    // Revisions are immutable, no need to read it in the transaction
+    let revision = select_post_revision(pool, post_id, revision_number).await?;
+    if revision.content_format == ContentFormat::Blocks {
+        PostBlocks::parse(&revision.content)?;
+    }
+
+    let mut tx = pool.begin().await?;
+    select_post_status_for_update(&mut tx, post_id).await?;
+    insert_previous_slug(&mut tx, SlugType::Post, post_id, &revision.slug)
+        .await?;
+    let post_content = PostContent {
+        content_html: render_content(
+            &revision.content_format,
+            &revision.content,
+        ),
+        title: revision.title,
+        content: revision.content,
+        content_format: revision.content_format,
+        slug: revision.slug,
+    };
+    update_post_content(&mut tx, post_id, &post_content, current_user.id)
+        .await?;
+    insert_post_revision(&mut tx, post_id, current_user.id).await?;
+    tx.commit().await?;
+
+    get_post_by_id_service(pool, post_id).await
+}
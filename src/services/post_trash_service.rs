@@ -0,0 +1,82 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    config::config::get_trash_retention_days,
+    dtos::{
+        pagination_dto::PaginationDTO,
+        post_dto::{PostDTO, TrashedPostIdsDTO},
+    },
+    repositories::posts_repository::{
+        count_trashed_posts, purge_expired_trashed_posts, purge_trashed_posts,
+        restore_trashed_posts, select_trashed_posts,
+    },
+};
+
+use super::calculate_pagination;
+
+/// Lists the trashed posts, the last trashed first.
+///
+/// # Returns
+///
+/// * `Result<PaginationDTO<PostDTO>>` - A page of trashed posts.
+pub async fn get_trashed_posts_service(
+    pool: &PgPool,
+    page: i64,
+    limit: i64,
+) -> Result<PaginationDTO<PostDTO>> {
+    let total_items = count_trashed_posts(pool).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let posts = select_trashed_posts(pool, limit, pagination.offset).await?;
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: posts.into_iter().map(PostDTO::from).collect(),
+    })
+}
+
+/// Takes posts out of the trash, with the status they were trashed with.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the restored posts.
+pub async fn restore_trashed_posts_service(
+    pool: &PgPool,
+    trashed_post_ids_dto: TrashedPostIdsDTO,
+) -> Result<Vec<i32>> {
+    restore_trashed_posts(pool, trashed_post_ids_dto.ids).await
+}
+
+/// Permanently deletes posts from the trash. Posts that are not trashed are
+/// left untouched.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the purged posts.
+pub async fn purge_trashed_posts_service(
+    pool: &PgPool,
+    trashed_post_ids_dto: TrashedPostIdsDTO,
+) -> Result<Vec<i32>> {
+    purge_trashed_posts(pool, trashed_post_ids_dto.ids).await
+}
+
+/// Purges the posts that stayed in the trash longer than
+/// `TRASH_RETENTION_DAYS`.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the purged posts, none if the
+///   retention is disabled.
+pub async fn purge_expired_trashed_posts_service(
+    pool: &PgPool,
+) -> Result<Vec<i32>> {
+    let retention_days = get_trash_retention_days();
+    if retention_days == 0 {
+        return Ok(vec![]);
+    }
+
+    purge_expired_trashed_posts(pool, i32::from(retention_days)).await
+}
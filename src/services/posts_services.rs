@@ -1,86 +1,284 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use chrono::NaiveDateTime;
+use sqlx::{PgConnection, PgPool};
+use validator::{ValidationError, ValidationErrors};
 
 use crate::dtos::pagination_dto::PaginationDTO;
-use crate::dtos::post_dto::{CreatePostDTO, DeletePostIdsDTO, PostDTO};
-use crate::models::posts_model::PostModel;
+use crate::dtos::post_dto::{
+    CreatePostDTO, DeletePostIdsDTO, PostDTO, PostFiltersDTO,
+};
+use crate::extractors::current_user_extractor::CurrentUser;
+use crate::extractors::if_match_extractor::{IfMatch, PreconditionFailedError};
+use crate::handlers::generate_slug_handler::generate_slug;
+use crate::handlers::render_content_handler::render_content;
+use crate::models::posts_model::{
+    IllegalTransitionError, PostFilters, PostModel, PostSortField, PostsStatus,
+};
+use crate::models::slugs_model::SlugType;
+use crate::models::sort_model::Sort;
+use crate::repositories::categories_repository::select_existing_category_ids;
+use crate::repositories::post_revisions_repository::insert_post_revision;
+use crate::repositories::post_status_transitions_repository::insert_post_status_transition;
+use crate::repositories::posts_categories_repository::replace_post_categories;
 use crate::repositories::posts_repository::{
-    count_posts, delete_post_by_id, insert_post, select_post_by_id,
-    select_posts, update_post,
+    count_posts, count_posts_by_tag_id, insert_post, select_post_by_id,
+    select_post_status_for_update, select_posts, select_posts_by_tag_id,
+    select_posts_without_content_html, trash_posts_by_id, update_post,
+    update_post_content_html, update_post_status,
+};
+use crate::repositories::posts_tags_repository::replace_post_tags;
+use crate::repositories::slugs_repository::insert_previous_slug;
+use crate::repositories::tags_repository::{
+    insert_missing_tags, select_existing_tag_ids, select_tag_by_id,
 };
+use crate::validators::slug_validator::validate_slug;
 
-use super::calculate_pagination;
+use super::slugs_service::allocate_slug;
+use super::{calculate_pagination, if_match_deletion_id};
 
 /// Service to insert a post into the database.
 ///
+/// The post, its categories and its tags are saved in a single transaction.
+/// A slug generated from the title gets a `-2`, `-3`… suffix if another post
+/// uses it. A post starts as a draft or pending review, and is only published
+/// or scheduled through the workflow.
+///
 /// # Arguments
 ///
 /// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user, recorded as the author.
 /// * `create_post_dto` - Data Transfer Object containing the details for the new post.
 ///
 /// # Returns
 ///
-/// Returns a `PostDTO` object containing the inserted post details.
+/// Returns a `PostDTO` object containing the inserted post details, with its categories and tags,
+/// or an `IllegalTransitionError` if the post would not start as a draft or pending review.
 pub async fn create_post_service(
     pool: &PgPool,
-    create_post_dto: CreatePostDTO,
+    current_user: &CurrentUser,
+    mut create_post_dto: CreatePostDTO,
 ) -> Result<PostDTO> {
-    let post_model: PostModel = create_post_dto.try_into()?;
+    let terms = PostTerms::take(&mut create_post_dto);
+    let generated_slug = create_post_dto.slug.is_none();
+    let mut post_model =
+        PostModel::try_from((create_post_dto, current_user.id))?;
+    if !post_model.status.is_initial() {
+        return Err(IllegalTransitionError {
+            from: PostsStatus::Draft,
+            to: post_model.status,
+        }
+        .into());
+    }
+    post_model.date_published = workflow_date_published(
+        None,
+        &post_model.status,
+        post_model.date_published,
+    )?;
+
+    let mut tx = pool.begin().await?;
+    if generated_slug {
+        post_model.slug =
+            Some(allocate_post_slug(&mut tx, &post_model, None).await?);
+    }
+    let post_id = insert_post(&mut tx, &post_model).await?;
+    assign_post_terms(&mut tx, post_id, terms).await?;
+    insert_post_revision(&mut tx, post_id, current_user.id).await?;
+    tx.commit().await?;
 
-    let create_post_model = insert_post(pool, post_model).await?;
-    let result = PostDTO::from(create_post_model);
-    Ok(result)
+    get_post_by_id_service(pool, post_id).await
 }
 
 /// Service to update a post by its ID in the database.
 ///
+/// The post, its categories and its tags are saved in a single transaction,
+/// and the new state of the post is recorded as a revision. A slug generated
+/// from the title is made unique as on creation.
+///
 /// # Arguments
 ///
 /// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user, recorded as the last editor.
 /// * `id` - The ID of the post to be updated.
+/// * `if_match` - The version the client expects the post to be at.
 /// * `update_post_dto` - Data Transfer Object containing the updated details of the post.
 ///
 /// # Returns
 ///
-/// Returns a `PostDTO` object containing the updated post details.
+/// Returns a `PostDTO` object containing the updated post details, with its categories and tags,
+/// or a `PreconditionFailedError` if the post is at another version.
 pub async fn update_post_service(
     pool: &PgPool,
+    current_user: &CurrentUser,
     id: i32,
-    update_post_dto: CreatePostDTO,
+    if_match: &IfMatch,
+    mut update_post_dto: CreatePostDTO,
 ) -> Result<PostDTO> {
-    let mut post_model: PostModel = update_post_dto.try_into()?;
+    let terms = PostTerms::take(&mut update_post_dto);
+    let generated_slug = update_post_dto.slug.is_none();
+    let mut post_model =
+        PostModel::try_from((update_post_dto, current_user.id))?;
     post_model.id = Some(id);
+    post_model.updated_by = Some(current_user.id);
+    post_model.date_updated = Some(chrono::Utc::now().naive_utc());
+
+    let mut tx = pool.begin().await?;
+    let (from, date_published, version) =
+        select_post_status_for_update(&mut tx, id).await?;
+    if_match.check(Some(version))?;
+    if from != post_model.status && !from.can_transition_to(&post_model.status)
+    {
+        return Err(IllegalTransitionError {
+            from,
+            to: post_model.status,
+        }
+        .into());
+    }
+    post_model.date_published = workflow_date_published(
+        Some((&from, date_published)),
+        &post_model.status,
+        post_model.date_published,
+    )?;
+
+    if generated_slug {
+        post_model.slug =
+            Some(allocate_post_slug(&mut tx, &post_model, Some(id)).await?);
+    }
+    if let Some(slug) = &post_model.slug {
+        insert_previous_slug(&mut tx, SlugType::Post, id, slug).await?;
+    }
+    update_post(&mut tx, id, &post_model).await?;
+    assign_post_terms(&mut tx, id, terms).await?;
+    insert_post_revision(&mut tx, id, current_user.id).await?;
+    if from != post_model.status {
+        insert_post_status_transition(
+            &mut tx,
+            id,
+            &from,
+            &post_model.status,
+            Some(current_user.id),
+        )
+        .await?;
+    }
+    tx.commit().await?;
+
+    get_post_by_id_service(pool, id).await
+}
+
+/// Service to move a post along the editorial workflow.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `current_user` - The authenticated user, recorded as the last editor.
+/// * `id` - The ID of the post.
+/// * `to` - The new status of the post.
+/// * `date_published` - The publication date of a scheduled post.
+///
+/// # Returns
+///
+/// Returns the updated `PostDTO`, or an `IllegalTransitionError` if the
+/// workflow does not allow the change.
+pub async fn transition_post_service(
+    pool: &PgPool,
+    current_user: &CurrentUser,
+    id: i32,
+    to: PostsStatus,
+    date_published: Option<NaiveDateTime>,
+) -> Result<PostDTO> {
+    let mut tx = pool.begin().await?;
+    let (from, previous_date_published, _) =
+        select_post_status_for_update(&mut tx, id).await?;
+    if !from.can_transition_to(&to) {
+        return Err(IllegalTransitionError { from, to }.into());
+    }
+    let date_published = workflow_date_published(
+        Some((&from, previous_date_published)),
+        &to,
+        date_published.or(previous_date_published),
+    )?;
 
-    let update_post_model = update_post(pool, id, post_model).await?;
-    let result = PostDTO::from(update_post_model);
-    Ok(result)
+    update_post_status(&mut tx, id, &to, date_published, current_user.id)
+        .await?;
+    insert_post_status_transition(
+        &mut tx,
+        id,
+        &from,
+        &to,
+        Some(current_user.id),
+    )
+    .await?;
+    tx.commit().await?;
+
+    get_post_by_id_service(pool, id).await
 }
 
-/// Service to retrieve all posts from the database with pagination and sorting.
+/// Service to retrieve all posts from the database with filters, pagination and sorting.
 ///
 /// # Arguments
 ///
 /// * `pool` - Reference to the database connection pool.
+/// * `filters_dto` - The conditions the posts must match.
 /// * `page` - The current page number for pagination.
 /// * `limit` - The number of posts per page.
-/// * `sort_column` - The column name to sort by.
-/// * `sort_order` - The order of sorting (either "asc" for ascending or "desc" for descending).
+/// * `sort` - The fields to sort by, e.g. `-date_published,title`.
 ///
 /// # Returns
 ///
 /// Returns a `PaginationDTO<PostDTO>` object containing the paginated list of posts and pagination information.
 pub async fn get_all_posts_service(
     pool: &PgPool,
+    filters_dto: PostFiltersDTO,
+    page: i64,
+    limit: i64,
+    sort: &str,
+) -> Result<PaginationDTO<PostDTO>> {
+    let sort = Sort::<PostSortField>::parse(sort)?;
+    let filters = PostFilters::try_from(filters_dto)?;
+    let total_items = count_posts(pool, &filters).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let posts_model =
+        select_posts(pool, &filters, limit, pagination.offset, &sort).await?;
+
+    let posts_dto: Vec<PostDTO> =
+        posts_model.into_iter().map(PostDTO::from).collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: posts_dto,
+    })
+}
+
+/// Service to retrieve the posts having a tag, with pagination and sorting.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+/// * `tag_id` - The ID of the tag.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of posts per page.
+/// * `sort` - The fields to sort by, e.g. `-date_published,title`.
+///
+/// # Returns
+///
+/// Returns a `PaginationDTO<PostDTO>` object, or a `RowNotFound` error if the tag does not exist.
+pub async fn get_posts_by_tag_service(
+    pool: &PgPool,
+    tag_id: i32,
     page: i64,
     limit: i64,
-    sort_column: &str,
-    sort_order: &str,
+    sort: &str,
 ) -> Result<PaginationDTO<PostDTO>> {
-    let total_items = count_posts(pool).await?;
+    let sort = Sort::<PostSortField>::parse(sort)?;
+    select_tag_by_id(pool, tag_id).await?;
+
+    let total_items = count_posts_by_tag_id(pool, tag_id).await?;
     let pagination = calculate_pagination(total_items, page, limit);
 
     let posts_model =
-        select_posts(pool, limit, pagination.offset, sort_column, sort_order)
+        select_posts_by_tag_id(pool, tag_id, limit, pagination.offset, &sort)
             .await?;
 
     let posts_dto: Vec<PostDTO> =
@@ -110,20 +308,199 @@ pub async fn get_post_by_id_service(pool: &PgPool, id: i32) -> Result<PostDTO> {
     Ok(post_dto)
 }
 
-/// Service to delete posts by their IDs in the database.
+/// Service to move posts to the trash by their IDs.
 ///
 /// # Arguments
 ///
 /// * `pool` - Reference to the database connection pool.
+/// * `if_match` - The version the client expects a single post to be at,
+///   any number of posts being trashed at any version with `*`.
 /// * `delete_post_ids_dto` - Data Transfer Object containing the list of post IDs to delete.
 ///
 /// # Returns
 ///
-/// Returns a vector of deleted post IDs.
+/// Returns a vector of trashed post IDs.
 pub async fn delete_post_service(
     pool: &PgPool,
+    if_match: &IfMatch,
     delete_post_ids_dto: DeletePostIdsDTO,
 ) -> Result<Vec<i32>> {
-    let deleted_ids = delete_post_by_id(pool, delete_post_ids_dto.ids).await?;
+    let mut version = None;
+    if if_match.is_present() && !if_match.is_any() {
+        let id = if_match_deletion_id(&delete_post_ids_dto.ids)?;
+        version = select_post_by_id(pool, id).await?.version;
+        if_match.check(version)?;
+    }
+
+    let deleted_ids =
+        trash_posts_by_id(pool, delete_post_ids_dto.ids, version).await?;
+    if version.is_some() && deleted_ids.is_empty() {
+        return Err(PreconditionFailedError.into());
+    }
+
     Ok(deleted_ids)
 }
+
+/// Service to render the content of the posts saved before it was rendered
+/// on save, in batches.
+///
+/// # Arguments
+///
+/// * `pool` - Reference to the database connection pool.
+///
+/// # Returns
+///
+/// Returns the number of rendered posts.
+pub async fn render_missing_content_html_service(
+    pool: &PgPool,
+) -> Result<usize> {
+    let mut rendered = 0;
+    loop {
+        let posts = select_posts_without_content_html(pool, 100).await?;
+        if posts.is_empty() {
+            return Ok(rendered);
+        }
+
+        for (id, content_format, content) in posts {
+            let content_html = render_content(&content_format, &content);
+            update_post_content_html(pool, id, &content_html).await?;
+            rendered += 1;
+        }
+    }
+}
+
+/// Makes the slug generated from the title of a post unique.
+async fn allocate_post_slug(
+    conn: &mut PgConnection,
+    post_model: &PostModel,
+    exclude_id: Option<i32>,
+) -> Result<String> {
+    let base = post_model.slug.as_deref().unwrap_or_default();
+
+    allocate_slug(conn, SlugType::Post, base, exclude_id).await
+}
+
+/// The categories and tags requested for a post.
+struct PostTerms {
+    category_ids: Vec<i32>,
+    tag_ids: Vec<i32>,
+    tag_names: Vec<String>,
+}
+
+impl PostTerms {
+    /// Moves the categories and tags out of the DTO, which is then converted
+    /// to a `PostModel`.
+    fn take(dto: &mut CreatePostDTO) -> Self {
+        PostTerms {
+            category_ids: std::mem::take(&mut dto.categories_ids),
+            tag_ids: std::mem::take(&mut dto.tag_ids),
+            tag_names: std::mem::take(&mut dto.tags),
+        }
+    }
+}
+
+/// The publication date a post is saved with: entering `Published` stamps
+/// the current time, and a post can only be scheduled in the future.
+///
+/// `previous` is the status and publication date the post had, if it
+/// already exists.
+fn workflow_date_published(
+    previous: Option<(&PostsStatus, Option<NaiveDateTime>)>,
+    to: &PostsStatus,
+    date_published: Option<NaiveDateTime>,
+) -> Result<Option<NaiveDateTime>> {
+    let now = chrono::Utc::now().naive_utc();
+    let (from, previous_date_published) = previous.unzip();
+
+    match to {
+        PostsStatus::Published if from == Some(&PostsStatus::Published) => {
+            Ok(date_published.or(previous_date_published.flatten()))
+        }
+        PostsStatus::Published => Ok(Some(now)),
+        PostsStatus::Scheduled
+            if from == Some(&PostsStatus::Scheduled)
+                && date_published == previous_date_published.flatten() =>
+        {
+            Ok(date_published)
+        }
+        PostsStatus::Scheduled => match date_published {
+            Some(date) if date > now => Ok(Some(date)),
+            _ => {
+                let mut error = ValidationError::new("scheduled_in_past");
+                error.message = Some(
+                    "Scheduled posts require a date_published in the future"
+                        .into(),
+                );
+                let mut errors = ValidationErrors::new();
+                errors.add("date_published", error);
+                Err(errors.into())
+            }
+        },
+        _ => Ok(date_published),
+    }
+}
+
+/// Sets the categories and tags of a post, creating the tags given by name
+/// unless a tag has the same slug.
+async fn assign_post_terms(
+    conn: &mut PgConnection,
+    post_id: i32,
+    terms: PostTerms,
+) -> Result<()> {
+    let mut errors = ValidationErrors::new();
+
+    let category_ids =
+        select_existing_category_ids(conn, &terms.category_ids).await?;
+    if let Some(id) = terms
+        .category_ids
+        .iter()
+        .find(|id| !category_ids.contains(id))
+    {
+        let mut error = ValidationError::new("unknown_category");
+        error.message = Some(format!("Category {} does not exist", id).into());
+        errors.add("categories_ids", error);
+    }
+
+    let mut tag_ids = select_existing_tag_ids(conn, &terms.tag_ids).await?;
+    if let Some(id) = terms.tag_ids.iter().find(|id| !tag_ids.contains(id)) {
+        let mut error = ValidationError::new("unknown_tag");
+        error.message = Some(format!("Tag {} does not exist", id).into());
+        errors.add("tag_ids", error);
+    }
+
+    let names: Vec<String> = terms
+        .tag_names
+        .iter()
+        .map(|name| name.trim().to_string())
+        .collect();
+    let slugs: Vec<String> =
+        names.iter().map(|name| generate_slug(name)).collect();
+    for (name, slug) in names.iter().zip(&slugs) {
+        if name.chars().count() > 100 {
+            let mut error = ValidationError::new("invalid_length");
+            error.message =
+                Some("Tag names must be at most 100 characters".into());
+            errors.add("tags", error);
+            break;
+        }
+        if let Err(error) = validate_slug(slug, 1, 200) {
+            errors.add("tags", error);
+            break;
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    if !names.is_empty() {
+        tag_ids.extend(insert_missing_tags(conn, &names, &slugs).await?);
+    }
+    tag_ids.sort_unstable();
+    tag_ids.dedup();
+
+    replace_post_categories(conn, post_id, &category_ids).await?;
+    replace_post_tags(conn, post_id, &tag_ids).await?;
+
+    Ok(())
+}
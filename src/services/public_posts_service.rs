@@ -0,0 +1,77 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use validator::Validate;
+
+use crate::dtos::pagination_dto::PaginationDTO;
+use crate::dtos::public_post_dtos::{PublicPostDTO, PublicPostsParamsDTO};
+use crate::repositories::public_posts_repository::{
+    count_public_posts, select_public_post_by_id, select_public_post_by_slug,
+    select_public_posts,
+};
+
+use super::calculate_pagination;
+
+/// The number of posts per page when the reader does not ask for one.
+const PUBLIC_POSTS_PER_PAGE: i64 = 10;
+
+/// Service to list the published posts, the most recent first.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `params` - The requested page and number of posts per page.
+///
+/// # Returns
+///
+/// * `Result<PaginationDTO<PublicPostDTO>>` - The paginated list of posts.
+pub async fn get_public_posts_service(
+    pool: &PgPool,
+    params: PublicPostsParamsDTO,
+) -> Result<PaginationDTO<PublicPostDTO>> {
+    params.validate()?;
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(PUBLIC_POSTS_PER_PAGE);
+
+    let total_items = count_public_posts(pool).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let posts_model =
+        select_public_posts(pool, limit, pagination.offset).await?;
+    let posts_dto: Vec<PublicPostDTO> =
+        posts_model.into_iter().map(PublicPostDTO::from).collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: posts_dto,
+    })
+}
+
+/// Service to retrieve a published post by its ID.
+///
+/// # Returns
+///
+/// * `Result<PublicPostDTO>` - The post, or a `RowNotFound` error if it is
+///   not published.
+pub async fn get_public_post_by_id_service(
+    pool: &PgPool,
+    id: i32,
+) -> Result<PublicPostDTO> {
+    let post_model = select_public_post_by_id(pool, id).await?;
+    Ok(PublicPostDTO::from(post_model))
+}
+
+/// Service to retrieve a published post by its slug.
+///
+/// # Returns
+///
+/// * `Result<PublicPostDTO>` - The post, or a `RowNotFound` error if it is
+///   not published.
+pub async fn get_public_post_by_slug_service(
+    pool: &PgPool,
+    slug: &str,
+) -> Result<PublicPostDTO> {
+    let post_model = select_public_post_by_slug(pool, slug).await?;
+    Ok(PublicPostDTO::from(post_model))
+}
@@ -0,0 +1,157 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::dtos::pagination_dto::PaginationDTO;
+use crate::dtos::redirect_dtos::{
+    CreateRedirectDTO, DeleteRedirectIdsDTO, RedirectDTO,
+};
+use crate::models::redirects_model::{RedirectModel, RedirectSortField};
+use crate::models::sort_model::Sort;
+use crate::repositories::redirects_repository::{
+    count_redirects, delete_redirect_by_id, hit_redirect, insert_redirect,
+    select_redirect_by_id, select_redirects, update_redirect,
+};
+
+use super::calculate_pagination;
+
+/// Service to create a new redirect.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `redirect_dto` - A `CreateRedirectDTO` object containing the redirect data.
+///
+/// # Returns
+///
+/// * `Result<RedirectDTO>` - The newly created redirect.
+pub async fn create_redirect_service(
+    pool: &PgPool,
+    redirect_dto: CreateRedirectDTO,
+) -> Result<RedirectDTO> {
+    let redirect_model: RedirectModel = redirect_dto.try_into()?;
+
+    let create_redirect_model = insert_redirect(pool, redirect_model).await?;
+    let result = RedirectDTO::from(create_redirect_model);
+    Ok(result)
+}
+
+/// Service to update an existing redirect by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the redirect to update.
+/// * `redirect_dto` - A `CreateRedirectDTO` object containing the updated redirect data.
+///
+/// # Returns
+///
+/// * `Result<RedirectDTO>` - The updated redirect.
+pub async fn update_redirect_service(
+    pool: &PgPool,
+    id: i32,
+    redirect_dto: CreateRedirectDTO,
+) -> Result<RedirectDTO> {
+    let mut redirect_model: RedirectModel = redirect_dto.try_into()?;
+    redirect_model.id = Some(id);
+
+    let update_redirect_model =
+        update_redirect(pool, id, redirect_model).await?;
+    let result = RedirectDTO::from(update_redirect_model);
+    Ok(result)
+}
+
+/// Service to retrieve all redirects with pagination and sorting.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of redirects per page.
+/// * `sort` - The fields to sort by, e.g. `-hits,source_path`.
+///
+/// # Returns
+///
+/// * `Result<PaginationDTO<RedirectDTO>>` - The paginated list of redirects.
+pub async fn get_all_redirects_service(
+    pool: &PgPool,
+    page: i64,
+    limit: i64,
+    sort: &str,
+) -> Result<PaginationDTO<RedirectDTO>> {
+    let sort = Sort::<RedirectSortField>::parse(sort)?;
+    let total_items = count_redirects(pool).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let redirects_model =
+        select_redirects(pool, limit, pagination.offset, &sort).await?;
+
+    let redirects_dto: Vec<RedirectDTO> =
+        redirects_model.into_iter().map(RedirectDTO::from).collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: redirects_dto,
+    })
+}
+
+/// Service to retrieve a redirect by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the redirect to retrieve.
+///
+/// # Returns
+///
+/// * `Result<RedirectDTO>` - The requested redirect.
+pub async fn get_redirect_by_id_service(
+    pool: &PgPool,
+    id: i32,
+) -> Result<RedirectDTO> {
+    let redirect_model = select_redirect_by_id(pool, id).await?;
+    let result = RedirectDTO::from(redirect_model);
+    Ok(result)
+}
+
+/// Service to follow the redirect from a path, counting the hit.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `path` - The requested path.
+///
+/// # Returns
+///
+/// * `Result<RedirectDTO>` - The followed redirect, or a `RowNotFound`
+///   error if no redirect starts from the path.
+pub async fn follow_redirect_service(
+    pool: &PgPool,
+    path: &str,
+) -> Result<RedirectDTO> {
+    let redirect_model = hit_redirect(pool, path.trim())
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+    let result = RedirectDTO::from(redirect_model);
+    Ok(result)
+}
+
+/// Service to delete redirects by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `delete_redirect_ids_dto` - A `DeleteRedirectIdsDTO` containing the list of redirect IDs to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted redirects.
+pub async fn delete_redirect_by_id_service(
+    pool: &PgPool,
+    delete_redirect_ids_dto: DeleteRedirectIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids =
+        delete_redirect_by_id(pool, delete_redirect_ids_dto.ids).await?;
+    Ok(deleted_ids)
+}
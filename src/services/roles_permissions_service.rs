@@ -0,0 +1,33 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::roles_permissions_dto::{
+        CreateRolesPermissionsDTO, DeleteRolesPermissionsIdsDTO,
+        RolesPermissionsDTO,
+    },
+    models::roles_permissions_model::RolesPermissionsModel,
+    repositories::roles_permissions_repository::{
+        delete_role_permission_by_id, insert_role_permission,
+    },
+};
+
+pub async fn create_role_permission_service(
+    pool: &PgPool,
+    create_dto: CreateRolesPermissionsDTO,
+) -> Result<RolesPermissionsDTO> {
+    let model: RolesPermissionsModel = create_dto.try_into()?;
+
+    let create_model = insert_role_permission(pool, model).await?;
+    let result = RolesPermissionsDTO::from(create_model);
+    Ok(result)
+}
+
+pub async fn delete_role_permission_service(
+    pool: &PgPool,
+    delete_dto: DeleteRolesPermissionsIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids =
+        delete_role_permission_by_id(pool, delete_dto.ids).await?;
+    Ok(deleted_ids)
+}
@@ -0,0 +1,128 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::dtos::pagination_dto::PaginationDTO;
+use crate::dtos::role_dto::{CreateRoleDTO, DeleteRoleIdsDTO, RoleDTO};
+use crate::models::roles_model::{RoleModel, RoleSortField};
+use crate::models::sort_model::Sort;
+use crate::repositories::roles_repository::{
+    count_roles, delete_role_by_id, insert_role, select_role_by_id,
+    select_roles, update_role,
+};
+
+use super::calculate_pagination;
+
+/// Service to create a new role.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `role_dto` - A `CreateRoleDTO` object containing the role data.
+///
+/// # Returns
+///
+/// * `Result<RoleDTO>` - The newly created role.
+pub async fn create_role_service(
+    pool: &PgPool,
+    role_dto: CreateRoleDTO,
+) -> Result<RoleDTO> {
+    let role_model: RoleModel = role_dto.try_into()?;
+
+    let create_role_model = insert_role(pool, role_model).await?;
+    let result = RoleDTO::from(create_role_model);
+    Ok(result)
+}
+
+/// Service to update an existing role by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the role to update.
+/// * `role_dto` - A `CreateRoleDTO` object containing the updated role data.
+///
+/// # Returns
+///
+/// * `Result<RoleDTO>` - The updated role.
+pub async fn update_role_service(
+    pool: &PgPool,
+    id: i32,
+    role_dto: CreateRoleDTO,
+) -> Result<RoleDTO> {
+    let mut role_model: RoleModel = role_dto.try_into()?;
+    role_model.id = Some(id);
+
+    let update_role_model = update_role(pool, id, role_model).await?;
+    let result = RoleDTO::from(update_role_model);
+    Ok(result)
+}
+
+/// Service to retrieve all roles with pagination and sorting.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `page` - The current page number for pagination.
+/// * `limit` - The number of roles per page.
+/// * `sort` - The fields to sort by, e.g. `name,-id`.
+///
+/// # Returns
+///
+/// * `Result<PaginationDTO<RoleDTO>>` - The paginated list of roles.
+pub async fn get_all_roles_service(
+    pool: &PgPool,
+    page: i64,
+    limit: i64,
+    sort: &str,
+) -> Result<PaginationDTO<RoleDTO>> {
+    let sort = Sort::<RoleSortField>::parse(sort)?;
+    let total_items = count_roles(pool).await?;
+    let pagination = calculate_pagination(total_items, page, limit);
+
+    let roles_model =
+        select_roles(pool, limit, pagination.offset, &sort).await?;
+
+    let roles_dto: Vec<RoleDTO> =
+        roles_model.into_iter().map(RoleDTO::from).collect();
+
+    Ok(PaginationDTO {
+        current_page: pagination.current_page,
+        total_pages: pagination.total_pages,
+        total_items: pagination.total_items,
+        data: roles_dto,
+    })
+}
+
+/// Service to retrieve a role by its ID.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `id` - The ID of the role to retrieve.
+///
+/// # Returns
+///
+/// * `Result<RoleDTO>` - The requested role.
+pub async fn get_role_by_id_service(pool: &PgPool, id: i32) -> Result<RoleDTO> {
+    let role_model = select_role_by_id(pool, id).await?;
+    let result = RoleDTO::from(role_model);
+    Ok(result)
+}
+
+/// Service to delete roles by their IDs.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `delete_role_ids_dto` - A `DeleteRoleIdsDTO` containing the list of role IDs to delete.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted roles.
+pub async fn delete_role_by_id_service(
+    pool: &PgPool,
+    delete_role_ids_dto: DeleteRoleIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids = delete_role_by_id(pool, delete_role_ids_dto.ids).await?;
+    Ok(deleted_ids)
+}
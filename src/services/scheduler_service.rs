@@ -0,0 +1,50 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use validator::Validate;
+
+use crate::{
+    config::config::get_publish_scheduler_interval,
+    dtos::scheduler_dtos::{NextRunsDTO, NextRunsParamsDTO, ScheduledRunDTO},
+    repositories::posts_repository::{
+        publish_due_posts, select_scheduled_posts,
+    },
+};
+
+/// Maximum number of posts published by one pass of the worker, the rest
+/// are left to the next pass.
+const PUBLISH_BATCH_SIZE: i64 = 100;
+
+/// Publishes the scheduled posts that are due.
+///
+/// Safe to run from several server instances at once, each post is
+/// published by exactly one of them.
+///
+/// # Returns
+///
+/// * `Result<Vec<i32>>` - The IDs of the published posts.
+pub async fn publish_due_posts_service(pool: &PgPool) -> Result<Vec<i32>> {
+    let now = chrono::Utc::now().naive_utc();
+
+    publish_due_posts(pool, now, PUBLISH_BATCH_SIZE).await
+}
+
+/// Lists the next posts the worker will publish.
+///
+/// # Returns
+///
+/// * `Result<NextRunsDTO>` - The worker interval and the scheduled posts,
+///   the next to be published first.
+pub async fn get_next_runs_service(
+    pool: &PgPool,
+    params: NextRunsParamsDTO,
+) -> Result<NextRunsDTO> {
+    params.validate()?;
+
+    let posts =
+        select_scheduled_posts(pool, params.limit.unwrap_or(20)).await?;
+
+    Ok(NextRunsDTO {
+        interval_seconds: get_publish_scheduler_interval(),
+        runs: posts.into_iter().map(ScheduledRunDTO::from).collect(),
+    })
+}
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use validator::ValidationErrors;
+
+use crate::{
+    dtos::slug_dtos::{
+        SlugAvailabilityDTO, SlugCheckParamsDTO, SlugResolutionDTO,
+        SlugResolveParamsDTO,
+    },
+    models::slugs_model::SlugType,
+    repositories::slugs_repository::{
+        lock_slugs, select_available_slug, select_slug_target,
+    },
+    validators::slug_validator::validate_slug,
+};
+
+/// Allocates a slug generated from a title or name, appending `-2`, `-3`…
+/// if another content of the same type already uses it.
+///
+/// The allocations of the content type are serialized until the end of the
+/// transaction, which must insert or update the content before committing.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the transaction the content is saved in.
+/// * `slug_type` - The content type of the slug.
+/// * `base` - The generated slug.
+/// * `exclude_id` - The ID of the content being updated, if any.
+pub async fn allocate_slug(
+    conn: &mut PgConnection,
+    slug_type: SlugType,
+    base: &str,
+    exclude_id: Option<i32>,
+) -> Result<String> {
+    lock_slugs(conn, slug_type).await?;
+
+    select_available_slug(conn, slug_type, base, exclude_id).await
+}
+
+/// Allocates a slug like `allocate_slug`, for contents saved outside of a
+/// transaction of the caller.
+///
+/// # Returns
+///
+/// * `Result<(Transaction<'static, Postgres>, String)>` - The transaction
+///   holding the allocation lock, to commit once the content is saved, and
+///   the slug.
+pub async fn reserve_slug(
+    pool: &PgPool,
+    slug_type: SlugType,
+    base: &str,
+    exclude_id: Option<i32>,
+) -> Result<(Transaction<'static, Postgres>, String)> {
+    let mut tx = pool.begin().await?;
+    let slug = allocate_slug(&mut tx, slug_type, base, exclude_id).await?;
+
+    Ok((tx, slug))
+}
+
+/// Checks whether a slug is free for a content type.
+///
+/// # Returns
+///
+/// * `Result<SlugAvailabilityDTO>` - The availability of the slug and a
+///   free alternative.
+pub async fn check_slug_service(
+    pool: &PgPool,
+    params: SlugCheckParamsDTO,
+) -> Result<SlugAvailabilityDTO> {
+    if let Err(validation_error) = validate_slug(&params.slug, 1, 200) {
+        let mut errors = ValidationErrors::new();
+        errors.add("slug", validation_error);
+        return Err(errors.into());
+    }
+
+    let mut conn = pool.acquire().await?;
+    let suggestion = select_available_slug(
+        &mut conn,
+        params.slug_type,
+        &params.slug,
+        params.exclude_id,
+    )
+    .await?;
+
+    Ok(SlugAvailabilityDTO {
+        available: suggestion == params.slug,
+        slug: params.slug,
+        suggestion,
+    })
+}
+
+/// Resolves a current or previous slug to the content it points to.
+///
+/// # Returns
+///
+/// * `Result<SlugResolutionDTO>` - The content and its current slug, or a
+///   `RowNotFound` error if no content has ever had the slug.
+pub async fn resolve_slug_service(
+    pool: &PgPool,
+    params: SlugResolveParamsDTO,
+) -> Result<SlugResolutionDTO> {
+    let (id, slug, redirected) =
+        select_slug_target(pool, params.slug_type, &params.slug)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+    Ok(SlugResolutionDTO {
+        slug_type: params.slug_type,
+        id,
+        slug,
+        redirected,
+    })
+}
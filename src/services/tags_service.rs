@@ -4,17 +4,25 @@ use validator::Validate;
 
 use crate::dtos::pagination_dto::PaginationDTO;
 use crate::dtos::tag_dto::{CreateTagDTO, DeleteTagIdsDTO, TagDTO};
+use crate::extractors::if_match_extractor::{IfMatch, PreconditionFailedError};
 use crate::handlers::generate_slug_handler::generate_slug;
-use crate::models::tags_model::TagModel;
+use crate::models::slugs_model::SlugType;
+use crate::models::sort_model::Sort;
+use crate::models::tags_model::{TagModel, TagSortField};
+use crate::repositories::slugs_repository::insert_previous_slug;
 use crate::repositories::tags_repository::{
     count_tags, delete_tag_by_id, insert_tag, select_tag_by_id, select_tags,
     update_tag,
 };
 
-use super::calculate_pagination;
+use super::slugs_service::{allocate_slug, reserve_slug};
+use super::{calculate_pagination, if_match_deletion_id};
 
 /// Service to insert a new tag into the database.
 ///
+/// A slug generated from the name gets a `-2`, `-3`… suffix if another tag
+/// uses it.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
@@ -27,29 +35,48 @@ pub async fn create_tag_service(
     pool: &PgPool,
     tag_dto: CreateTagDTO,
 ) -> Result<TagDTO> {
-    let tag_model: TagModel = tag_dto.try_into()?;
+    let generated_slug = tag_dto.slug.is_none();
+    let mut tag_model: TagModel = tag_dto.try_into()?;
+
+    let mut slug_lock = None;
+    if generated_slug {
+        let base = tag_model.slug.take().unwrap_or_default();
+        let (lock, slug) =
+            reserve_slug(pool, SlugType::Tag, &base, None).await?;
+        tag_model.slug = Some(slug);
+        slug_lock = Some(lock);
+    }
 
     let create_tag_model = insert_tag(pool, tag_model).await?;
+    if let Some(slug_lock) = slug_lock {
+        slug_lock.commit().await?;
+    }
     let result = TagDTO::from(create_tag_model);
     Ok(result)
 }
 
 /// Service to update an existing tag by its ID in the database.
 ///
+/// A slug generated from the name is made unique as on creation.
+///
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
 /// * `id` - The ID of the tag to update.
+/// * `if_match` - The version the client expects the tag to be at.
 /// * `update_tag_dto` - A `CreateTagDTO` object containing the updated tag data.
 ///
 /// # Returns
 ///
-/// * `Result<TagModel>` - The updated `TagModel` record.
+/// * `Result<TagModel>` - The updated `TagModel` record, or a
+///   `PreconditionFailedError` if the tag is at another version.
 pub async fn update_tag_service(
     pool: &PgPool,
     id: i32,
+    if_match: &IfMatch,
     tag_dto: CreateTagDTO,
 ) -> Result<TagDTO> {
+    let generated_slug = tag_dto.slug.is_none();
     let mut tag_model: TagModel = tag_dto.try_into()?;
     tag_model.id = Some(id);
 
@@ -58,7 +85,24 @@ pub async fn update_tag_service(
     }
     tag_model.validate()?;
 
-    let update_tag_model = update_tag(pool, id, tag_model).await?;
+    let current = select_tag_by_id(pool, id).await?;
+    if_match.check(current.version)?;
+    tag_model.version = current.version;
+
+    let mut tx = pool.begin().await?;
+    if generated_slug {
+        let base = tag_model.slug.take().unwrap_or_default();
+        tag_model.slug =
+            Some(allocate_slug(&mut tx, SlugType::Tag, &base, Some(id)).await?);
+    }
+    if let Some(slug) = &tag_model.slug {
+        insert_previous_slug(&mut tx, SlugType::Tag, id, slug).await?;
+    }
+
+    let update_tag_model = update_tag(pool, id, tag_model)
+        .await
+        .map_err(PreconditionFailedError::on_row_not_found)?;
+    tx.commit().await?;
     let result = TagDTO::from(update_tag_model);
     Ok(result)
 }
@@ -76,15 +120,13 @@ pub async fn get_all_tags_service(
     pool: &PgPool,
     page: i64,
     limit: i64,
-    sort_column: &str,
-    sort_order: &str,
+    sort: &str,
 ) -> Result<PaginationDTO<TagDTO>> {
+    let sort = Sort::<TagSortField>::parse(sort)?;
     let total_items = count_tags(pool).await?;
     let pagination = calculate_pagination(total_items, page, limit);
 
-    let tags_model =
-        select_tags(pool, limit, pagination.offset, sort_column, sort_order)
-            .await?;
+    let tags_model = select_tags(pool, limit, pagination.offset, &sort).await?;
 
     let tags_dto: Vec<TagDTO> =
         tags_model.into_iter().map(TagDTO::from).collect();
@@ -118,6 +160,8 @@ pub async fn get_tag_by_id_service(pool: &PgPool, id: i32) -> Result<TagDTO> {
 /// # Arguments
 ///
 /// * `pool` - A reference to the PostgreSQL connection pool.
+/// * `if_match` - The version the client expects a single tag to be at,
+///   any number of tags being deleted at any version with `*`.
 /// * `delete_tag_ids_dto` - A `DeleteTagIdsDTO` containing the list of tag IDs to delete.
 ///
 /// # Returns
@@ -125,8 +169,21 @@ pub async fn get_tag_by_id_service(pool: &PgPool, id: i32) -> Result<TagDTO> {
 /// * `Result<Vec<i32>>` - A vector containing the IDs of the deleted tags.
 pub async fn delete_tag_by_id_service(
     pool: &PgPool,
+    if_match: &IfMatch,
     delete_tag_ids_dto: DeleteTagIdsDTO,
 ) -> Result<Vec<i32>> {
-    let deleted_ids = delete_tag_by_id(pool, delete_tag_ids_dto.ids).await?;
+    let mut version = None;
+    if if_match.is_present() && !if_match.is_any() {
+        let id = if_match_deletion_id(&delete_tag_ids_dto.ids)?;
+        version = select_tag_by_id(pool, id).await?.version;
+        if_match.check(version)?;
+    }
+
+    let deleted_ids =
+        delete_tag_by_id(pool, delete_tag_ids_dto.ids, version).await?;
+    if version.is_some() && deleted_ids.is_empty() {
+        return Err(PreconditionFailedError.into());
+    }
+
     Ok(deleted_ids)
 }
@@ -0,0 +1,223 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use rand::{rngs::OsRng, RngCore};
+use sqlx::PgPool;
+use totp_rs::{Algorithm, Secret, TOTP};
+use validator::Validate;
+
+use crate::{
+    config::config::get_totp_issuer,
+    dtos::two_factor_dtos::{RecoveryCodesDTO, TotpCodeDTO, TotpEnrollmentDTO},
+    models::user_totp_model::UserTotpModel,
+    repositories::{
+        user_recovery_codes_repository::{
+            consume_user_recovery_code, delete_user_recovery_codes,
+            replace_user_recovery_codes,
+        },
+        user_totp_repository::{
+            confirm_user_totp, delete_user_totp, select_user_totp,
+            update_user_totp_last_used_step, upsert_pending_user_totp,
+        },
+        users_repository::select_user_by_id,
+    },
+};
+
+use super::auth_service::{generate_random_hex, hash_token};
+
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP_SECONDS: u64 = 30;
+const TOTP_SECRET_BYTES: usize = 20;
+const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Starts a TOTP enrolment for the user, replacing an unconfirmed one.
+///
+/// Two-factor authentication is only enabled once the enrolment is confirmed
+/// with a first code.
+///
+/// # Returns
+///
+/// * `Result<Option<TotpEnrollmentDTO>>` - The secret to add to an
+///   authenticator app, or `None` if two-factor authentication is already
+///   enabled.
+pub async fn enroll_totp_service(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Option<TotpEnrollmentDTO>> {
+    let user = select_user_by_id(pool, user_id).await?;
+
+    let mut secret = vec![0u8; TOTP_SECRET_BYTES];
+    OsRng.fill_bytes(&mut secret);
+    let totp = build_totp(secret, user.email)?;
+    let encoded_secret = totp.get_secret_base32();
+
+    if !upsert_pending_user_totp(pool, user_id, &encoded_secret).await? {
+        return Ok(None);
+    }
+
+    Ok(Some(TotpEnrollmentDTO {
+        secret: encoded_secret,
+        otpauth_uri: totp.get_url(),
+    }))
+}
+
+/// Enables two-factor authentication once the user proves the authenticator
+/// app was set up, by sending a code generated from the pending secret.
+///
+/// # Returns
+///
+/// * `Result<Option<RecoveryCodesDTO>>` - The recovery codes, shown once, or
+///   `None` if there is no pending enrolment or the code is wrong.
+pub async fn confirm_totp_service(
+    pool: &PgPool,
+    user_id: i32,
+    request: TotpCodeDTO,
+) -> Result<Option<RecoveryCodesDTO>> {
+    request.validate()?;
+
+    let enrollment = match select_user_totp(pool, user_id).await? {
+        Some(enrollment) if enrollment.confirmed_at.is_none() => enrollment,
+        _ => return Ok(None),
+    };
+
+    if !verify_totp_code(pool, &enrollment, &request.code).await? {
+        return Ok(None);
+    }
+
+    confirm_user_totp(pool, user_id).await?;
+    let recovery_codes = generate_recovery_codes(pool, user_id).await?;
+
+    Ok(Some(RecoveryCodesDTO { recovery_codes }))
+}
+
+/// Disables two-factor authentication. Once enabled, it can only be turned
+/// off with a valid code or recovery code.
+///
+/// # Returns
+///
+/// * `Result<bool>` - `false` if the code is wrong.
+pub async fn disable_totp_service(
+    pool: &PgPool,
+    user_id: i32,
+    request: TotpCodeDTO,
+) -> Result<bool> {
+    request.validate()?;
+
+    if is_two_factor_enabled(pool, user_id).await?
+        && !verify_second_factor(pool, user_id, &request.code).await?
+    {
+        return Ok(false);
+    }
+
+    delete_user_totp(pool, user_id).await?;
+    delete_user_recovery_codes(pool, user_id).await?;
+
+    Ok(true)
+}
+
+/// Whether the user confirmed a TOTP enrolment.
+pub async fn is_two_factor_enabled(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<bool> {
+    let enrollment = select_user_totp(pool, user_id).await?;
+
+    Ok(enrollment.is_some_and(|enrollment| enrollment.confirmed_at.is_some()))
+}
+
+/// Checks a code from the authenticator app, or a recovery code.
+///
+/// A TOTP code is accepted at most once, and a recovery code is used up.
+pub async fn verify_second_factor(
+    pool: &PgPool,
+    user_id: i32,
+    code: &str,
+) -> Result<bool> {
+    let enrollment = match select_user_totp(pool, user_id).await? {
+        Some(enrollment) if enrollment.confirmed_at.is_some() => enrollment,
+        _ => return Ok(false),
+    };
+
+    let code = code.trim();
+    if code.len() == TOTP_DIGITS && code.chars().all(|c| c.is_ascii_digit()) {
+        return verify_totp_code(pool, &enrollment, code).await;
+    }
+
+    let normalized: String = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+
+    consume_user_recovery_code(pool, user_id, &hash_token(&normalized)).await
+}
+
+/// Accepts a code of the previous, current or next time step to allow for
+/// clock drift, and records the step so that the code cannot be replayed.
+async fn verify_totp_code(
+    pool: &PgPool,
+    enrollment: &UserTotpModel,
+    code: &str,
+) -> Result<bool> {
+    let secret = Secret::Encoded(enrollment.secret.clone())
+        .to_bytes()
+        .map_err(|e| anyhow!("Invalid TOTP secret: {:?}", e))?;
+    let totp = build_totp(secret, String::new())?;
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let current_step = now / TOTP_STEP_SECONDS;
+    let matching_step = [current_step - 1, current_step, current_step + 1]
+        .into_iter()
+        .find(|step| totp.check(code, step * TOTP_STEP_SECONDS));
+
+    match matching_step {
+        Some(step) => {
+            update_user_totp_last_used_step(
+                pool,
+                enrollment.user_id,
+                step as i64,
+            )
+            .await
+        }
+        None => Ok(false),
+    }
+}
+
+/// Replaces the recovery codes of the user, returning them in clear text.
+async fn generate_recovery_codes(
+    pool: &PgPool,
+    user_id: i32,
+) -> Result<Vec<String>> {
+    let codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+        .map(|_| generate_random_hex(10))
+        .collect();
+    let hashes: Vec<String> =
+        codes.iter().map(|code| hash_token(code)).collect();
+
+    replace_user_recovery_codes(pool, user_id, &hashes).await?;
+
+    // Grouped for readability, the dashes are ignored when verifying
+    Ok(codes
+        .iter()
+        .map(|code| {
+            code.as_bytes()
+                .chunks(5)
+                .map(|chunk| String::from_utf8_lossy(chunk).to_string())
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+        .collect())
+}
+
+fn build_totp(secret: Vec<u8>, account_name: String) -> Result<TOTP> {
+    TOTP::new(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        0,
+        TOTP_STEP_SECONDS,
+        secret,
+        Some(get_totp_issuer()),
+        account_name,
+    )
+    .map_err(|e| anyhow!("Invalid TOTP parameters: {}", e))
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::{
+    dtos::users_roles_dto::{
+        CreateUsersRolesDTO, DeleteUsersRolesIdsDTO, UsersRolesDTO,
+    },
+    models::users_roles_model::UsersRolesModel,
+    repositories::users_roles_repository::{
+        delete_user_role_by_id, insert_user_role,
+    },
+};
+
+pub async fn create_user_role_service(
+    pool: &PgPool,
+    create_dto: CreateUsersRolesDTO,
+) -> Result<UsersRolesDTO> {
+    let model: UsersRolesModel = create_dto.try_into()?;
+
+    let create_model = insert_user_role(pool, model).await?;
+    let result = UsersRolesDTO::from(create_model);
+    Ok(result)
+}
+
+pub async fn delete_user_role_service(
+    pool: &PgPool,
+    delete_dto: DeleteUsersRolesIdsDTO,
+) -> Result<Vec<i32>> {
+    let deleted_ids = delete_user_role_by_id(pool, delete_dto.ids).await?;
+    Ok(deleted_ids)
+}
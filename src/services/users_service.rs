@@ -1,80 +1,106 @@
 use anyhow::Result;
-use argon2::{
-    password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
-    Argon2,
-};
+use log::error;
 use sqlx::PgPool;
+use validator::Validate;
 
 use crate::{
     dtos::{
         pagination_dto::PaginationDTO,
-        user_dtos::{CreateUserDTO, DeleteUserIdsDTO, UserDTO},
+        user_dtos::{
+            CreateUserDTO, DeleteUserIdsDTO, UpdateUserDTO, UserDTO,
+            UserFiltersDTO,
+        },
+    },
+    extractors::if_match_extractor::{IfMatch, PreconditionFailedError},
+    mailers::Mailer,
+    models::{
+        sort_model::Sort,
+        users_models::{UserFilters, UserModel, UserSortField},
     },
-    models::users_models::UserModel,
-    repositories::users_repository::{
-        count_users, delete_user_by_id, insert_user, select_user_by_email,
-        select_user_by_id, select_users, update_user,
+    repositories::{
+        refresh_tokens_repository::revoke_refresh_tokens_by_user_id,
+        users_repository::{
+            count_users, delete_user_by_id, insert_user, select_user_by_email,
+            select_user_by_id, select_users, update_user,
+            update_user_email_verified, update_user_password,
+        },
     },
 };
 
-use super::calculate_pagination;
+use super::{
+    auth_service::{hash_password, send_verification_email_service},
+    calculate_pagination, if_match_deletion_id,
+};
 
+/// Creates a user and emails them a link to verify their address.
 pub async fn create_user_service(
     pool: &PgPool,
+    mailer: &dyn Mailer,
     user_dto: CreateUserDTO,
 ) -> Result<UserDTO> {
     let mut user_model: UserModel = user_dto.try_into()?;
-
-    let argon2 = Argon2::default();
-    let salt = SaltString::generate(&mut OsRng);
-    let password_hash = argon2
-        .hash_password(user_model.password.as_bytes(), &salt)
-        .map_err(|e| anyhow::anyhow!("Password hashing failed: {}", e))?
-        .to_string();
-
-    user_model.password = password_hash;
+    user_model.password = hash_password(&user_model.password)?;
 
     let create_user_model = insert_user(pool, user_model).await?;
+    send_verification_email(pool, mailer, &create_user_model).await;
     let result = UserDTO::from(create_user_model);
 
     Ok(result)
 }
 
+/// Updates the provided fields of a user. Changing the email address resets
+/// its verification and sends a verification link to the new address, and
+/// changing the password signs the user out of every session.
+///
+/// Fails with a `PreconditionFailedError` if `if_match` does not match the
+/// current version of the user.
 pub async fn update_user_service(
     pool: &PgPool,
+    mailer: &dyn Mailer,
     id: i32,
-    user_dto: CreateUserDTO,
+    if_match: &IfMatch,
+    user_dto: UpdateUserDTO,
 ) -> Result<UserDTO> {
-    let mut user_model: UserModel = user_dto.try_into()?;
-
-    let argon2 = Argon2::default();
-    let salt = SaltString::generate(&mut OsRng);
-    let password_hash = argon2
-        .hash_password(user_model.password.as_bytes(), &salt)
-        .map_err(|e| anyhow::anyhow!("Password hashing failed: {}", e))?
-        .to_string();
-
-    user_model.password = password_hash;
-
-    let create_user_model = update_user(pool, id, user_model).await?;
-    let result = UserDTO::from(create_user_model);
+    user_dto.validate()?;
+
+    let current_user = select_user_by_id(pool, id).await?;
+    if_match.check(current_user.version)?;
+    let previous_email = current_user.email.clone();
+    let password = user_dto.password.clone();
+    let user_model = user_dto.apply_to(current_user)?;
+
+    let mut updated_user_model = update_user(pool, id, user_model)
+        .await
+        .map_err(PreconditionFailedError::on_row_not_found)?;
+    if let Some(password) = password {
+        update_user_password(pool, id, &hash_password(&password)?).await?;
+        revoke_refresh_tokens_by_user_id(pool, id).await?;
+    }
+    if updated_user_model.email != previous_email {
+        updated_user_model.version =
+            Some(update_user_email_verified(pool, id, false).await?);
+        updated_user_model.email_verified_at = None;
+        send_verification_email(pool, mailer, &updated_user_model).await;
+    }
+    let result = UserDTO::from(updated_user_model);
 
     Ok(result)
 }
 
 pub async fn get_all_users_service(
     pool: &PgPool,
+    filters_dto: UserFiltersDTO,
     page: i64,
     limit: i64,
-    sort_column: &str,
-    sort_order: &str,
+    sort: &str,
 ) -> Result<PaginationDTO<UserDTO>> {
-    let total_items = count_users(pool).await?;
+    let sort = Sort::<UserSortField>::parse(sort)?;
+    let filters = UserFilters::from(filters_dto);
+    let total_items = count_users(pool, &filters).await?;
     let pagination = calculate_pagination(total_items, page, limit);
 
     let user_model: Vec<UserModel> =
-        select_users(pool, limit, pagination.offset, sort_column, sort_order)
-            .await?;
+        select_users(pool, &filters, limit, pagination.offset, &sort).await?;
 
     let user_dto: Vec<UserDTO> =
         user_model.into_iter().map(UserDTO::from).collect();
@@ -104,8 +130,41 @@ pub async fn get_user_by_email_service(
 
 pub async fn delete_user_by_id_service(
     pool: &PgPool,
+    if_match: &IfMatch,
     delete_user_ids_dto: DeleteUserIdsDTO,
 ) -> Result<Vec<i32>> {
-    let deleted_ids = delete_user_by_id(pool, delete_user_ids_dto.ids).await?;
+    let mut version = None;
+    if if_match.is_present() && !if_match.is_any() {
+        let id = if_match_deletion_id(&delete_user_ids_dto.ids)?;
+        version = select_user_by_id(pool, id).await?.version;
+        if_match.check(version)?;
+    }
+
+    let deleted_ids =
+        delete_user_by_id(pool, delete_user_ids_dto.ids, version).await?;
+    if version.is_some() && deleted_ids.is_empty() {
+        return Err(PreconditionFailedError.into());
+    }
+
     Ok(deleted_ids)
 }
+
+/// Sends the verification link of a user. A failure is only logged: the user
+/// is saved already, and the password reset flow verifies the address too.
+async fn send_verification_email(
+    pool: &PgPool,
+    mailer: &dyn Mailer,
+    user: &UserModel,
+) {
+    let user_id = user.id.expect("L'utilisateur doit avoir un ID");
+
+    if let Err(e) =
+        send_verification_email_service(pool, mailer, user_id, &user.email)
+            .await
+    {
+        error!(
+            "Failed to send the verification email of user {}: {:?}",
+            user_id, e
+        );
+    }
+}
@@ -2,10 +2,19 @@
 pub mod setup {
     use std::fmt::Write;
 
+    use argon2::{
+        password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
+        Argon2,
+    };
     use sqlx::{Pool, Postgres};
+    use totp_rs::{Algorithm, Secret, TOTP};
 
     use crate::config::config;
+    use crate::config::jwt_keys::jwt_keys;
     use crate::db;
+    use crate::dtos::auth_dtos::{ClaimsDTO, TokenDTO};
+    use crate::mailers::Email;
+    use crate::services::auth_service::{login_service, LoginResult};
 
     /// <summary>
     /// Set up a test database connection pool.
@@ -83,4 +92,150 @@ pub mod setup {
                 err
             })
     }
+
+    /// <summary>
+    /// Generate a bearer token granting the given permissions.
+    /// </summary>
+    /// <param name="permissions">The permissions to include in the token claims.</param>
+    /// <returns>An `Authorization` header value of the form `Bearer <token>`.</returns>
+    pub fn bearer_token(permissions: &[&str]) -> String {
+        bearer_token_for(1, permissions)
+    }
+
+    /// <summary>
+    /// Generate a bearer token for the given user, granting the given permissions.
+    /// </summary>
+    /// <param name="user_id">The ID of the user the token is issued to.</param>
+    /// <param name="permissions">The permissions to include in the token claims.</param>
+    /// <returns>An `Authorization` header value of the form `Bearer <token>`.</returns>
+    pub fn bearer_token_for(user_id: i32, permissions: &[&str]) -> String {
+        dotenv::from_filename(".env.test").ok();
+
+        let claims = ClaimsDTO {
+            sub: user_id.to_string(),
+            exp: chrono::Utc::now()
+                .checked_add_signed(chrono::Duration::minutes(5))
+                .expect("Failed to compute token expiration")
+                .timestamp() as usize,
+            roles: vec![],
+            permissions: permissions.iter().map(|p| p.to_string()).collect(),
+        };
+
+        let token = jwt_keys()
+            .encode(&claims)
+            .expect("Failed to encode test token");
+
+        format!("Bearer {}", token)
+    }
+
+    /// <summary>
+    /// Insert an active test user with the given email and password.
+    /// </summary>
+    /// <param name="pool">A reference to the database connection pool.</param>
+    /// <param name="email">The email of the user, also used as username.</param>
+    /// <param name="password">The clear text password, stored hashed.</param>
+    /// <returns>The ID of the inserted user.</returns>
+    pub async fn insert_test_user(
+        pool: &Pool<Postgres>,
+        email: &str,
+        password: &str,
+    ) -> i32 {
+        // <remarks>
+        // Remove any leftover from a previous failed run, including posts
+        // other tests attached to the user.
+        // </remarks>
+        sqlx::query(
+            "DELETE FROM posts WHERE author_id IN (SELECT id FROM users WHERE email = $1)",
+        )
+        .bind(email)
+        .execute(pool)
+        .await
+        .expect("Failed to clean up test user posts");
+        clean_data_test(pool, "users", "email", email)
+            .await
+            .expect("Failed to clean up test user");
+
+        let salt = SaltString::generate(&mut OsRng);
+        let password_hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .expect("Failed to hash test password")
+            .to_string();
+
+        sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO users (username, password, email, firstname, lastname)
+            VALUES ($1, $2, $1, 'Test', 'User')
+            RETURNING id
+            "#,
+        )
+        .bind(email)
+        .bind(password_hash)
+        .fetch_one(pool)
+        .await
+        .expect("Failed to insert test user")
+    }
+
+    /// <summary>
+    /// Log a test user in through the login service.
+    /// </summary>
+    /// <param name="pool">A reference to the database connection pool.</param>
+    /// <param name="email">The email of the user.</param>
+    /// <param name="password">The clear text password of the user.</param>
+    /// <returns>The issued access and refresh tokens.</returns>
+    pub async fn login_test_user(
+        pool: &Pool<Postgres>,
+        email: &str,
+        password: &str,
+    ) -> TokenDTO {
+        match login_service(pool, email, password, "127.0.0.1")
+            .await
+            .expect("Failed to log in")
+        {
+            LoginResult::Success(token) => token,
+            _ => panic!("Invalid test credentials"),
+        }
+    }
+
+    /// <summary>
+    /// Extract the token of the link contained in an email.
+    /// </summary>
+    /// <param name="email">An email sent by the password reset or verification flows.</param>
+    /// <returns>The value of the `token` query parameter of the link.</returns>
+    pub fn token_from_email(email: &Email) -> String {
+        email
+            .body
+            .split("token=")
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .expect("Email does not contain a token")
+            .to_string()
+    }
+
+    /// <summary>
+    /// Generate the code an authenticator app would show for a TOTP secret.
+    /// </summary>
+    /// <param name="secret">The base32 encoded secret.</param>
+    /// <param name="offset_seconds">Shift from the current time, to get the code of another time step.</param>
+    /// <returns>The six-digit code.</returns>
+    pub fn totp_code(secret: &str, offset_seconds: u64) -> String {
+        let totp = TOTP::new(
+            Algorithm::SHA1,
+            6,
+            0,
+            30,
+            Secret::Encoded(secret.to_string())
+                .to_bytes()
+                .expect("Invalid TOTP secret"),
+            None,
+            String::new(),
+        )
+        .expect("Invalid TOTP parameters");
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Clock before UNIX epoch")
+            .as_secs();
+
+        totp.generate(now + offset_seconds)
+    }
 }
@@ -1 +1,2 @@
+pub mod redirect_validator;
 pub mod slug_validator;
@@ -0,0 +1,87 @@
+use validator::ValidationError;
+
+/// The length of the `source_path` and `target` columns.
+const MAX_REDIRECT_LENGTH: usize = 2000;
+
+/// Validation function for the path a redirect is followed from, an
+/// absolute path on this site such as `/old-page`.
+pub fn validate_redirect_source(path: &str) -> Result<(), ValidationError> {
+    if !is_local_path(path) {
+        let mut error = ValidationError::new("invalid_source_path");
+        error.message = Some(
+            format!(
+                "Source path must start with '/', without whitespace, up to \
+                 {} characters",
+                MAX_REDIRECT_LENGTH
+            )
+            .into(),
+        );
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Validation function for the target of a redirect, either an absolute
+/// path on this site or an `http` or `https` URL.
+pub fn validate_redirect_target(target: &str) -> Result<(), ValidationError> {
+    let is_url = target.len() <= MAX_REDIRECT_LENGTH
+        && target.split_once("://").is_some_and(|(scheme, rest)| {
+            matches!(scheme, "http" | "https")
+                && !rest.is_empty()
+                && !rest.starts_with('/')
+                && !has_invalid_characters(target)
+        });
+
+    if !is_url && !is_local_path(target) {
+        let mut error = ValidationError::new("invalid_target");
+        error.message = Some(
+            "Target must be a path starting with '/' or an absolute http or \
+             https URL"
+                .into(),
+        );
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// A path such as `/page`, `//host` being a URL to another site.
+fn is_local_path(path: &str) -> bool {
+    path.len() <= MAX_REDIRECT_LENGTH
+        && path.starts_with('/')
+        && !path.starts_with("//")
+        && !has_invalid_characters(path)
+}
+
+fn has_invalid_characters(value: &str) -> bool {
+    value
+        .chars()
+        .any(|c| c.is_whitespace() || c.is_control() || c == '\\')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_redirect_source() {
+        assert!(validate_redirect_source("/old-page?ref=1").is_ok());
+        assert!(validate_redirect_source("old-page").is_err());
+        assert!(validate_redirect_source("//example.com").is_err());
+        assert!(validate_redirect_source("/old page").is_err());
+    }
+
+    #[test]
+    fn test_validate_redirect_target() {
+        assert!(validate_redirect_target("/new-page").is_ok());
+        assert!(validate_redirect_target("https://example.com/page").is_ok());
+        assert!(validate_redirect_target("javascript:alert(1)").is_err());
+        assert!(validate_redirect_target("//example.com").is_err());
+        assert!(validate_redirect_target("/\\example.com").is_err());
+
+        if let Err(err) = validate_redirect_target("ftp://example.com") {
+            assert_eq!(err.code, "invalid_target");
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod publish_scheduler;
+pub mod trash_purger;
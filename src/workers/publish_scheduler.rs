@@ -0,0 +1,32 @@
+use log::{error, info};
+use ntex::time::{interval, Seconds};
+use sqlx::PgPool;
+
+use crate::{
+    config::config::get_publish_scheduler_interval,
+    services::scheduler_service::publish_due_posts_service,
+};
+
+/// Starts the background worker publishing scheduled posts once their
+/// `date_published` has passed, unless `PUBLISH_SCHEDULER_INTERVAL` is `0`.
+pub fn start_publish_scheduler(pool: PgPool) {
+    let interval_seconds = get_publish_scheduler_interval();
+    if interval_seconds == 0 {
+        info!("Scheduled publishing is disabled");
+        return;
+    }
+
+    ntex::rt::spawn(async move {
+        let ticks = interval(Seconds(interval_seconds));
+        loop {
+            ticks.tick().await;
+            match publish_due_posts_service(&pool).await {
+                Ok(ids) if !ids.is_empty() => {
+                    info!("Published scheduled posts {:?}", ids)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to publish scheduled posts: {:?}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,34 @@
+use log::{error, info};
+use ntex::time::{interval, Seconds};
+use sqlx::PgPool;
+
+use crate::{
+    config::config::get_trash_retention_days,
+    services::post_trash_service::purge_expired_trashed_posts_service,
+};
+
+/// Seconds between two purges, the retention is counted in days.
+const PURGE_INTERVAL_SECONDS: u16 = 3600;
+
+/// Starts the background worker purging the posts that stayed in the trash
+/// longer than `TRASH_RETENTION_DAYS`, unless it is `0`.
+pub fn start_trash_purger(pool: PgPool) {
+    if get_trash_retention_days() == 0 {
+        info!("Trash purging is disabled");
+        return;
+    }
+
+    ntex::rt::spawn(async move {
+        let ticks = interval(Seconds(PURGE_INTERVAL_SECONDS));
+        loop {
+            ticks.tick().await;
+            match purge_expired_trashed_posts_service(&pool).await {
+                Ok(ids) if !ids.is_empty() => {
+                    info!("Purged trashed posts {:?}", ids)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to purge trashed posts: {:?}", e),
+            }
+        }
+    });
+}